@@ -0,0 +1,2242 @@
+use std::{
+    f64::consts::{FRAC_2_PI, LN_2},
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    cos, cos_simd, cosf, cosf_simd, ln, ln_simd, lnf, lnf_simd,
+    log::{SUBNORMAL_SCALE, SUBNORMAL_SCALE_F32},
+    polyval, polyval_simd, polyvalf, polyvalf_simd, sincos, sincos_simd,
+    sincosf, sincosf_simd,
+};
+
+// f(z) = J0(x) / (z - a^2), z = x^2, a = J0_ROOTS[0]
+// domain: 0 <= x <= 4
+//
+// The constant term is off by an ulp so that J0(0) comes out as exactly 1.
+const J0_MINIMAX_1: [f64; 12] = [
+    2.2336843678249962e-25,
+    -1.4674004684335375e-22,
+    7.15323583773962e-20,
+    -2.8554646508791334e-17,
+    9.220822097180243e-15,
+    -2.3494817565914e-12,
+    4.5736278944055307e-10,
+    -6.517182621260217e-8,
+    6.404783237242266e-6,
+    -0.00039698772526443674,
+    0.013329146159788531,
+    -0.1729150690306449,
+];
+
+// f(u) = J0(x) / (x - a), u = x - 6, a = J0_ROOTS[1]
+// domain: 4 <= x <= 8
+const J0_MINIMAX_2: [f64; 19] = [
+    -6.089337793197239e-19,
+    -2.420773089457343e-17,
+    2.3634251075615695e-16,
+    8.099174605123997e-15,
+    -7.086852359661718e-14,
+    -2.035109821009615e-12,
+    1.6621792856977836e-11,
+    3.881003341805387e-10,
+    -2.9472164422313693e-9,
+    -5.369878633692875e-8,
+    3.7683306575973564e-7,
+    5.046333168084599e-6,
+    -3.245981773476202e-5,
+    -0.0002903924577954878,
+    0.0016907164421519542,
+    0.008464995494980606,
+    -0.04343003493451364,
+    -0.07753659236669226,
+    0.3138953660581486,
+];
+
+// f(z) = J1(x) / (x (z - b^2)), z = x^2, b = J1_ROOTS[0]
+// domain: 0 <= x <= 5
+const J1_MINIMAX_1: [f64; 13] = [
+    -1.1106136995895942e-29,
+    9.627664303689181e-27,
+    -6.084107480893113e-24,
+    3.2023260504449543e-21,
+    -1.401429251024076e-18,
+    5.008667777519124e-16,
+    -1.428217595635108e-13,
+    3.1542400805215546e-11,
+    -5.188298753840383e-9,
+    6.019939527912011e-7,
+    -4.541501468060544e-5,
+    0.0019373847544143917,
+    -0.03405537391318949,
+];
+
+// f(u) = J1(x) / (x - b), u = x - 6.5, b = J1_ROOTS[1]
+// domain: 5 <= x <= 8
+const J1_MINIMAX_2: [f64; 17] = [
+    4.931057219437789e-16,
+    6.326417342995727e-16,
+    -1.4510044067705923e-13,
+    -1.3012678458978406e-13,
+    3.2284750312194e-11,
+    1.6585682772197116e-11,
+    -5.3657541013111525e-9,
+    -8.072392133637003e-10,
+    6.329695893100368e-7,
+    -1.1455840996380213e-7,
+    -4.920127473503496e-5,
+    2.2534588972428572e-5,
+    0.0022411250616447198,
+    -0.0014177205859508455,
+    -0.04833112679918981,
+    0.028353274403168434,
+    0.2983810684341155,
+];
+
+// f(z) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (z - c^2), z = x^2,
+// c = Y0_ROOTS[0]
+// domain: 0 <= x <= 2.5
+const Y0_MINIMAX_1: [f64; 11] = [
+    2.9062656218813587e-22,
+    -1.450582971726022e-19,
+    5.626748592786727e-17,
+    -1.7560129876651524e-14,
+    4.3016675822815e-12,
+    -7.996093627355336e-10,
+    1.0780429652310574e-7,
+    -9.89472646334693e-6,
+    0.0005617931795902858,
+    -0.01674467259105566,
+    0.18214429522164177,
+];
+
+// f(u) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (x - c), u = x - 4,
+// c = Y0_ROOTS[1]
+// domain: 2.5 <= x <= 5.5
+const Y0_MINIMAX_2: [f64; 17] = [
+    -3.92688809682506e-16,
+    5.3911050402655805e-15,
+    1.0942118091990845e-13,
+    -1.2842745502461076e-12,
+    -2.2872091055952884e-11,
+    2.2002356446391008e-10,
+    3.5468226600261644e-9,
+    -2.6266610779954596e-8,
+    -3.8846612161014567e-7,
+    1.97107660119628e-6,
+    2.814184031984374e-5,
+    -7.407987086905861e-5,
+    -0.0012378364062197323,
+    0.0003135758811788069,
+    0.02982941580394523,
+    0.037247726178246614,
+    -0.33674095518307207,
+];
+
+// f(u) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (x - c), u = x - 6.75,
+// c = Y0_ROOTS[2]
+// domain: 5.5 <= x <= 8
+const Y0_MINIMAX_3: [f64; 15] = [
+    -5.755154442743132e-14,
+    9.744255093706602e-13,
+    1.305513243594097e-11,
+    -1.605866243340733e-10,
+    -2.211570794310337e-9,
+    1.7546208313939197e-8,
+    2.744178511083868e-7,
+    -1.1579594026008465e-6,
+    -2.356896824931788e-5,
+    3.6171306787090364e-5,
+    0.0012744843894313767,
+    -0.0002602814072194792,
+    -0.03563143119535248,
+    0.006452471083546996,
+    0.27505053742496377,
+];
+
+// f(z) = x (Y1(x) - 2 / pi ln(x / d) J1(x)) / (z - d^2), z = x^2,
+// d = Y1_ROOTS[0]
+// domain: 0 <= x <= 4
+const Y1_MINIMAX_1: [f64; 13] = [
+    -1.346460888670737e-26,
+    9.315946164244242e-24,
+    -4.778852524578766e-21,
+    2.012662611276665e-18,
+    -6.8812674373879255e-16,
+    1.8637036029887186e-13,
+    -3.8723233955383916e-11,
+    5.912915882397641e-9,
+    -6.242862699344232e-7,
+    4.144150087153242e-5,
+    -0.0014502499501658145,
+    0.01602772144791309,
+    0.13187550549740895,
+];
+
+// f(u) = x (Y1(x) - 2 / pi ln(x / d) J1(x)) / (x - d), u = x - 6,
+// d = Y1_ROOTS[1]
+// domain: 4 <= x <= 8
+const Y1_MINIMAX_2: [f64; 18] = [
+    -4.14628142263047e-16,
+    -9.458318363663628e-16,
+    1.1661461255390498e-13,
+    3.6749085828197556e-13,
+    -2.3867977082721954e-11,
+    -9.93268314188838e-11,
+    3.5747922288079298e-9,
+    1.9069000376823432e-8,
+    -3.68774267398364e-7,
+    -2.5056115991983692e-6,
+    2.3789709412886706e-5,
+    0.0002109269355952709,
+    -0.0008014922738851252,
+    -0.010196304937398681,
+    0.008186586865217787,
+    0.23290047090697077,
+    0.07995563827835882,
+    -1.6560985734400866,
+];
+
+// For x >= BESSEL_BIG, with theta = x - (2n + 1) pi / 4,
+// Jn(x) = sqrt(2 / (pi x)) (Pn(x) cos(theta) - Qn(x) sin(theta))
+// Yn(x) = sqrt(2 / (pi x)) (Pn(x) sin(theta) + Qn(x) cos(theta))
+// These are Pn and x Qn in terms of t = 1 / x^2.
+// domain: x >= 8
+const P0_ASYMPTOTIC: [f64; 14] = [
+    -28093630054980.992,
+    3498264657967.7725,
+    -204134249944.44492,
+    7571728491.247756,
+    -208222964.30903813,
+    4851560.015744321,
+    -111690.30168112234,
+    3010.7857164531533,
+    -109.9430022461711,
+    6.0739074375158495,
+    -0.5725012713087603,
+    0.11215209952095949,
+    -0.07031249999997911,
+    1.0,
+];
+
+const Q0_ASYMPTOTIC: [f64; 14] = [
+    241549670765744.0,
+    -29811156049085.895,
+    1716528242109.8757,
+    -62349865798.57692,
+    1656698761.8656049,
+    -36444202.84983351,
+    764643.7680108406,
+    -18000.30965503604,
+    550.6424488940271,
+    -24.37927781509384,
+    1.7277261151951038,
+    -0.2271080008912501,
+    0.07324218749980708,
+    -0.12499999999999999,
+];
+
+const P1_ASYMPTOTIC: [f64; 14] = [
+    29742058533249.746,
+    -3705502642577.4907,
+    216403420336.58215,
+    -8037400940.215629,
+    221526610.31645638,
+    -5181996.655961647,
+    120114.44252770777,
+    -3273.5046644823287,
+    121.51975236492596,
+    -6.883772404989626,
+    0.6765924306128245,
+    -0.144195556547387,
+    0.11718749999997798,
+    1.0,
+];
+
+const Q1_ASYMPTOTIC: [f64; 14] = [
+    -255217151850580.75,
+    31512636923044.324,
+    -1815777702301.7964,
+    66029000724.71561,
+    -1757771629.701683,
+    38794966.24695457,
+    -818596.0771050657,
+    19447.616586792912,
+    -603.1144870172631,
+    27.247509834534135,
+    -1.9935302734140805,
+    0.27757644567237694,
+    -0.10253906249979691,
+    0.375,
+];
+
+// Zeros of J0, J1, Y0 and Y1 below BESSEL_BIG, each as hi + lo, factored out
+// of the polynomials so that the relative error stays small around them
+const J0_ROOTS: [(f64, f64); 2] = [
+    (2.404825557695773, -1.176691651530894e-16),
+    (5.520078110286311, 8.088597146146722e-17),
+];
+const J1_ROOTS: [(f64, f64); 2] = [
+    (3.8317059702075125, -1.5269184090088067e-16),
+    (7.015586669815619, -9.414165653410389e-17),
+];
+const Y0_ROOTS: [(f64, f64); 3] = [
+    (0.8935769662791675, 2.6596231539720385e-17),
+    (3.957678419314858, -1.0764340697562706e-16),
+    (7.086051060301773, -8.835285723085408e-17),
+];
+const Y1_ROOTS: [(f64, f64); 2] = [
+    (2.197141326031017, -4.8259835876454966e-17),
+    (5.429681040794135, 4.162514026670377e-16),
+];
+
+// sqrt(pi)
+const SQRT_PI: f64 = 1.772453850905516;
+
+// Boundaries between the polynomials below BESSEL_BIG, kept away from the
+// roots that the polynomials on either side do not factor out
+const J0_SPLIT: f64 = 4.0;
+const J1_SPLIT: f64 = 5.0;
+const Y0_SPLIT: [f64; 2] = [2.5, 5.5];
+const Y1_SPLIT: f64 = 4.0;
+
+// From here on the asymptotic forms are used
+const BESSEL_BIG: f64 = 8.0;
+
+// Below this Y1(x), which is about -2 / (pi x), overflows
+const Y1_TINY: f64 = 3.541315033259774e-309;
+
+// Below this jn uses the leading term of its power series, (x / 2)^n / n!
+const JN_TINY: f64 = 1.862645149230957e-9;
+
+// Miller's recurrence for jn starts JN_EXTRA + sqrt(JN_ACC n) orders above n,
+// and is rescaled by 1 / JN_RESCALE whenever it grows past JN_RESCALE
+const JN_EXTRA: u64 = 16;
+const JN_ACC: u64 = 64;
+const JN_RESCALE: f64 = 1e250;
+
+// ln(2^-1075), below which jn rounds to zero
+const JN_LN_UNDERFLOW: f64 = -745.2;
+
+// x - (hi + lo), accurate to the last bit of lo next to the root
+#[inline(always)]
+fn root_dist(x: f64, hi: f64, lo: f64) -> f64 {
+    (x - hi) - lo
+}
+
+// ln(x / (hi + lo)) for x > 0, accurate also next to the root. The rounding
+// error of the quotient is added back to first order. Subnormal x is scaled up
+// first, so that the quotient keeps all its bits.
+#[inline(always)]
+fn ln_ratio(x: f64, hi: f64, lo: f64) -> f64 {
+    let (x, k) = if x < f64::MIN_POSITIVE {
+        (x * SUBNORMAL_SCALE, 54.0_f64)
+    } else {
+        (x, 0.0)
+    };
+
+    let q = x / hi;
+    let e = hi.mul_add(-q, x) - lo * q;
+
+    k.mul_add(-LN_2, ln(q) + e / (hi * q))
+}
+
+// J0(x) for 0 <= x < BESSEL_BIG
+fn j0_small(x: f64) -> f64 {
+    if x < J0_SPLIT {
+        let (hi, lo) = J0_ROOTS[0];
+        root_dist(x, hi, lo) * ((x + hi) * polyval(&J0_MINIMAX_1, x * x))
+    } else {
+        let (hi, lo) = J0_ROOTS[1];
+        root_dist(x, hi, lo) * polyval(&J0_MINIMAX_2, x - 6.0)
+    }
+}
+
+// J1(x) for 0 <= x < BESSEL_BIG
+fn j1_small(x: f64) -> f64 {
+    if x < JN_TINY {
+        // The leading term x / 2, which also rounds subnormal results once
+        0.5 * x
+    } else if x < J1_SPLIT {
+        let (hi, lo) = J1_ROOTS[0];
+        x * root_dist(x, hi, lo) * (x + hi) * polyval(&J1_MINIMAX_1, x * x)
+    } else {
+        let (hi, lo) = J1_ROOTS[1];
+        root_dist(x, hi, lo) * polyval(&J1_MINIMAX_2, x - 6.5)
+    }
+}
+
+// Y0(x) for 0 < x < BESSEL_BIG
+fn y0_small(x: f64) -> f64 {
+    let ((hi, lo), p) = if x < Y0_SPLIT[0] {
+        let root = Y0_ROOTS[0];
+        (root, (x + root.0) * polyval(&Y0_MINIMAX_1, x * x))
+    } else if x < Y0_SPLIT[1] {
+        (Y0_ROOTS[1], polyval(&Y0_MINIMAX_2, x - 4.0))
+    } else {
+        (Y0_ROOTS[2], polyval(&Y0_MINIMAX_3, x - 6.75))
+    };
+
+    (FRAC_2_PI * ln_ratio(x, hi, lo))
+        .mul_add(j0_small(x), root_dist(x, hi, lo) * p)
+}
+
+// Y1(x) for 0 < x < BESSEL_BIG
+fn y1_small(x: f64) -> f64 {
+    let ((hi, lo), p) = if x < Y1_SPLIT {
+        let root = Y1_ROOTS[0];
+        (root, (x + root.0) * polyval(&Y1_MINIMAX_1, x * x))
+    } else {
+        (Y1_ROOTS[1], polyval(&Y1_MINIMAX_2, x - 6.0))
+    };
+
+    (FRAC_2_PI * ln_ratio(x, hi, lo))
+        .mul_add(j1_small(x), root_dist(x, hi, lo) * p / x)
+}
+
+// (sin(x) - cos(x), sin(x) + cos(x)) for finite x >= BESSEL_BIG. Their
+// product is -cos(2x), which gives whichever of them cancels from the other.
+fn sin_cos_diff_sum(x: f64) -> (f64, f64) {
+    let (s, c) = sincos(x);
+
+    let (ss, cc) = (s - c, s + c);
+
+    if !(x + x).is_finite() {
+        (ss, cc)
+    } else if s * c < 0.0 {
+        (ss, -cos(x + x) / ss)
+    } else {
+        (-cos(x + x) / cc, cc)
+    }
+}
+
+// (Pn(x), Qn(x)) of the asymptotic forms, for x >= BESSEL_BIG
+#[inline(always)]
+fn asymptotic_pq(p: &[f64; 14], q: &[f64; 14], x: f64) -> (f64, f64) {
+    let r = x.recip();
+    let t = r * r;
+
+    (polyval(p, t), r * polyval(q, t))
+}
+
+/// Computes the Bessel function of the first kind of order zero.
+pub fn j0(x: f64) -> f64 {
+    let x = x.abs();
+
+    if x < BESSEL_BIG {
+        j0_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pq(&P0_ASYMPTOTIC, &Q0_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum(x);
+
+        p.mul_add(cc, -q * ss) / (SQRT_PI * x.sqrt())
+    }
+}
+
+/// Computes the Bessel function of the first kind of order one.
+pub fn j1(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if a < BESSEL_BIG {
+        j1_small(a)
+    } else if a.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pq(&P1_ASYMPTOTIC, &Q1_ASYMPTOTIC, a);
+        let (ss, cc) = sin_cos_diff_sum(a);
+
+        p.mul_add(ss, q * cc) / (SQRT_PI * a.sqrt())
+    };
+
+    if x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Computes the Bessel function of the second kind of order zero. Returns
+/// negative infinity at zero and NaN for negative `x`.
+pub fn y0(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::NEG_INFINITY
+    } else if x < BESSEL_BIG {
+        y0_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pq(&P0_ASYMPTOTIC, &Q0_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum(x);
+
+        p.mul_add(ss, q * cc) / (SQRT_PI * x.sqrt())
+    }
+}
+
+/// Computes the Bessel function of the second kind of order one. Returns
+/// negative infinity at zero and NaN for negative `x`.
+pub fn y1(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x < Y1_TINY {
+        f64::NEG_INFINITY
+    } else if x < BESSEL_BIG {
+        y1_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pq(&P1_ASYMPTOTIC, &Q1_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum(x);
+
+        q.mul_add(ss, -p * cc) / (SQRT_PI * x.sqrt())
+    }
+}
+
+// Where the recurrence for jn starts, an even order well above n
+fn jn_start(n: u32) -> u64 {
+    let n = n as u64;
+    let m = n + JN_EXTRA + ((JN_ACC * n) as f64).sqrt() as u64;
+
+    m + m % 2
+}
+
+// jn(n, x) for x > n by forward recurrence from j0 and j1, which is stable
+// there
+fn jn_forward(n: u32, x: f64) -> f64 {
+    let mut a = j0(x);
+    let mut b = j1(x);
+
+    for k in 1..n {
+        let c = (2.0 * k as f64 / x).mul_add(b, -a);
+        a = b;
+        b = c;
+    }
+
+    b
+}
+
+// jn(n, x) for x < JN_TINY, where (x / 2)^n / n! is exact to working
+// precision
+fn jn_series(n: u32, x: f64) -> f64 {
+    let h = 0.5 * x;
+
+    let mut y = 1.0;
+    for k in 1..=n {
+        y *= h / k as f64;
+
+        if y == 0.0 {
+            break;
+        }
+    }
+
+    y
+}
+
+// jn(n, x) for JN_TINY <= x <= n by Miller's backward recurrence, normalised
+// with J0(x) + 2 J2(x) + 2 J4(x) + ... = 1
+fn jn_backward(n: u32, x: f64) -> f64 {
+    let mut bjp = 0.0;
+    let mut bj = 1.0;
+    let mut ans = 0.0;
+    let mut sum = 0.0;
+
+    for k in (1..=jn_start(n)).rev() {
+        let bjm = (2.0 * k as f64 / x).mul_add(bj, -bjp);
+        bjp = bj;
+        bj = bjm;
+
+        if bj.abs() > JN_RESCALE {
+            bj /= JN_RESCALE;
+            bjp /= JN_RESCALE;
+            ans /= JN_RESCALE;
+            sum /= JN_RESCALE;
+        }
+
+        if k % 2 == 1 {
+            sum += bj;
+        }
+
+        if k == n as u64 {
+            ans = bjp;
+        }
+    }
+
+    ans / 2.0f64.mul_add(sum, -bj)
+}
+
+// Kapteyn's bound ln(|jn(n, n z)|) <= n (ln(z) + s - ln(1 + s)),
+// s = sqrt(1 - z^2), for 0 <= x <= n. The sum in brackets cancels for z close
+// to 1, so it is raised by its rounding error to stay an upper bound.
+fn jn_ln_bound(n: u32, x: f64) -> f64 {
+    let n = n as f64;
+    let z = x / n;
+    let s = ((1.0 - z) * (1.0 + z)).sqrt();
+
+    n * ((ln(z) + s - ln(1.0 + s)) + 8.0 * f64::EPSILON)
+}
+
+// jn(n, x) for n >= 2 and x >= 0
+fn jn_pos(n: u32, x: f64) -> f64 {
+    if x > n as f64 {
+        jn_forward(n, x)
+    } else if x < JN_TINY {
+        jn_series(n, x)
+    } else if jn_ln_bound(n, x) < JN_LN_UNDERFLOW {
+        // Skips the recurrence, which takes about n steps, for orders far
+        // above x
+        0.0
+    } else {
+        jn_backward(n, x)
+    }
+}
+
+/// Computes the Bessel function of the first kind of integer order `n`.
+/// Orders below `x` use forward recurrence from `j0` and `j1`, and higher
+/// orders use Miller's backward recurrence, so the cost grows linearly with
+/// `n`. Orders so far above `x` that the result underflows return zero
+/// without the recurrence.
+pub fn jn(n: i32, x: f64) -> f64 {
+    let m = n.unsigned_abs();
+    let a = x.abs();
+
+    let y = match m {
+        0 => j0(a),
+        1 => j1(a),
+        _ => jn_pos(m, a),
+    };
+
+    // J(-n, x) = J(n, -x) = (-1)^n J(n, x)
+    if m % 2 == 1 && (n < 0) != x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+fn root_dist_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    hi: Simd<f64, LANES>,
+    lo: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (x - hi) - lo
+}
+
+#[inline(always)]
+fn ln_ratio_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    hi: Simd<f64, LANES>,
+    lo: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let subnormal = x.simd_lt(Simd::splat(f64::MIN_POSITIVE));
+    let x = subnormal.select(x * Simd::splat(SUBNORMAL_SCALE), x);
+    let k = subnormal.select(Simd::splat(54.0), Simd::splat(0.0));
+
+    let q = x / hi;
+    let e = hi.mul_add(-q, x) - lo * q;
+
+    k.mul_add(Simd::splat(-LN_2), ln_simd(q) + e / (hi * q))
+}
+
+// Picks the root for each lane
+#[inline(always)]
+fn select_root<const LANES: usize>(
+    mask: Mask<i64, LANES>,
+    a: (f64, f64),
+    b: (f64, f64),
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (
+        mask.select(Simd::splat(a.0), Simd::splat(b.0)),
+        mask.select(Simd::splat(a.1), Simd::splat(b.1)),
+    )
+}
+
+// J0(x) for lanes with 0 <= x < BESSEL_BIG; other lanes are garbage except
+// NaN
+#[inline(always)]
+fn j0_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(J0_SPLIT));
+    let (hi, lo) = select_root(small, J0_ROOTS[0], J0_ROOTS[1]);
+
+    let p1 =
+        (x + Simd::splat(J0_ROOTS[0].0)) * polyval_simd(&J0_MINIMAX_1, x * x);
+    let p2 = polyval_simd(&J0_MINIMAX_2, x - Simd::splat(6.0));
+
+    root_dist_simd(x, hi, lo) * small.select(p1, p2)
+}
+
+// J1(x) for lanes with 0 <= x < BESSEL_BIG; other lanes are garbage except
+// NaN
+#[inline(always)]
+fn j1_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(J1_SPLIT));
+    let (hi, lo) = select_root(small, J1_ROOTS[0], J1_ROOTS[1]);
+
+    let p1 = x
+        * root_dist_simd(x, hi, lo)
+        * (x + Simd::splat(J1_ROOTS[0].0))
+        * polyval_simd(&J1_MINIMAX_1, x * x);
+    let p2 = root_dist_simd(x, hi, lo)
+        * polyval_simd(&J1_MINIMAX_2, x - Simd::splat(6.5));
+
+    x.simd_lt(Simd::splat(JN_TINY))
+        .select(Simd::splat(0.5) * x, small.select(p1, p2))
+}
+
+// Y0(x) for lanes with 0 < x < BESSEL_BIG; other lanes are garbage except
+// NaN
+#[inline(always)]
+fn y0_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(Y0_SPLIT[0]));
+    let mid = x.simd_lt(Simd::splat(Y0_SPLIT[1]));
+
+    let (hi, lo) = select_root(mid, Y0_ROOTS[1], Y0_ROOTS[2]);
+    let (hi, lo) = (
+        small.select(Simd::splat(Y0_ROOTS[0].0), hi),
+        small.select(Simd::splat(Y0_ROOTS[0].1), lo),
+    );
+
+    let p1 =
+        (x + Simd::splat(Y0_ROOTS[0].0)) * polyval_simd(&Y0_MINIMAX_1, x * x);
+    let p2 = polyval_simd(&Y0_MINIMAX_2, x - Simd::splat(4.0));
+    let p3 = polyval_simd(&Y0_MINIMAX_3, x - Simd::splat(6.75));
+    let p = small.select(p1, mid.select(p2, p3));
+
+    (Simd::splat(FRAC_2_PI) * ln_ratio_simd(x, hi, lo))
+        .mul_add(j0_small_simd(x), root_dist_simd(x, hi, lo) * p)
+}
+
+// Y1(x) for lanes with 0 < x < BESSEL_BIG; other lanes are garbage except
+// NaN
+#[inline(always)]
+fn y1_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(Y1_SPLIT));
+    let (hi, lo) = select_root(small, Y1_ROOTS[0], Y1_ROOTS[1]);
+
+    let p1 =
+        (x + Simd::splat(Y1_ROOTS[0].0)) * polyval_simd(&Y1_MINIMAX_1, x * x);
+    let p2 = polyval_simd(&Y1_MINIMAX_2, x - Simd::splat(6.0));
+    let p = small.select(p1, p2);
+
+    (Simd::splat(FRAC_2_PI) * ln_ratio_simd(x, hi, lo))
+        .mul_add(j1_small_simd(x), root_dist_simd(x, hi, lo) * p / x)
+}
+
+#[inline(always)]
+fn sin_cos_diff_sum_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincos_simd(x);
+
+    let ss = s - c;
+    let cc = s + c;
+
+    let z = -cos_simd(x + x);
+    let finite = (x + x).is_finite();
+    let cancel = (s * c).simd_lt(Simd::splat(0.0));
+
+    (
+        (finite & !cancel).select(z / cc, ss),
+        (finite & cancel).select(z / ss, cc),
+    )
+}
+
+#[inline(always)]
+fn asymptotic_pq_simd<const LANES: usize>(
+    p: &[f64; 14],
+    q: &[f64; 14],
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let r = x.recip();
+    let t = r * r;
+
+    (polyval_simd(p, t), r * polyval_simd(q, t))
+}
+
+/// The SIMD version of [`j0`].
+#[inline(always)]
+pub fn j0_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.abs();
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = j0_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) = asymptotic_pq_simd(&P0_ASYMPTOTIC, &Q0_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum_simd(x);
+
+        let y_big = p.mul_add(cc, -q * ss) / (Simd::splat(SQRT_PI) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    x.is_infinite().select(Simd::splat(0.0), y)
+}
+
+/// The SIMD version of [`j1`].
+#[inline(always)]
+pub fn j1_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+    let big = a.simd_ge(Simd::splat(BESSEL_BIG));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = j1_small_simd(a);
+    }
+
+    if big.any() {
+        let (p, q) = asymptotic_pq_simd(&P1_ASYMPTOTIC, &Q1_ASYMPTOTIC, a);
+        let (ss, cc) = sin_cos_diff_sum_simd(a);
+
+        let y_big = p.mul_add(ss, q * cc) / (Simd::splat(SQRT_PI) * a.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = a.is_infinite().select(Simd::splat(0.0), y);
+
+    x.is_sign_negative().select(-y, y)
+}
+
+/// The SIMD version of [`y0`].
+#[inline(always)]
+pub fn y0_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = y0_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) = asymptotic_pq_simd(&P0_ASYMPTOTIC, &Q0_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum_simd(x);
+
+        let y_big = p.mul_add(ss, q * cc) / (Simd::splat(SQRT_PI) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.is_infinite().select(zero, y);
+    let y = x.simd_eq(zero).select(Simd::splat(f64::NEG_INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f64::NAN), y)
+}
+
+/// The SIMD version of [`y1`].
+#[inline(always)]
+pub fn y1_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = y1_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) = asymptotic_pq_simd(&P1_ASYMPTOTIC, &Q1_ASYMPTOTIC, x);
+        let (ss, cc) = sin_cos_diff_sum_simd(x);
+
+        let y_big = q.mul_add(ss, -p * cc) / (Simd::splat(SQRT_PI) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.is_infinite().select(zero, y);
+    let y = x
+        .simd_lt(Simd::splat(Y1_TINY))
+        .select(Simd::splat(f64::NEG_INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f64::NAN), y)
+}
+
+#[inline(always)]
+fn jn_forward_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut a = j0_simd(x);
+    let mut b = j1_simd(x);
+
+    for k in 1..n {
+        let c = (Simd::splat(2.0 * k as f64) / x).mul_add(b, -a);
+        a = b;
+        b = c;
+    }
+
+    b
+}
+
+#[inline(always)]
+fn jn_series_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let h = Simd::splat(0.5) * x;
+
+    let mut y = Simd::splat(1.0);
+    for k in 1..=n {
+        y *= h / Simd::splat(k as f64);
+
+        if y.simd_eq(Simd::splat(0.0)).all() {
+            break;
+        }
+    }
+
+    y
+}
+
+#[inline(always)]
+fn jn_backward_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let rescale = Simd::splat(JN_RESCALE);
+
+    let mut bjp = Simd::splat(0.0);
+    let mut bj = Simd::splat(1.0);
+    let mut ans = Simd::splat(0.0);
+    let mut sum = Simd::splat(0.0);
+
+    for k in (1..=jn_start(n)).rev() {
+        let bjm = (Simd::splat(2.0 * k as f64) / x).mul_add(bj, -bjp);
+        bjp = bj;
+        bj = bjm;
+
+        let huge = bj.abs().simd_gt(rescale);
+
+        if huge.any() {
+            bj = huge.select(bj / rescale, bj);
+            bjp = huge.select(bjp / rescale, bjp);
+            ans = huge.select(ans / rescale, ans);
+            sum = huge.select(sum / rescale, sum);
+        }
+
+        if k % 2 == 1 {
+            sum += bj;
+        }
+
+        if k == n as u64 {
+            ans = bjp;
+        }
+    }
+
+    ans / Simd::splat(2.0).mul_add(sum, -bj)
+}
+
+// jn(n, x) for n >= 2 and lanes with x >= 0 or NaN
+#[inline(always)]
+fn jn_ln_bound_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let n = Simd::splat(n as f64);
+    let z = x / n;
+    let s = ((one - z) * (one + z)).sqrt();
+
+    n * ((ln_simd(z) + s - ln_simd(one + s)) + Simd::splat(8.0 * f64::EPSILON))
+}
+
+#[inline(always)]
+fn jn_pos_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let tiny_x = Simd::splat(JN_TINY);
+
+    let forward = x.simd_gt(Simd::splat(n as f64));
+    let tiny = x.simd_lt(tiny_x);
+
+    let zero = jn_ln_bound_simd(n, x).simd_lt(Simd::splat(JN_LN_UNDERFLOW));
+    let skip = forward | tiny | zero;
+
+    let mut y = Simd::splat(0.0);
+
+    if forward.any() {
+        y = forward.select(jn_forward_simd(n, x), y);
+    }
+
+    if tiny.any() {
+        // The other lanes go in as zero, so that the series stops as soon
+        // as the tiny lanes underflow rather than after n steps
+        let xt = tiny.select(x, Simd::splat(0.0));
+
+        y = tiny.select(jn_series_simd(n, xt), y);
+    }
+
+    // Lanes that round to zero are left at zero
+    if !skip.all() {
+        // Tiny lanes are kept out of the recurrence, where it would overflow
+        // between two rescalings
+        let y_back = jn_backward_simd(n, tiny.select(tiny_x, x));
+
+        y = skip.select(y, y_back);
+    }
+
+    y
+}
+
+/// The SIMD version of [`jn`].
+#[inline(always)]
+pub fn jn_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let m = n.unsigned_abs();
+    let a = x.abs();
+
+    let y = match m {
+        0 => j0_simd(a),
+        1 => j1_simd(a),
+        _ => jn_pos_simd(m, a),
+    };
+
+    if m % 2 == 1 {
+        let flip = x.is_sign_negative() ^ Mask::splat(n < 0);
+        flip.select(-y, y)
+    } else {
+        y
+    }
+}
+
+// f(z) = J0(x) / (z - a^2), z = x^2, a = J0_ROOTS_F32[0]
+// domain: 0 <= x <= 4
+//
+// The constant term is off by an ulp so that J0(0) comes out as exactly 1.
+const J0_MINIMAX_1_F32: [f32; 8] = [
+    7.557_178e-15,
+    -2.307_583e-12,
+    4.568_043e-10,
+    -6.516_767_6e-8,
+    6.404_766_3e-6,
+    -3.969_876_9e-4,
+    1.332_914_6e-2,
+    -1.729_150_9e-1,
+];
+
+// f(u) = J0(x) / (x - a), u = x - 6, a = J0_ROOTS_F32[1]
+// domain: 4 <= x <= 8
+const J0_MINIMAX_2_F32: [f32; 11] = [
+    -2.769_731e-9,
+    -4.958_287_6e-8,
+    3.761_299_5e-7,
+    5.030_064e-6,
+    -3.245_859_3e-5,
+    -2.903_641_4e-4,
+    1.690_715_6e-3,
+    8.464_975e-3,
+    -4.343_003_4e-2,
+    -7.753_659e-2,
+    3.138_953_7e-1,
+];
+
+// f(z) = J1(x) / (x (z - b^2)), z = x^2, b = J1_ROOTS_F32[0]
+// domain: 0 <= x <= 5
+const J1_MINIMAX_1_F32: [f32; 10] = [
+    2.522_888_1e-21,
+    -1.366_708_2e-18,
+    4.998_65e-16,
+    -1.428_041_6e-13,
+    3.154_221e-11,
+    -5.188_297_3e-9,
+    6.019_94e-7,
+    -4.541_501_4e-5,
+    1.937_384_7e-3,
+    -3.405_537_5e-2,
+];
+
+// f(u) = J1(x) / (x - b), u = x - 6.5, b = J1_ROOTS_F32[1]
+// domain: 5 <= x <= 8
+const J1_MINIMAX_2_F32: [f32; 10] = [
+    -7.165_815_7e-10,
+    6.034_422_3e-7,
+    -1.147_352_5e-7,
+    -4.914_355e-5,
+    2.253_473e-5,
+    2.241_078_8e-3,
+    -1.417_720_6e-3,
+    -4.833_111_5e-2,
+    2.835_327_4e-2,
+    2.983_810_6e-1,
+];
+
+// f(z) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (z - c^2), z = x^2,
+// c = Y0_ROOTS_F32[0]
+// domain: 0 <= x <= 2.5
+const Y0_MINIMAX_1_F32: [f32; 7] = [
+    3.933_453_4e-12,
+    -7.964_890_4e-10,
+    1.077_910_9e-7,
+    -9.894_697_5e-6,
+    5.617_931_6e-4,
+    -1.674_467_3e-2,
+    1.821_443e-1,
+];
+
+// f(u) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (x - c), u = x - 4,
+// c = Y0_ROOTS_F32[1]
+// domain: 2.5 <= x <= 5.5
+const Y0_MINIMAX_2_F32: [f32; 10] = [
+    -2.505_505_5e-8,
+    -3.689_789e-7,
+    1.968_707_7e-6,
+    2.810_376_5e-5,
+    -7.407_797e-5,
+    -1.237_805_9e-3,
+    3.135_753_5e-4,
+    2.982_940_7e-2,
+    3.724_772_5e-2,
+    -3.367_409_4e-1,
+];
+
+// f(u) = (Y0(x) - 2 / pi ln(x / c) J0(x)) / (x - c), u = x - 6.75,
+// c = Y0_ROOTS_F32[2]
+// domain: 5.5 <= x <= 8
+const Y0_MINIMAX_3_F32: [f32; 10] = [
+    1.692_858_1e-8,
+    2.659_071_8e-7,
+    -1.157_119_2e-6,
+    -2.355_738_8e-5,
+    3.617_084e-5,
+    1.274_477_9e-3,
+    -2.602_813_2e-4,
+    -3.563_143e-2,
+    6.452_471e-3,
+    2.750_505_5e-1,
+];
+
+// f(z) = x (Y1(x) - 2 / pi ln(x / d) J1(x)) / (z - d^2), z = x^2,
+// d = Y1_ROOTS_F32[0]
+// domain: 0 <= x <= 4
+const Y1_MINIMAX_1_F32: [f32; 8] = [
+    1.468_988_4e-13,
+    -3.773_460_4e-11,
+    5.899_773e-9,
+    -6.241_887e-7,
+    4.144_110_4e-5,
+    -1.450_249_1e-3,
+    1.602_772e-2,
+    1.318_755e-1,
+];
+
+// f(u) = x (Y1(x) - 2 / pi ln(x / d) J1(x)) / (x - d), u = x - 6,
+// d = Y1_ROOTS_F32[1]
+// domain: 4 <= x <= 8
+const Y1_MINIMAX_2_F32: [f32; 12] = [
+    3.298_646_2e-9,
+    1.790_963_8e-8,
+    -3.675_464e-7,
+    -2.500_441_5e-6,
+    2.378_717_7e-5,
+    2.109_162_6e-4,
+    -8.014_899e-4,
+    -1.019_629_5e-2,
+    8.186_586e-3,
+    2.329_004_7e-1,
+    7.995_564e-2,
+    -1.656_098_6,
+];
+
+// Pn and x Qn of the asymptotic forms in terms of t = 1 / x^2, as for
+// P0_ASYMPTOTIC
+// domain: x >= 8
+const P0_ASYMPTOTIC_F32: [f32; 5] = [
+    3.490_687_8,
+    -5.428_213e-1,
+    1.119_986_5e-1,
+    -7.031_221e-2,
+    1.0,
+];
+
+const Q0_ASYMPTOTIC_F32: [f32; 5] = [
+    -1.243_796_5e1,
+    1.594_777_5,
+    -2.264_301_5e-1,
+    7.324_091e-2,
+    -1.25e-1,
+];
+
+const P1_ASYMPTOTIC_F32: [f32; 5] = [
+    -4.007_603_6,
+    6.434_511_5e-1,
+    -1.440_239_8e-1,
+    1.171_871_8e-1,
+    1.0,
+];
+
+const Q1_ASYMPTOTIC_F32: [f32; 5] = [
+    1.407_725_5e1,
+    -1.846_479_4,
+    2.768_257_3e-1,
+    -1.025_376_54e-1,
+    3.75e-1,
+];
+
+const J0_ROOTS_F32: [(f32, f32); 2] =
+    [(2.404_825_4, 1.087_059_05e-7), (5.520_078, -7.193_414_6e-8)];
+const J1_ROOTS_F32: [(f32, f32); 2] =
+    [(3.831_706, -7.685_059e-8), (7.015_587, -1.832_117_2e-7)];
+const Y0_ROOTS_F32: [(f32, f32); 3] = [
+    (8.935_77e-1, -1.335_797_9e-8),
+    (3.957_678_3, 1.012_911_8e-7),
+    (7.086_051, 7.305_812e-8),
+];
+const Y1_ROOTS_F32: [(f32, f32); 2] =
+    [(2.197_141_4, -8.288_927e-8), (5.429_681, 2.165_143_5e-7)];
+
+const SQRT_PI_F32: f32 = 1.772_453_9;
+
+const J0_SPLIT_F32: f32 = 4.0;
+const J1_SPLIT_F32: f32 = 5.0;
+const Y0_SPLIT_F32: [f32; 2] = [2.5, 5.5];
+const Y1_SPLIT_F32: f32 = 4.0;
+
+const BESSEL_BIG_F32: f32 = 8.0;
+
+// Below this Y1(x), which is about -2 / (pi x), overflows
+const Y1_TINY_F32: f32 = 1.870_858e-39;
+
+// Below this jnf uses the leading term of its power series
+const JN_TINY_F32: f32 = 1.220_703_1e-4;
+
+// Rescaling threshold of the backward recurrence for jnf
+const JN_RESCALE_F32: f32 = 1e20;
+
+// ln(2^-150), below which jnf rounds to zero
+const JN_LN_UNDERFLOW_F32: f32 = -104.0;
+
+#[inline(always)]
+fn root_distf(x: f32, hi: f32, lo: f32) -> f32 {
+    (x - hi) - lo
+}
+
+#[inline(always)]
+fn ln_ratiof(x: f32, hi: f32, lo: f32) -> f32 {
+    use std::f32::consts::LN_2;
+
+    let (x, k) = if x < f32::MIN_POSITIVE {
+        (x * SUBNORMAL_SCALE_F32, 25.0_f32)
+    } else {
+        (x, 0.0)
+    };
+
+    let q = x / hi;
+    let e = hi.mul_add(-q, x) - lo * q;
+
+    k.mul_add(-LN_2, lnf(q) + e / (hi * q))
+}
+
+fn j0f_small(x: f32) -> f32 {
+    if x < J0_SPLIT_F32 {
+        let (hi, lo) = J0_ROOTS_F32[0];
+        root_distf(x, hi, lo) * ((x + hi) * polyvalf(&J0_MINIMAX_1_F32, x * x))
+    } else {
+        let (hi, lo) = J0_ROOTS_F32[1];
+        root_distf(x, hi, lo) * polyvalf(&J0_MINIMAX_2_F32, x - 6.0)
+    }
+}
+
+fn j1f_small(x: f32) -> f32 {
+    if x < JN_TINY_F32 {
+        // The leading term x / 2, which also rounds subnormal results once
+        0.5 * x
+    } else if x < J1_SPLIT_F32 {
+        let (hi, lo) = J1_ROOTS_F32[0];
+        x * root_distf(x, hi, lo)
+            * (x + hi)
+            * polyvalf(&J1_MINIMAX_1_F32, x * x)
+    } else {
+        let (hi, lo) = J1_ROOTS_F32[1];
+        root_distf(x, hi, lo) * polyvalf(&J1_MINIMAX_2_F32, x - 6.5)
+    }
+}
+
+fn y0f_small(x: f32) -> f32 {
+    use std::f32::consts::FRAC_2_PI;
+
+    let ((hi, lo), p) = if x < Y0_SPLIT_F32[0] {
+        let root = Y0_ROOTS_F32[0];
+        (root, (x + root.0) * polyvalf(&Y0_MINIMAX_1_F32, x * x))
+    } else if x < Y0_SPLIT_F32[1] {
+        (Y0_ROOTS_F32[1], polyvalf(&Y0_MINIMAX_2_F32, x - 4.0))
+    } else {
+        (Y0_ROOTS_F32[2], polyvalf(&Y0_MINIMAX_3_F32, x - 6.75))
+    };
+
+    (FRAC_2_PI * ln_ratiof(x, hi, lo))
+        .mul_add(j0f_small(x), root_distf(x, hi, lo) * p)
+}
+
+fn y1f_small(x: f32) -> f32 {
+    use std::f32::consts::FRAC_2_PI;
+
+    let ((hi, lo), p) = if x < Y1_SPLIT_F32 {
+        let root = Y1_ROOTS_F32[0];
+        (root, (x + root.0) * polyvalf(&Y1_MINIMAX_1_F32, x * x))
+    } else {
+        (Y1_ROOTS_F32[1], polyvalf(&Y1_MINIMAX_2_F32, x - 6.0))
+    };
+
+    (FRAC_2_PI * ln_ratiof(x, hi, lo))
+        .mul_add(j1f_small(x), root_distf(x, hi, lo) * p / x)
+}
+
+fn sin_cos_diff_sumf(x: f32) -> (f32, f32) {
+    let (s, c) = sincosf(x);
+
+    let (ss, cc) = (s - c, s + c);
+
+    if !(x + x).is_finite() {
+        (ss, cc)
+    } else if s * c < 0.0 {
+        (ss, -cosf(x + x) / ss)
+    } else {
+        (-cosf(x + x) / cc, cc)
+    }
+}
+
+#[inline(always)]
+fn asymptotic_pqf(p: &[f32; 5], q: &[f32; 5], x: f32) -> (f32, f32) {
+    let r = x.recip();
+    let t = r * r;
+
+    (polyvalf(p, t), r * polyvalf(q, t))
+}
+
+/// The `f32` version of [`j0`].
+pub fn j0f(x: f32) -> f32 {
+    let x = x.abs();
+
+    if x < BESSEL_BIG_F32 {
+        j0f_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pqf(&P0_ASYMPTOTIC_F32, &Q0_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf(x);
+
+        p.mul_add(cc, -q * ss) / (SQRT_PI_F32 * x.sqrt())
+    }
+}
+
+/// The `f32` version of [`j1`].
+pub fn j1f(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if a < BESSEL_BIG_F32 {
+        j1f_small(a)
+    } else if a.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pqf(&P1_ASYMPTOTIC_F32, &Q1_ASYMPTOTIC_F32, a);
+        let (ss, cc) = sin_cos_diff_sumf(a);
+
+        p.mul_add(ss, q * cc) / (SQRT_PI_F32 * a.sqrt())
+    };
+
+    if x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// The `f32` version of [`y0`].
+pub fn y0f(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::NEG_INFINITY
+    } else if x < BESSEL_BIG_F32 {
+        y0f_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pqf(&P0_ASYMPTOTIC_F32, &Q0_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf(x);
+
+        p.mul_add(ss, q * cc) / (SQRT_PI_F32 * x.sqrt())
+    }
+}
+
+/// The `f32` version of [`y1`].
+pub fn y1f(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x < Y1_TINY_F32 {
+        f32::NEG_INFINITY
+    } else if x < BESSEL_BIG_F32 {
+        y1f_small(x)
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        let (p, q) = asymptotic_pqf(&P1_ASYMPTOTIC_F32, &Q1_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf(x);
+
+        q.mul_add(ss, -p * cc) / (SQRT_PI_F32 * x.sqrt())
+    }
+}
+
+fn jnf_forward(n: u32, x: f32) -> f32 {
+    let mut a = j0f(x);
+    let mut b = j1f(x);
+
+    for k in 1..n {
+        let c = (2.0 * k as f32 / x).mul_add(b, -a);
+        a = b;
+        b = c;
+    }
+
+    b
+}
+
+fn jnf_series(n: u32, x: f32) -> f32 {
+    let h = 0.5 * x;
+
+    let mut y = 1.0;
+    for k in 1..=n {
+        y *= h / k as f32;
+
+        if y == 0.0 {
+            break;
+        }
+    }
+
+    y
+}
+
+fn jnf_backward(n: u32, x: f32) -> f32 {
+    let mut bjp = 0.0;
+    let mut bj = 1.0;
+    let mut ans = 0.0;
+    let mut sum = 0.0;
+
+    for k in (1..=jn_start(n)).rev() {
+        let bjm = (2.0 * k as f32 / x).mul_add(bj, -bjp);
+        bjp = bj;
+        bj = bjm;
+
+        if bj.abs() > JN_RESCALE_F32 {
+            bj /= JN_RESCALE_F32;
+            bjp /= JN_RESCALE_F32;
+            ans /= JN_RESCALE_F32;
+            sum /= JN_RESCALE_F32;
+        }
+
+        if k % 2 == 1 {
+            sum += bj;
+        }
+
+        if k == n as u64 {
+            ans = bjp;
+        }
+    }
+
+    ans / 2.0f32.mul_add(sum, -bj)
+}
+
+fn jnf_ln_bound(n: u32, x: f32) -> f32 {
+    let n = n as f32;
+    let z = x / n;
+    let s = ((1.0 - z) * (1.0 + z)).sqrt();
+
+    n * ((lnf(z) + s - lnf(1.0 + s)) + 8.0 * f32::EPSILON)
+}
+
+fn jnf_pos(n: u32, x: f32) -> f32 {
+    if x > n as f32 {
+        jnf_forward(n, x)
+    } else if x < JN_TINY_F32 {
+        jnf_series(n, x)
+    } else if jnf_ln_bound(n, x) < JN_LN_UNDERFLOW_F32 {
+        0.0
+    } else {
+        jnf_backward(n, x)
+    }
+}
+
+/// The `f32` version of [`jn`].
+pub fn jnf(n: i32, x: f32) -> f32 {
+    let m = n.unsigned_abs();
+    let a = x.abs();
+
+    let y = match m {
+        0 => j0f(a),
+        1 => j1f(a),
+        _ => jnf_pos(m, a),
+    };
+
+    if m % 2 == 1 && (n < 0) != x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+fn root_distf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    hi: Simd<f32, LANES>,
+    lo: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (x - hi) - lo
+}
+
+#[inline(always)]
+fn ln_ratiof_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    hi: Simd<f32, LANES>,
+    lo: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::LN_2;
+
+    let subnormal = x.simd_lt(Simd::splat(f32::MIN_POSITIVE));
+    let x = subnormal.select(x * Simd::splat(SUBNORMAL_SCALE_F32), x);
+    let k = subnormal.select(Simd::splat(25.0), Simd::splat(0.0));
+
+    let q = x / hi;
+    let e = hi.mul_add(-q, x) - lo * q;
+
+    k.mul_add(Simd::splat(-LN_2), lnf_simd(q) + e / (hi * q))
+}
+
+#[inline(always)]
+fn select_rootf<const LANES: usize>(
+    mask: Mask<i32, LANES>,
+    a: (f32, f32),
+    b: (f32, f32),
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (
+        mask.select(Simd::splat(a.0), Simd::splat(b.0)),
+        mask.select(Simd::splat(a.1), Simd::splat(b.1)),
+    )
+}
+
+#[inline(always)]
+fn j0f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(J0_SPLIT_F32));
+    let (hi, lo) = select_rootf(small, J0_ROOTS_F32[0], J0_ROOTS_F32[1]);
+
+    let p1 = (x + Simd::splat(J0_ROOTS_F32[0].0))
+        * polyvalf_simd(&J0_MINIMAX_1_F32, x * x);
+    let p2 = polyvalf_simd(&J0_MINIMAX_2_F32, x - Simd::splat(6.0));
+
+    root_distf_simd(x, hi, lo) * small.select(p1, p2)
+}
+
+#[inline(always)]
+fn j1f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(J1_SPLIT_F32));
+    let (hi, lo) = select_rootf(small, J1_ROOTS_F32[0], J1_ROOTS_F32[1]);
+
+    let p1 = x
+        * root_distf_simd(x, hi, lo)
+        * (x + Simd::splat(J1_ROOTS_F32[0].0))
+        * polyvalf_simd(&J1_MINIMAX_1_F32, x * x);
+    let p2 = root_distf_simd(x, hi, lo)
+        * polyvalf_simd(&J1_MINIMAX_2_F32, x - Simd::splat(6.5));
+
+    x.simd_lt(Simd::splat(JN_TINY_F32))
+        .select(Simd::splat(0.5) * x, small.select(p1, p2))
+}
+
+#[inline(always)]
+fn y0f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::FRAC_2_PI;
+
+    let small = x.simd_lt(Simd::splat(Y0_SPLIT_F32[0]));
+    let mid = x.simd_lt(Simd::splat(Y0_SPLIT_F32[1]));
+
+    let (hi, lo) = select_rootf(mid, Y0_ROOTS_F32[1], Y0_ROOTS_F32[2]);
+    let (hi, lo) = (
+        small.select(Simd::splat(Y0_ROOTS_F32[0].0), hi),
+        small.select(Simd::splat(Y0_ROOTS_F32[0].1), lo),
+    );
+
+    let p1 = (x + Simd::splat(Y0_ROOTS_F32[0].0))
+        * polyvalf_simd(&Y0_MINIMAX_1_F32, x * x);
+    let p2 = polyvalf_simd(&Y0_MINIMAX_2_F32, x - Simd::splat(4.0));
+    let p3 = polyvalf_simd(&Y0_MINIMAX_3_F32, x - Simd::splat(6.75));
+    let p = small.select(p1, mid.select(p2, p3));
+
+    (Simd::splat(FRAC_2_PI) * ln_ratiof_simd(x, hi, lo))
+        .mul_add(j0f_small_simd(x), root_distf_simd(x, hi, lo) * p)
+}
+
+#[inline(always)]
+fn y1f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::FRAC_2_PI;
+
+    let small = x.simd_lt(Simd::splat(Y1_SPLIT_F32));
+    let (hi, lo) = select_rootf(small, Y1_ROOTS_F32[0], Y1_ROOTS_F32[1]);
+
+    let p1 = (x + Simd::splat(Y1_ROOTS_F32[0].0))
+        * polyvalf_simd(&Y1_MINIMAX_1_F32, x * x);
+    let p2 = polyvalf_simd(&Y1_MINIMAX_2_F32, x - Simd::splat(6.0));
+    let p = small.select(p1, p2);
+
+    (Simd::splat(FRAC_2_PI) * ln_ratiof_simd(x, hi, lo))
+        .mul_add(j1f_small_simd(x), root_distf_simd(x, hi, lo) * p / x)
+}
+
+#[inline(always)]
+fn sin_cos_diff_sumf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincosf_simd(x);
+
+    let ss = s - c;
+    let cc = s + c;
+
+    let z = -cosf_simd(x + x);
+    let finite = (x + x).is_finite();
+    let cancel = (s * c).simd_lt(Simd::splat(0.0));
+
+    (
+        (finite & !cancel).select(z / cc, ss),
+        (finite & cancel).select(z / ss, cc),
+    )
+}
+
+#[inline(always)]
+fn asymptotic_pqf_simd<const LANES: usize>(
+    p: &[f32; 5],
+    q: &[f32; 5],
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let r = x.recip();
+    let t = r * r;
+
+    (polyvalf_simd(p, t), r * polyvalf_simd(q, t))
+}
+
+/// The SIMD version of [`j0f`].
+#[inline(always)]
+pub fn j0f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.abs();
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG_F32));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = j0f_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) =
+            asymptotic_pqf_simd(&P0_ASYMPTOTIC_F32, &Q0_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf_simd(x);
+
+        let y_big =
+            p.mul_add(cc, -q * ss) / (Simd::splat(SQRT_PI_F32) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    x.is_infinite().select(Simd::splat(0.0), y)
+}
+
+/// The SIMD version of [`j1f`].
+#[inline(always)]
+pub fn j1f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+    let big = a.simd_ge(Simd::splat(BESSEL_BIG_F32));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = j1f_small_simd(a);
+    }
+
+    if big.any() {
+        let (p, q) =
+            asymptotic_pqf_simd(&P1_ASYMPTOTIC_F32, &Q1_ASYMPTOTIC_F32, a);
+        let (ss, cc) = sin_cos_diff_sumf_simd(a);
+
+        let y_big =
+            p.mul_add(ss, q * cc) / (Simd::splat(SQRT_PI_F32) * a.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = a.is_infinite().select(Simd::splat(0.0), y);
+
+    x.is_sign_negative().select(-y, y)
+}
+
+/// The SIMD version of [`y0f`].
+#[inline(always)]
+pub fn y0f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG_F32));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = y0f_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) =
+            asymptotic_pqf_simd(&P0_ASYMPTOTIC_F32, &Q0_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf_simd(x);
+
+        let y_big =
+            p.mul_add(ss, q * cc) / (Simd::splat(SQRT_PI_F32) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.is_infinite().select(zero, y);
+    let y = x.simd_eq(zero).select(Simd::splat(f32::NEG_INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f32::NAN), y)
+}
+
+/// The SIMD version of [`y1f`].
+#[inline(always)]
+pub fn y1f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(BESSEL_BIG_F32));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = y1f_small_simd(x);
+    }
+
+    if big.any() {
+        let (p, q) =
+            asymptotic_pqf_simd(&P1_ASYMPTOTIC_F32, &Q1_ASYMPTOTIC_F32, x);
+        let (ss, cc) = sin_cos_diff_sumf_simd(x);
+
+        let y_big =
+            q.mul_add(ss, -p * cc) / (Simd::splat(SQRT_PI_F32) * x.sqrt());
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.is_infinite().select(zero, y);
+    let y = x
+        .simd_lt(Simd::splat(Y1_TINY_F32))
+        .select(Simd::splat(f32::NEG_INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f32::NAN), y)
+}
+
+#[inline(always)]
+fn jnf_forward_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut a = j0f_simd(x);
+    let mut b = j1f_simd(x);
+
+    for k in 1..n {
+        let c = (Simd::splat(2.0 * k as f32) / x).mul_add(b, -a);
+        a = b;
+        b = c;
+    }
+
+    b
+}
+
+#[inline(always)]
+fn jnf_series_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let h = Simd::splat(0.5) * x;
+
+    let mut y = Simd::splat(1.0);
+    for k in 1..=n {
+        y *= h / Simd::splat(k as f32);
+
+        if y.simd_eq(Simd::splat(0.0)).all() {
+            break;
+        }
+    }
+
+    y
+}
+
+#[inline(always)]
+fn jnf_backward_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let rescale = Simd::splat(JN_RESCALE_F32);
+
+    let mut bjp = Simd::splat(0.0);
+    let mut bj = Simd::splat(1.0);
+    let mut ans = Simd::splat(0.0);
+    let mut sum = Simd::splat(0.0);
+
+    for k in (1..=jn_start(n)).rev() {
+        let bjm = (Simd::splat(2.0 * k as f32) / x).mul_add(bj, -bjp);
+        bjp = bj;
+        bj = bjm;
+
+        let huge = bj.abs().simd_gt(rescale);
+
+        if huge.any() {
+            bj = huge.select(bj / rescale, bj);
+            bjp = huge.select(bjp / rescale, bjp);
+            ans = huge.select(ans / rescale, ans);
+            sum = huge.select(sum / rescale, sum);
+        }
+
+        if k % 2 == 1 {
+            sum += bj;
+        }
+
+        if k == n as u64 {
+            ans = bjp;
+        }
+    }
+
+    ans / Simd::splat(2.0).mul_add(sum, -bj)
+}
+
+#[inline(always)]
+fn jnf_ln_bound_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let n = Simd::splat(n as f32);
+    let z = x / n;
+    let s = ((one - z) * (one + z)).sqrt();
+
+    n * ((lnf_simd(z) + s - lnf_simd(one + s))
+        + Simd::splat(8.0 * f32::EPSILON))
+}
+
+#[inline(always)]
+fn jnf_pos_simd<const LANES: usize>(
+    n: u32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let tiny_x = Simd::splat(JN_TINY_F32);
+
+    let forward = x.simd_gt(Simd::splat(n as f32));
+    let tiny = x.simd_lt(tiny_x);
+
+    let zero =
+        jnf_ln_bound_simd(n, x).simd_lt(Simd::splat(JN_LN_UNDERFLOW_F32));
+    let skip = forward | tiny | zero;
+
+    let mut y = Simd::splat(0.0);
+
+    if forward.any() {
+        y = forward.select(jnf_forward_simd(n, x), y);
+    }
+
+    if tiny.any() {
+        let xt = tiny.select(x, Simd::splat(0.0));
+
+        y = tiny.select(jnf_series_simd(n, xt), y);
+    }
+
+    if !skip.all() {
+        let y_back = jnf_backward_simd(n, tiny.select(tiny_x, x));
+
+        y = skip.select(y, y_back);
+    }
+
+    y
+}
+
+/// The SIMD version of [`jnf`].
+#[inline(always)]
+pub fn jnf_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let m = n.unsigned_abs();
+    let a = x.abs();
+
+    let y = match m {
+        0 => j0f_simd(a),
+        1 => j1f_simd(a),
+        _ => jnf_pos_simd(m, a),
+    };
+
+    if m % 2 == 1 {
+        let flip = x.is_sign_negative() ^ Mask::splat(n < 0);
+        flip.select(-y, y)
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::PI, simd::Simd};
+
+    use crate::*;
+
+    // Error bound relative to the envelope sqrt(2 / (pi x)) wherever the
+    // functions oscillate and the asymptotic forms or the forward recurrence
+    // make the error absolute rather than relative around the zeros
+    fn bessel_error(y: f64, y_ref: f64, envelope: f64) -> bool {
+        y == y_ref
+            || (y - y_ref).abs()
+                <= 8.0 * f64::EPSILON * y_ref.abs().max(envelope)
+    }
+
+    // The same for the f32 versions, against the f64 versions at the same
+    // point
+    fn bessel_errorf(y: f32, y_ref: f64, envelope: f64) -> bool {
+        let scale = y_ref.abs().max(envelope).max(f32::MIN_POSITIVE as f64);
+
+        y as f64 == y_ref
+            || (y as f64 - y_ref).abs() <= 8.0 * f32::EPSILON as f64 * scale
+    }
+
+    fn envelope(x: f64) -> f64 {
+        (2.0 / (PI * x)).sqrt()
+    }
+
+    #[test]
+    fn test_bessel() {
+        const INF: f64 = f64::INFINITY;
+
+        // (x, j0(x), j1(x), y0(x), y1(x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (5e-324, 1.0, 0.0, -473.9990734230043, -INF),
+            (1e-320, 1.0, 5e-321, -469.15259466535355, -INF),
+            (2.2e-308, 1.0, 1.1e-308, -451.0601856595595, -2.8937262380344605e307),
+            (1e-300, 1.0, 5e-301, -439.8351636227653, -6.366197723675813e299),
+            (1e-5, 0.999999999975, 4.9999999999375e-6, -7.40316028370197, -63661.97727536548),
+            (0.5, 0.9384698072408129, 0.2422684576748739, -0.44451873350670656, -1.471472392670243),
+            (0.8935769662791675, 0.8101238593535642, 0.4036537406955804, -2.3389279284062102e-17, -0.8794208024971948),
+            (1.0, 0.7651976865579666, 0.4400505857449335, 0.08825696421567696, -0.7812128213002887),
+            (2.404825557695773, -6.10876525973673e-17, 0.5191474972894667, 0.509924383448479, 0.1027466824382596),
+            (3.0, -0.26005195490193345, 0.3390589585259365, 0.3768500100127904, 0.3246744247918),
+            (3.8317059702075125, -0.402759395702553, -6.149807356994906e-17, 0.0513976730994109, 0.41251739515882574),
+            (5.0, -0.1775967713143383, -0.32757913759146523, -0.30851762524903376, 0.14786314339122683),
+            (7.5, 0.2663396578803784, 0.1352484275797055, 0.11731328614820863, -0.25912851048611624),
+            (8.0, 0.1716508071375539, 0.23463634685391463, 0.22352148938756622, -0.1580604617312475),
+            (12.3, 0.11079795030758544, -0.1942588480405914, -0.1985930946350262, -0.11894840329926616),
+            (100.0, 0.019985850304223122, -0.07714535201411216, -0.07724431336508315, -0.020372312002759792),
+            (1e10, 2.175591750246892e-6, -7.676508175684158e-6, -7.676508175792937e-6, -2.175591750630717e-6),
+            (1e300, -7.860673062724093e-151, -1.3681360450342481e-151, -1.3681360450342481e-151, 7.860673062724093e-151),
+        ];
+
+        for (x, j0_ref, j1_ref, y0_ref, y1_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+            let env = if x < 8.0 { 0.0 } else { envelope(x) };
+
+            assert!(bessel_error(j0(x), j0_ref, env), "{x}");
+            assert!(bessel_error(j1(x), j1_ref, env), "{x}");
+            assert!(bessel_error(y0(x), y0_ref, env), "{x}");
+            assert!(bessel_error(y1(x), y1_ref, env), "{x}");
+
+            assert_eq!(j0(-x), j0(x), "{x}");
+            assert_eq!(j1(-x), -j1(x), "{x}");
+
+            assert_eq!(j0_simd(xs)[0], j0(x), "{x}");
+            assert_eq!(j1_simd(xs)[0], j1(x), "{x}");
+            assert_eq!(y0_simd(xs)[0], y0(x), "{x}");
+            assert_eq!(y1_simd(xs)[0], y1(x), "{x}");
+            assert_eq!(j1_simd(-xs)[0], j1(-x), "{x}");
+            assert_eq!(xs.j0()[0], j0(x), "{x}");
+            assert_eq!(xs.y1()[0], y1(x), "{x}");
+            assert_eq!(jn(0, x), j0(x), "{x}");
+            assert_eq!(jn(1, x), j1(x), "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let xd = xf as f64;
+            let envf = if xd < 8.0 { 0.0 } else { envelope(xd) };
+            assert!(bessel_errorf(j0f(xf), j0(xd), envf), "{x}");
+            assert!(bessel_errorf(j1f(xf), j1(xd), envf), "{x}");
+            assert!(bessel_errorf(y0f(xf), y0(xd), envf), "{x}");
+            assert!(bessel_errorf(y1f(xf), y1(xd), envf), "{x}");
+            assert_eq!(j1f(-xf), -j1f(xf), "{x}");
+            assert_eq!(j0f_simd(xfs)[0], j0f(xf), "{x}");
+            assert_eq!(j1f_simd(xfs)[0], j1f(xf), "{x}");
+            assert_eq!(y0f_simd(xfs)[0], y0f(xf), "{x}");
+            assert_eq!(y1f_simd(xfs)[0], y1f(xf), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_jn() {
+        // (n, x, jn(n, x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (2, 0.5, 0.03060402345868264),
+            (2, 8.0, -0.11299172042407525),
+            (3, 1e-5, 2.083333333320313e-17),
+            (3, 2.5, 0.21660039103911352),
+            (5, 4.9, 0.24801683034416155),
+            (5, 30.0, -0.14324029551207706),
+            (10, 1.0, 2.6306151236874534e-10),
+            (10, 9.9, 0.19901352409053374),
+            (10, 10.1, 0.2158741725304777),
+            (20, 15.0, 0.007360234079223486),
+            (20, 100.0, 0.062217458498338755),
+            (50, 45.0, 0.017284343240791224),
+            (50, 1e-3, 2.920285702604064e-230),
+            (200, 10.0, 6.967530155393544e-236),
+        ];
+
+        for (n, x, y_ref) in table {
+            let xs = Simd::<f64, 4>::from([x, 1e-20, 0.5 * n as f64, 1e3]);
+            let env = if x > n as f64 { envelope(x) } else { 0.0 };
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+
+            assert!(bessel_error(jn(n, x), y_ref, env), "{n} {x}");
+            assert_eq!(jn(-n, x), sign * jn(n, x), "{n} {x}");
+            assert_eq!(jn(n, -x), sign * jn(n, x), "{n} {x}");
+            assert_eq!(jn(-n, -x), jn(n, x), "{n} {x}");
+
+            assert_eq!(jn_simd(n, xs)[0], jn(n, x), "{n} {x}");
+            assert_eq!(jn_simd(-n, -xs)[0], jn(n, x), "{n} {x}");
+            assert_eq!(xs.jn(n)[0], jn(n, x), "{n} {x}");
+
+            let xf = x as f32;
+            let xd = xf as f64;
+            let envf = if xd > n as f64 { envelope(xd) } else { 0.0 };
+            assert!(bessel_errorf(jnf(n, xf), jn(n, xd), envf), "{n} {x}");
+            assert_eq!(jnf(-n, -xf), jnf(n, xf), "{n} {x}");
+            assert_eq!(
+                jnf_simd(n, Simd::<f32, 4>::splat(xf))[0],
+                jnf(n, xf),
+                "{n} {x}"
+            );
+        }
+
+        // Orders far above x round to zero without running the recurrence
+        for (n, x) in [
+            (i32::MAX, 10.0),
+            (i32::MIN, -10.0),
+            (i32::MAX, 2e9),
+            (300, 10.0),
+            (2000, 1e3),
+        ] {
+            let xs = Simd::<f64, 4>::from([x, 1e-20, 0.5 * x, -x]);
+            let xfs = Simd::<f32, 4>::from([
+                x as f32,
+                1e-20,
+                0.5 * x as f32,
+                -x as f32,
+            ]);
+
+            assert_eq!(jn(n, x), 0.0, "{n} {x}");
+            assert_eq!(jn_simd(n, xs), Simd::splat(0.0), "{n} {x}");
+            assert_eq!(jnf(n, x as f32), 0.0, "{n} {x}");
+            assert_eq!(jnf_simd(n, xfs), Simd::splat(0.0), "{n} {x}");
+        }
+    }
+
+    #[test]
+    fn test_bessel_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        // (x, [j0(x), j1(x), y0(x), y1(x), jn(2, x), jn(3, x)])
+        #[rustfmt::skip]
+        let table = [
+            (0.0, [1.0, 0.0, -INF, -INF, 0.0, 0.0]),
+            (-0.0, [1.0, -0.0, -INF, -INF, 0.0, -0.0]),
+            (5e-324, [1.0, 0.0, y0(5e-324), -INF, 0.0, 0.0]),
+            (-5e-324, [1.0, -0.0, NAN, NAN, 0.0, -0.0]),
+            (-1.0, [j0(1.0), -j1(1.0), NAN, NAN, jn(2, 1.0), -jn(3, 1.0)]),
+            (INF, [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            (-INF, [0.0, -0.0, NAN, NAN, 0.0, -0.0]),
+            (NAN, [NAN, NAN, NAN, NAN, NAN, NAN]),
+        ];
+
+        for (x, y_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            let ys = [
+                (j0(x), j0_simd(xs)[0]),
+                (j1(x), j1_simd(xs)[0]),
+                (y0(x), y0_simd(xs)[0]),
+                (y1(x), y1_simd(xs)[0]),
+                (jn(2, x), jn_simd(2, xs)[0]),
+                (jn(3, x), jn_simd(3, xs)[0]),
+            ];
+
+            for ((y, y_simd), y_ref) in ys.into_iter().zip(y_ref) {
+                let same = |y: f64| {
+                    y.to_bits() == y_ref.to_bits()
+                        || y.is_nan() && y_ref.is_nan()
+                };
+
+                assert!(same(y), "{x} {y} {y_ref}");
+                assert!(same(y_simd), "{x} {y_simd} {y_ref}");
+            }
+        }
+
+        // The f32 versions have the same special values
+        for x in [
+            0.0f32,
+            -0.0,
+            1e-45,
+            -1e-45,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ] {
+            let xs = Simd::<f32, 4>::splat(x);
+            let xd = x as f64;
+
+            let ys = [
+                (j0f(x), j0f_simd(xs)[0], j0(xd)),
+                (j1f(x), j1f_simd(xs)[0], j1(xd)),
+                (y0f(x), y0f_simd(xs)[0], y0(xd)),
+                (y1f(x), y1f_simd(xs)[0], y1(xd)),
+                (jnf(2, x), jnf_simd(2, xs)[0], jn(2, xd)),
+                (jnf(3, x), jnf_simd(3, xs)[0], jn(3, xd)),
+            ];
+
+            for (y, y_simd, y_ref) in ys {
+                let same = |y: f32| {
+                    y.to_bits() == (y_ref as f32).to_bits()
+                        || y.is_nan() && y_ref.is_nan()
+                };
+
+                assert!(same(y), "{x} {y} {y_ref}");
+                assert!(same(y_simd), "{x} {y_simd} {y_ref}");
+            }
+        }
+
+        assert!(y0f(-1.0).is_nan());
+        assert!(y1f_simd(Simd::<f32, 4>::splat(-1.0))[0].is_nan());
+    }
+}
@@ -0,0 +1,660 @@
+use std::simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount};
+
+use crate::{
+    exp, exp_simd, expf, expf_simd, ln, ln_simd, lnf, lnf_simd, polyval,
+    polyval_simd, polyvalf, polyvalf_simd,
+};
+
+// f(z) = erf(x) / x - 1 in terms of z = x^2
+// domain: |x| <= 0.75
+const ERF_MINIMAX: [f64; 11] = [
+    1.1463755544334476e-08,
+    -1.5937599276605997e-07,
+    1.6430388115030847e-06,
+    -1.4924176629811083e-05,
+    0.00012055288776907262,
+    -0.0008548326169083397,
+    0.00522397761512218,
+    -0.02686617064440247,
+    0.11283791670952481,
+    -0.37612638903183715,
+    0.1283791670955126,
+];
+
+// f(y) = (x + 4) exp(x^2) erfc(x), y = (x - 4) / (x + 4)
+// domain: 0.75 <= x <= 28
+const ERFC_MINIMAX: [f64; 21] = [
+    -1.111980217494715e-08,
+    5.836167897536532e-10,
+    1.1717207598806271e-07,
+    -7.451081445889611e-08,
+    -8.403608471154243e-07,
+    1.4231221588323815e-06,
+    4.691530001065646e-06,
+    -1.886031114232686e-05,
+    -3.6342848104481887e-06,
+    0.00017681264018240924,
+    -0.0004550528556801729,
+    -0.0002809588327754015,
+    0.006112055676257982,
+    -0.026370053343791843,
+    0.0763815149078717,
+    -0.17401093723973884,
+    0.33085158787806385,
+    -0.54085383131324,
+    0.7732087022652364,
+    -0.9765487290808819,
+    1.095995661000491,
+];
+
+// f(v) = erfinv(x) / x, v = w - 3.125, w = -ln(1 - x^2)
+// domain: 0 <= w <= 6.25
+const ERFINV_CENTRAL: [f64; 24] = [
+    3.3757473558319e-21,
+    -1.0090195343018837e-21,
+    -3.575498236752015e-19,
+    1.1444086535071092e-18,
+    1.575978118589624e-17,
+    -1.3003901196808538e-16,
+    -4.28657207516132e-17,
+    6.594699332554525e-15,
+    -3.9989776653931004e-14,
+    -8.117008231965965e-14,
+    2.6303545569102642e-12,
+    -1.2976958306378151e-11,
+    -5.414238989324638e-11,
+    1.051218398504727e-09,
+    -4.112661977968645e-09,
+    -2.9070382775121806e-08,
+    4.2347881907783596e-07,
+    -1.3654691844344232e-06,
+    -1.3882523395791712e-05,
+    0.00018673420802425603,
+    -0.0007407025341539548,
+    -0.006033670871427754,
+    0.24015818242558826,
+    1.6536545626831027,
+];
+
+// f(s) = erfinv(x) / x, s = sqrt(w) - 3.25
+// domain: 6.25 <= w <= 16
+const ERFINV_TAIL: [f64; 20] = [
+    -1.6187421282767245e-08,
+    6.343353272557722e-10,
+    1.3394224624397062e-07,
+    -2.7118675560987385e-07,
+    -3.026589653790519e-08,
+    1.49854615114152e-06,
+    -3.984077279723229e-06,
+    2.9258240728756043e-06,
+    1.2464433131370779e-05,
+    -4.731901554923047e-05,
+    6.828724879809689e-05,
+    2.403126312969912e-05,
+    -0.0003550378308275893,
+    0.0009532893630754274,
+    -0.0016882755342518287,
+    0.0024914420970183248,
+    -0.0037512085082629424,
+    0.00537091455357091,
+    1.0052589676941657,
+    3.0838856104922208,
+];
+
+// f(s) = erfinv(x) / x, s = sqrt(w) - 5
+// domain: 16 <= w <= 37.5, which covers every x below 1
+const ERFINV_FAR_TAIL: [f64; 20] = [
+    4.888996226527109e-12,
+    -2.0424843874433256e-11,
+    1.954445338847353e-11,
+    6.973660783516087e-11,
+    -3.9060872752693665e-10,
+    1.3156510551244387e-09,
+    -3.523167579707724e-09,
+    7.821805447486429e-09,
+    -1.5220940012722592e-08,
+    2.901841316863821e-08,
+    -6.75698231303916e-08,
+    2.290529094365573e-07,
+    -9.930261802970936e-07,
+    4.526052505204442e-06,
+    -1.9681771108696593e-05,
+    7.599527809922643e-05,
+    -0.00021503011980422273,
+    -0.00013871931838025747,
+    1.010300464864545,
+    4.849906401408584,
+];
+
+// Below this erf uses its own polynomial, above it erfc does
+const ERF_SMALL: f64 = 0.75;
+
+// Beyond this erfc(x) underflows to 0
+const ERFC_MAX: f64 = 27.4;
+
+// Boundaries between the erfinv polynomials, in terms of w
+const ERFINV_TAIL_W: f64 = 6.25;
+const ERFINV_FAR_TAIL_W: f64 = 16.0;
+
+// erf(x) / x - 1 for |x| <= ERF_SMALL, in terms of z = x^2
+fn erf_poly(z: f64) -> f64 {
+    polyval(&ERF_MINIMAX, z)
+}
+
+// erfc(a) for a >= ERF_SMALL. 1 - erf(a) would cancel here, so erfc is the
+// primary function and erf is derived from it. Dividing by a + 4 rather than
+// multiplying with its reciprocal saves a rounding, which is worth about an ulp.
+fn erfc_tail(a: f64) -> f64 {
+    if a >= ERFC_MAX {
+        return 0.0;
+    }
+
+    let d = a + 4.0;
+    let y = (a - 4.0) / d;
+
+    // a^2 = hi + lo exactly, and exp(-a^2) = exp(-hi) (1 - lo) because lo is
+    // far below the precision of the result
+    let hi = a * a;
+    let lo = a.mul_add(a, -hi);
+    let e = exp(-hi);
+
+    e.mul_add(-lo, e) * polyval(&ERFC_MINIMAX, y) / d
+}
+
+/// Computes the error function `2 / sqrt(pi) * int_0^x exp(-t^2) dt`.
+pub fn erf(x: f64) -> f64 {
+    let a = x.abs();
+
+    if a < ERF_SMALL {
+        x.mul_add(erf_poly(x * x), x)
+    } else {
+        (1.0 - erfc_tail(a)).copysign(x)
+    }
+}
+
+/// Computes `1 - erf(x)`, accurate also where it is much smaller than 1.
+pub fn erfc(x: f64) -> f64 {
+    if x.abs() < ERF_SMALL {
+        // x - 0.5 is exact for x >= 0.25, which keeps the cancellation in
+        // 1 - erf(x) from costing accuracy
+        0.5 - x.mul_add(erf_poly(x * x), x - 0.5)
+    } else if x > 0.0 {
+        erfc_tail(x)
+    } else {
+        2.0 - erfc_tail(-x)
+    }
+}
+
+/// Computes the inverse of `erf`, so that `erf(erfinv(x)) = x` for `x` in
+/// `[-1, 1]`.
+pub fn erfinv(x: f64) -> f64 {
+    let a = x.abs();
+
+    if a == 1.0 {
+        return f64::INFINITY.copysign(x);
+    }
+
+    // NaN for |x| > 1
+    let w = -ln((1.0 - a) * (1.0 + a));
+
+    let p = if w < ERFINV_TAIL_W {
+        polyval(&ERFINV_CENTRAL, w - 3.125)
+    } else if w < ERFINV_FAR_TAIL_W {
+        polyval(&ERFINV_TAIL, w.sqrt() - 3.25)
+    } else {
+        polyval(&ERFINV_FAR_TAIL, w.sqrt() - 5.0)
+    };
+
+    p * x
+}
+
+#[inline(always)]
+fn erfc_tail_simd<const LANES: usize>(a: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let d = a + Simd::splat(4.0);
+    let y = (a - Simd::splat(4.0)) / d;
+
+    let hi = a * a;
+    let lo = a.mul_add(a, -hi);
+    let e = exp_simd(-hi);
+
+    let y = e.mul_add(-lo, e) * polyval_simd(&ERFC_MINIMAX, y) / d;
+
+    // Infinite lanes come out as NaN above
+    a.simd_ge(Simd::splat(ERFC_MAX)).select(Simd::splat(0.0), y)
+}
+
+/// The SIMD version of [`erf`].
+#[inline(always)]
+pub fn erf_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+    let small = a.simd_lt(Simd::splat(ERF_SMALL));
+
+    let y = x.mul_add(polyval_simd(&ERF_MINIMAX, x * x), x);
+
+    if small.all() {
+        return y;
+    }
+
+    let y_tail = (Simd::splat(1.0) - erfc_tail_simd(a)).copysign(x);
+
+    small.select(y, y_tail)
+}
+
+/// The SIMD version of [`erfc`].
+#[inline(always)]
+pub fn erfc_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let half = Simd::splat(0.5);
+    let small = x.abs().simd_lt(Simd::splat(ERF_SMALL));
+
+    let y = half - x.mul_add(polyval_simd(&ERF_MINIMAX, x * x), x - half);
+
+    if small.all() {
+        return y;
+    }
+
+    let y_tail = erfc_tail_simd(x.abs());
+    let y_tail = x
+        .is_sign_negative()
+        .select(Simd::splat(2.0) - y_tail, y_tail);
+
+    small.select(y, y_tail)
+}
+
+/// The SIMD version of [`erfinv`].
+#[inline(always)]
+pub fn erfinv_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let w = -ln_simd((one - a) * (one + a));
+
+    let mut p = polyval_simd(&ERFINV_CENTRAL, w - Simd::splat(3.125));
+
+    // The tails are rare, so their polynomials are only evaluated when some
+    // lane needs them. NaN lanes end up in the far tail, which keeps them NaN.
+    let central = w.simd_lt(Simd::splat(ERFINV_TAIL_W));
+
+    if !central.all() {
+        let s = w.sqrt();
+        let near = w.simd_lt(Simd::splat(ERFINV_FAR_TAIL_W));
+
+        let p_tail = polyval_simd(&ERFINV_TAIL, s - Simd::splat(3.25));
+        let p_far = polyval_simd(&ERFINV_FAR_TAIL, s - Simd::splat(5.0));
+
+        p = central.select(p, near.select(p_tail, p_far));
+    }
+
+    a.simd_eq(one)
+        .select(Simd::splat(f64::INFINITY), p * a)
+        .copysign(x)
+}
+
+// f(z) = erf(x) / x - 1 in terms of z = x^2
+// domain: |x| <= 0.75
+const ERF_MINIMAX_F32: [f32; 6] = [
+    -6.756_48e-4,
+    5.115_332e-3,
+    -2.683_511_4e-2,
+    1.128_338_65e-1,
+    -3.761_262e-1,
+    1.283_791_7e-1,
+];
+
+// f(y) = (x + 4) exp(x^2) erfc(x), y = (x - 4) / (x + 4)
+// domain: 0.75 <= x <= 10.13
+const ERFC_MINIMAX_F32: [f32; 11] = [
+    -6.628_969_6e-4,
+    -2.669_713_6e-4,
+    6.208_801_6e-3,
+    -2.635_333_3e-2,
+    7.636_542e-2,
+    -1.740_145_5e-1,
+    3.308_527_2e-1,
+    -5.408_536e-1,
+    7.732_087e-1,
+    -9.765_487_3e-1,
+    1.095_995_7,
+];
+
+// f(v) = erfinv(x) / x, v = w - 3.125, w = -ln(1 - x^2)
+// domain: 0 <= w <= 6.25
+const ERFINV_CENTRAL_F32: [f32; 11] = [
+    6.983_681e-10,
+    -4.607_646_7e-9,
+    -2.565_287_7e-8,
+    4.256_628e-7,
+    -1.379_974_3e-6,
+    -1.388_702e-5,
+    1.867_594e-4,
+    -7.406_987e-4,
+    -6.033_683e-3,
+    2.401_581_9e-1,
+    1.653_654_6,
+];
+
+// f(s) = erfinv(x) / x, s = sqrt(w) - 3.25
+// domain: 6.25 <= w <= 16, which covers every f32 below 1
+const ERFINV_TAIL_F32: [f32; 8] = [
+    -2.696_583_4e-4,
+    9.413_204e-4,
+    -1.719_108_9e-3,
+    2.499_769e-3,
+    -3.747_705_6e-3,
+    5.369_796_4e-3,
+    1.005_258_9,
+    3.083_885_7,
+];
+
+const ERF_SMALL_F32: f32 = 0.75;
+const ERFCF_MAX: f32 = 10.13;
+const ERFINV_TAIL_W_F32: f32 = 6.25;
+
+// erfc(a) for a >= ERF_SMALL_F32, as in erfc_tail
+fn erfcf_tail(a: f32) -> f32 {
+    if a >= ERFCF_MAX {
+        return 0.0;
+    }
+
+    let d = a + 4.0;
+    let y = (a - 4.0) / d;
+
+    let hi = a * a;
+    let lo = a.mul_add(a, -hi);
+    let e = expf(-hi);
+
+    e.mul_add(-lo, e) * polyvalf(&ERFC_MINIMAX_F32, y) / d
+}
+
+/// The `f32` version of [`erf`].
+pub fn erff(x: f32) -> f32 {
+    let a = x.abs();
+
+    if a < ERF_SMALL_F32 {
+        x.mul_add(polyvalf(&ERF_MINIMAX_F32, x * x), x)
+    } else {
+        (1.0 - erfcf_tail(a)).copysign(x)
+    }
+}
+
+/// The `f32` version of [`erfc`].
+pub fn erfcf(x: f32) -> f32 {
+    if x.abs() < ERF_SMALL_F32 {
+        0.5 - x.mul_add(polyvalf(&ERF_MINIMAX_F32, x * x), x - 0.5)
+    } else if x > 0.0 {
+        erfcf_tail(x)
+    } else {
+        2.0 - erfcf_tail(-x)
+    }
+}
+
+/// The `f32` version of [`erfinv`].
+pub fn erfinvf(x: f32) -> f32 {
+    let a = x.abs();
+
+    if a == 1.0 {
+        return f32::INFINITY.copysign(x);
+    }
+
+    let w = -lnf((1.0 - a) * (1.0 + a));
+
+    let p = if w < ERFINV_TAIL_W_F32 {
+        polyvalf(&ERFINV_CENTRAL_F32, w - 3.125)
+    } else {
+        polyvalf(&ERFINV_TAIL_F32, w.sqrt() - 3.25)
+    };
+
+    p * x
+}
+
+#[inline(always)]
+fn erfcf_tail_simd<const LANES: usize>(a: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let d = a + Simd::splat(4.0);
+    let y = (a - Simd::splat(4.0)) / d;
+
+    let hi = a * a;
+    let lo = a.mul_add(a, -hi);
+    let e = expf_simd(-hi);
+
+    let y = e.mul_add(-lo, e) * polyvalf_simd(&ERFC_MINIMAX_F32, y) / d;
+
+    a.simd_ge(Simd::splat(ERFCF_MAX))
+        .select(Simd::splat(0.0), y)
+}
+
+/// The SIMD version of [`erff`].
+#[inline(always)]
+pub fn erff_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+    let small = a.simd_lt(Simd::splat(ERF_SMALL_F32));
+
+    let y = x.mul_add(polyvalf_simd(&ERF_MINIMAX_F32, x * x), x);
+
+    if small.all() {
+        return y;
+    }
+
+    let y_tail = (Simd::splat(1.0) - erfcf_tail_simd(a)).copysign(x);
+
+    small.select(y, y_tail)
+}
+
+/// The SIMD version of [`erfcf`].
+#[inline(always)]
+pub fn erfcf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let half = Simd::splat(0.5);
+    let small = x.abs().simd_lt(Simd::splat(ERF_SMALL_F32));
+
+    let y = half - x.mul_add(polyvalf_simd(&ERF_MINIMAX_F32, x * x), x - half);
+
+    if small.all() {
+        return y;
+    }
+
+    let y_tail = erfcf_tail_simd(x.abs());
+    let y_tail = x
+        .is_sign_negative()
+        .select(Simd::splat(2.0) - y_tail, y_tail);
+
+    small.select(y, y_tail)
+}
+
+/// The SIMD version of [`erfinvf`].
+#[inline(always)]
+pub fn erfinvf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let w = -lnf_simd((one - a) * (one + a));
+
+    let mut p = polyvalf_simd(&ERFINV_CENTRAL_F32, w - Simd::splat(3.125));
+
+    let central = w.simd_lt(Simd::splat(ERFINV_TAIL_W_F32));
+
+    if !central.all() {
+        let p_tail =
+            polyvalf_simd(&ERFINV_TAIL_F32, w.sqrt() - Simd::splat(3.25));
+
+        p = central.select(p, p_tail);
+    }
+
+    a.simd_eq(one)
+        .select(Simd::splat(f32::INFINITY), p * a)
+        .copysign(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{tests::ulp_diff, *};
+
+    // (x, erf(x), erfc(x)), correctly rounded
+    #[rustfmt::skip]
+    const ERF_TABLE: [(f64, f64, f64); 22] = [
+        (1e-300, 1.1283791670955126e-300, 1.0),
+        (1e-10, 1.1283791670955126e-10, 0.999999999887162),
+        (0.1, 0.1124629160182849, 0.887537083981715),
+        (0.5, 0.5204998778130465, 0.4795001221869535),
+        (0.74, 0.7046780778547458, 0.2953219221452542),
+        (0.75, 0.7111556336535151, 0.28884436634648486),
+        (0.8287059875595566, 0.7587897936646684, 0.24121020633533166),
+        (1.0, 0.8427007929497149, 0.15729920705028513),
+        (1.5, 0.9661051464753108, 0.033894853524689274),
+        (2.0, 0.9953222650189527, 0.004677734981047266),
+        (3.0, 0.9999779095030014, 2.209049699858544e-5),
+        (4.0, 0.9999999845827421, 1.541725790028002e-8),
+        (6.0, 1.0, 2.1519736712498913e-17),
+        (10.0, 1.0, 2.088487583762545e-45),
+        (15.0, 1.0, 7.212994172451207e-100),
+        (20.0, 1.0, 5.395865611607901e-176),
+        (25.0, 1.0, 8.300172571196523e-274),
+        (27.0, 1.0, 5.23705e-319),
+        (-0.3, -0.3286267594591274, 1.3286267594591274),
+        (-1.0, -0.8427007929497149, 1.8427007929497148),
+        (-2.5, -0.999593047982555, 1.999593047982555),
+        (-5.0, -0.9999999999984626, 1.9999999999984626),
+    ];
+
+    #[test]
+    fn test_erf_ulp() {
+        for (x, erf_ref, erfc_ref) in ERF_TABLE {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(erf(x), erf_ref) <= 2, "{x}");
+            assert!(ulp_diff(erfc(x), erfc_ref) <= 4, "{x}");
+            assert_eq!(erf_simd(xs)[0], erf(x), "{x}");
+            assert_eq!(erfc_simd(xs)[0], erfc(x), "{x}");
+            assert_eq!(xs.erf()[0], erf(x), "{x}");
+            assert_eq!(xs.erfc()[0], erfc(x), "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let erff_ref = erf(xf as f64) as f32;
+            let erfcf_ref = erfc(xf as f64) as f32;
+            assert!(
+                erff(xf).to_bits().abs_diff(erff_ref.to_bits()) <= 2,
+                "{x}"
+            );
+            assert!(
+                erfcf(xf).to_bits().abs_diff(erfcf_ref.to_bits()) <= 5,
+                "{x}"
+            );
+            assert_eq!(erff_simd(xfs)[0], erff(xf), "{x}");
+            assert_eq!(xfs.erfc()[0], erfcf(xf), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_erf_special_values() {
+        const INF: f64 = f64::INFINITY;
+
+        for (x, erf_ref, erfc_ref) in [
+            (0.0, 0.0, 1.0),
+            (-0.0, -0.0, 1.0),
+            (INF, 1.0, 0.0),
+            (-INF, -1.0, 2.0),
+            (30.0, 1.0, 0.0),
+            (f64::NAN, f64::NAN, f64::NAN),
+        ] {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            assert_eq!(ulp_diff(erf(x), erf_ref), 0, "{x}");
+            assert_eq!(ulp_diff(erfc(x), erfc_ref), 0, "{x}");
+            assert_eq!(ulp_diff(erf_simd(xs)[0], erf_ref), 0, "{x}");
+            assert_eq!(ulp_diff(erfc_simd(xs)[0], erfc_ref), 0, "{x}");
+        }
+
+        assert!(erf(-0.0).is_sign_negative());
+        assert!(erf_simd(Simd::<f64, 4>::splat(-0.0))[0].is_sign_negative());
+    }
+
+    #[test]
+    fn test_erfinv() {
+        #[rustfmt::skip]
+        let table = [
+            (1e-300, 8.86226925452758e-301),
+            (1e-8, 8.86226925452758e-9),
+            (0.1, 0.08885599049425769),
+            (0.5, 0.4769362762044699),
+            (0.9, 1.1630871536766743),
+            (0.99, 1.8213863677184494),
+            (0.999999, 3.458910737275499),
+            (0.9999999999990905, 5.05125408524939),
+            (0.9999999999999999, 5.8635847487551676),
+            (-0.7, -0.7328690779592167),
+            (-0.9999, -2.75106390571208),
+        ];
+
+        for (x, y_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(erfinv(x), y_ref) <= 4, "{x}");
+            assert_eq!(erfinv_simd(xs)[0], erfinv(x), "{x}");
+            assert_eq!(xs.erf_inv()[0], erfinv(x), "{x}");
+
+            let xf = x as f32;
+            let yf_ref = erfinv(xf as f64) as f32;
+            if yf_ref.is_finite() {
+                assert!(
+                    erfinvf(xf).to_bits().abs_diff(yf_ref.to_bits()) <= 2,
+                    "{x}"
+                );
+            }
+            assert_eq!(erfinvf_simd(Simd::<f32, 4>::splat(xf))[0], erfinvf(xf));
+        }
+
+        for i in 1..1000 {
+            let x = -1.0 + i as f64 / 500.0;
+            let y = erfinv(x);
+
+            assert!((erf(y) - x).abs() <= 4e-16, "{x}");
+            assert_eq!(erfinv_simd(Simd::<f64, 4>::splat(x))[0], y);
+        }
+
+        let special = [
+            (1.0, f64::INFINITY),
+            (-1.0, -f64::INFINITY),
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (1.5, f64::NAN),
+            (-2.0, f64::NAN),
+            (f64::NAN, f64::NAN),
+        ];
+
+        for (x, y_ref) in special {
+            assert_eq!(ulp_diff(erfinv(x), y_ref), 0, "{x}");
+            assert_eq!(
+                ulp_diff(erfinv_simd(Simd::<f64, 4>::splat(x))[0], y_ref),
+                0,
+                "{x}"
+            );
+        }
+
+        assert_eq!(erfinvf(1.0), f32::INFINITY);
+        assert_eq!(erfinvf_simd(Simd::<f32, 4>::splat(0.5))[0], erfinvf(0.5));
+    }
+}
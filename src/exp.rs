@@ -1,33 +1,263 @@
-use std::simd::{LaneCount, Simd, SupportedLaneCount};
-
-use crate::{
-    periodic_clamp, periodic_clamp_simd, polyval, polyval_simd, powi, powi_simd,
+use std::{
+    f64::consts::{LN_10, LN_2, LOG2_10, LOG2_E},
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
 };
 
-const EXP_PT2: f64 = 1.2214027581601698;
-
-const TAYLOR: [f64; 11] = [
-    2.755_731_922_398_589e-7,
-    2.755_731_922_398_589_3e-6,
-    2.480_158_730_158_73e-5,
-    0.000_198_412_698_412_698_4,
-    0.001_388_888_888_888_889,
-    0.008_333_333_333_333_333,
-    0.041_666_666_666_666_664,
-    0.166_666_666_666_666_66,
+use crate::{polyval, polyval_simd, polyvalf, polyvalf_simd};
+
+// f(x) = (exp(x) - 1 - x) / x^2
+// domain: [-ln(2)/2, ln(2)/2]
+const MINIMAX: [f64; 10] = [
+    2.5110038296727242e-8,
+    2.7632640675430235e-7,
+    2.755724236744966e-6,
+    2.4801487366025675e-5,
+    0.00019841269886563802,
+    0.0013888888947785523,
+    0.008333333333322215,
+    0.041666666666522106,
+    0.16666666666666674,
+    0.500000000000001,
+];
+
+// Same function with one more term, for callers that keep the argument in
+// extended precision and need the fit error well below an ulp
+const MINIMAX_EXTENDED: [f64; 11] = [
+    2.0914679376583935e-9,
+    2.510520637395701e-8,
+    2.7557273661348637e-7,
+    2.7557255425746435e-6,
+    2.4801587325533363e-5,
+    0.00019841269874800493,
+    0.0013888888888883752,
+    0.008333333333326141,
+    0.04166666666666667,
+    0.1666666666666667,
     0.5,
-    1.0,
-    1.0,
 ];
 
+// ln(2) split so that k * LN2_HI is exact for every k in range
+pub(crate) const LN2_HI: f64 = 0.6931471803691238;
+pub(crate) const LN2_LO: f64 = 1.9082149292705877e-10;
+
+// log10(2) split so that k * LOG10_2_HI is exact for every k in range
+const LOG10_2_HI: f64 = 0.30102999566395283;
+const LOG10_2_LO: f64 = 2.8363394551044964e-14;
+
+// Largest x with a finite result and smallest x with a non-zero result
+pub(crate) const EXP_MAX: f64 = 709.782712893384;
+pub(crate) const EXP_MIN: f64 = -745.1332191019411;
+const EXP2_MAX: f64 = 1023.9999999999999;
+const EXP2_MIN: f64 = -1074.9999999999998;
+const EXP10_MAX: f64 = 308.2547155599167;
+const EXP10_MIN: f64 = -323.60724533877976;
+
+// Below EXPM1_MIN, exp(x) - 1 rounds to -1. Once k exceeds EXPM1_BIG_K the -1
+// is lost in the rounding of exp(x).
+const EXPM1_MIN: f64 = -40.0;
+const EXPM1_BIG_K: f64 = 56.0;
+
+// exp(u) - 1 for |u| <= ln(2) / 2
+#[inline(always)]
+fn expm1_reduced(u: f64) -> f64 {
+    (u * u).mul_add(polyval(&MINIMAX, u), u)
+}
+
+// 2^k as two factors that are both normal for k in [-1075, 1024]. Applying
+// them one after the other means that neither overflows on its own, and
+// subnormal results are only rounded once.
+#[inline(always)]
+pub(crate) fn pow2_split(k: f64) -> (f64, f64) {
+    let k = k as i64;
+    let k1 = k >> 1;
+    let fac1 = f64::from_bits(((k1 + 1023) as u64) << 52);
+    let fac2 = f64::from_bits(((k - k1 + 1023) as u64) << 52);
+
+    (fac1, fac2)
+}
+
+// exp(u) * 2^k for |u| <= ln(2) / 2 and k in [-1075, 1024]
+#[inline(always)]
+pub(crate) fn exp_scaled(u: f64, k: f64) -> f64 {
+    let (fac1, fac2) = pow2_split(k);
+
+    (expm1_reduced(u) + 1.0) * fac1 * fac2
+}
+
+// exp(u + u_lo) * 2^k for |u| <= ln(2) / 2, |u_lo| <= ulp(u) and k in
+// [-1075, 1024]. 1 + u is kept as an exact sum, so that the only sizeable
+// error left is the final rounding.
+#[inline(always)]
+pub(crate) fn exp_scaled_extended(u: f64, u_lo: f64, k: f64) -> f64 {
+    let (fac1, fac2) = pow2_split(k);
+
+    let s = 1.0 + u;
+    let s_lo = (1.0 - s) + u;
+    let tail =
+        (u * u).mul_add(polyval(&MINIMAX_EXTENDED, u), u_lo.mul_add(u, u_lo));
+
+    (s + (s_lo + tail)) * fac1 * fac2
+}
+
+#[inline(always)]
+fn expm1_reduced_simd<const LANES: usize>(
+    u: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (u * u).mul_add(polyval_simd(&MINIMAX, u), u)
+}
+
+#[inline(always)]
+pub(crate) fn pow2_split_simd<const LANES: usize>(
+    k: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k: Simd<i64, LANES> = k.cast();
+    let k1 = k >> Simd::splat(1);
+    let fac1 = Simd::<f64, LANES>::from_bits(
+        (k1 + Simd::splat(1023)).cast::<u64>() << Simd::splat(52),
+    );
+    let fac2 = Simd::<f64, LANES>::from_bits(
+        (k - k1 + Simd::splat(1023)).cast::<u64>() << Simd::splat(52),
+    );
+
+    (fac1, fac2)
+}
+
+#[inline(always)]
+pub(crate) fn exp_scaled_simd<const LANES: usize>(
+    u: Simd<f64, LANES>,
+    k: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (fac1, fac2) = pow2_split_simd(k);
+
+    (expm1_reduced_simd(u) + Simd::splat(1.0)) * fac1 * fac2
+}
+
+#[inline(always)]
+pub(crate) fn exp_scaled_extended_simd<const LANES: usize>(
+    u: Simd<f64, LANES>,
+    u_lo: Simd<f64, LANES>,
+    k: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let (fac1, fac2) = pow2_split_simd(k);
+
+    let s = one + u;
+    let s_lo = (one - s) + u;
+    let tail = (u * u)
+        .mul_add(polyval_simd(&MINIMAX_EXTENDED, u), u_lo.mul_add(u, u_lo));
+
+    (s + (s_lo + tail)) * fac1 * fac2
+}
+
+// Patches up the lanes that were clamped away from [min, max]
+#[inline(always)]
+pub(crate) fn exp_saturate_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    y: Simd<f64, LANES>,
+    min: f64,
+    max: f64,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = x
+        .simd_gt(Simd::splat(max))
+        .select(Simd::splat(f64::INFINITY), y);
+
+    x.simd_lt(Simd::splat(min)).select(Simd::splat(0.0), y)
+}
+
 pub fn exp(x: f64) -> f64 {
-    const A: f64 = 0.2;
-    let (u, n) = periodic_clamp(x, A);
+    if x.is_nan() {
+        return x;
+    } else if x > EXP_MAX {
+        return f64::INFINITY;
+    } else if x < EXP_MIN {
+        return 0.0;
+    }
+
+    // x = k ln(2) + u, |u| <= ln(2) / 2
+    let k = (x * LOG2_E).round();
+    let u = k.mul_add(-LN2_HI, x);
+    let u = k.mul_add(-LN2_LO, u);
+
+    exp_scaled(u, k)
+}
+
+/// Computes `2^x`, exact for integer `x`.
+pub fn exp2(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    } else if x > EXP2_MAX {
+        return f64::INFINITY;
+    } else if x < EXP2_MIN {
+        return 0.0;
+    }
 
-    let expu = polyval(&TAYLOR, u);
-    let fac = powi(EXP_PT2, n);
+    // x = k + f, |f| <= 1/2. The subtraction is exact, so integer x gives
+    // u = 0 and an exact power of two.
+    let k = x.round();
+    let u = (x - k) * LN_2;
 
-    expu * fac
+    exp_scaled(u, k)
+}
+
+/// Computes `10^x`.
+pub fn exp10(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    } else if x > EXP10_MAX {
+        return f64::INFINITY;
+    } else if x < EXP10_MIN {
+        return 0.0;
+    }
+
+    // x = k log10(2) + f, |f| <= log10(2) / 2
+    let k = (x * LOG2_10).round();
+    let f = k.mul_add(-LOG10_2_HI, x);
+    let f = k.mul_add(-LOG10_2_LO, f);
+
+    exp_scaled(f * LN_10, k)
+}
+
+/// Computes `exp(x) - 1`, accurate also for `x` close to zero.
+pub fn expm1(x: f64) -> f64 {
+    // The reduction below loses the sign of zero
+    if x.is_nan() || x == 0.0 {
+        return x;
+    } else if x > EXP_MAX {
+        return f64::INFINITY;
+    }
+
+    let x = x.max(EXPM1_MIN);
+
+    let k = (x * LOG2_E).round();
+    let u = k.mul_add(-LN2_HI, x);
+    let u = k.mul_add(-LN2_LO, u);
+
+    let expm1u = expm1_reduced(u);
+    let (fac1, fac2) = pow2_split(k);
+
+    if k > EXPM1_BIG_K {
+        (expm1u + 1.0) * fac1 * fac2
+    } else {
+        // exp(x) - 1 = 2^k (exp(u) - 1) + (2^k - 1), where 2^k - 1 is exact
+        let fac = fac1 * fac2;
+        fac.mul_add(expm1u, fac - 1.0)
+    }
 }
 
 #[inline(always)]
@@ -35,21 +265,372 @@ pub fn exp_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    const A: f64 = 0.2;
-    let (u, n) = periodic_clamp_simd(x, A);
+    // Clamping keeps k small enough for the exponent arithmetic in
+    // exp_scaled_simd. Lanes outside the range are patched up at the end.
+    let xc = x.simd_clamp(Simd::splat(EXP_MIN), Simd::splat(EXP_MAX));
+
+    let k = (xc * Simd::splat(LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI), xc);
+    let u = k.mul_add(Simd::splat(-LN2_LO), u);
+
+    exp_saturate_simd(x, exp_scaled_simd(u, k), EXP_MIN, EXP_MAX)
+}
+
+/// The SIMD version of [`exp2`].
+#[inline(always)]
+pub fn exp2_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let xc = x.simd_clamp(Simd::splat(EXP2_MIN), Simd::splat(EXP2_MAX));
+
+    let k = xc.round();
+    let u = (xc - k) * Simd::splat(LN_2);
+
+    exp_saturate_simd(x, exp_scaled_simd(u, k), EXP2_MIN, EXP2_MAX)
+}
+
+/// The SIMD version of [`exp10`].
+#[inline(always)]
+pub fn exp10_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let xc = x.simd_clamp(Simd::splat(EXP10_MIN), Simd::splat(EXP10_MAX));
+
+    let k = (xc * Simd::splat(LOG2_10)).round();
+    let f = k.mul_add(Simd::splat(-LOG10_2_HI), xc);
+    let f = k.mul_add(Simd::splat(-LOG10_2_LO), f);
+
+    let y = exp_scaled_simd(f * Simd::splat(LN_10), k);
+
+    exp_saturate_simd(x, y, EXP10_MIN, EXP10_MAX)
+}
+
+/// The SIMD version of [`expm1`].
+#[inline(always)]
+pub fn expm1_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let xc = x.simd_clamp(Simd::splat(EXPM1_MIN), Simd::splat(EXP_MAX));
+
+    let k = (xc * Simd::splat(LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI), xc);
+    let u = k.mul_add(Simd::splat(-LN2_LO), u);
+
+    let expm1u = expm1_reduced_simd(u);
+    let (fac1, fac2) = pow2_split_simd(k);
+
+    // exp(x) - 1 = 2^k (exp(u) - 1) + (2^k - 1), where 2^k - 1 is exact
+    let fac = fac1 * fac2;
+    let y_small = fac.mul_add(expm1u, fac - Simd::splat(1.0));
+    let y_big = (expm1u + Simd::splat(1.0)) * fac1 * fac2;
+
+    let y = k.simd_gt(Simd::splat(EXPM1_BIG_K)).select(y_big, y_small);
 
-    let expu = polyval_simd(&TAYLOR, u);
-    let fac = powi_simd(Simd::splat(EXP_PT2), n);
+    let y = x
+        .simd_gt(Simd::splat(EXP_MAX))
+        .select(Simd::splat(f64::INFINITY), y);
 
-    expu * fac
+    // The reduction loses the sign of zero
+    x.simd_eq(Simd::splat(0.0)).select(x, y)
+}
+
+// f(x) = (exp(x) - 1 - x) / x^2
+// domain: [-ln(2)/2, ln(2)/2]
+const MINIMAX_F32: [f32; 6] = [
+    1.990_757_2e-4,
+    1.394_858_1e-3,
+    8.333_286e-3,
+    4.166_624e-2,
+    1.666_666_7e-1,
+    5.0e-1,
+];
+
+// ln(2) split so that k * LN2_HI_F32 is exact for every k in range
+pub(crate) const LN2_HI_F32: f32 = 0.693_359_4;
+pub(crate) const LN2_LO_F32: f32 = -2.121_944_4e-4;
+
+// log10(2) split so that k * LOG10_2_HI_F32 is exact for every k in range
+const LOG10_2_HI_F32: f32 = 0.300_781_25;
+const LOG10_2_LO_F32: f32 = 2.487_456_6e-4;
+
+// Inputs outside these ranges overflow to inf or underflow to 0 anyway, and
+// clamping keeps the exponent arithmetic below within the normal range.
+pub(crate) const EXPF_MIN: f32 = -104.0;
+pub(crate) const EXPF_MAX: f32 = 89.0;
+const EXP2F_MIN: f32 = -151.0;
+const EXP2F_MAX: f32 = 129.0;
+const EXP10F_MIN: f32 = -46.0;
+const EXP10F_MAX: f32 = 39.0;
+
+const EXPM1F_MIN: f32 = -18.0;
+const EXPM1F_BIG_K: f32 = 25.0;
+
+// exp(u) - 1 for |u| <= ln(2) / 2
+#[inline(always)]
+fn expm1f_reduced(u: f32) -> f32 {
+    (u * u).mul_add(polyvalf(&MINIMAX_F32, u), u)
+}
+
+// 2^k as two normal factors for k in [-151, 129]
+#[inline(always)]
+fn pow2f_split(k: f32) -> (f32, f32) {
+    let k = k as i32;
+    let k1 = k >> 1;
+    let fac1 = f32::from_bits(((k1 + 127) as u32) << 23);
+    let fac2 = f32::from_bits(((k - k1 + 127) as u32) << 23);
+
+    (fac1, fac2)
+}
+
+// exp(u) * 2^k for |u| <= ln(2) / 2 and k in [-151, 129]
+#[inline(always)]
+pub(crate) fn expf_scaled(u: f32, k: f32) -> f32 {
+    let (fac1, fac2) = pow2f_split(k);
+
+    (expm1f_reduced(u) + 1.0) * fac1 * fac2
+}
+
+// exp(u + u_lo) * 2^k for |u| <= ln(2) / 2, |u_lo| <= ulp(u) and k in
+// [-151, 129], with 1 + u kept as an exact sum as in exp_scaled_extended
+#[inline(always)]
+fn expf_scaled_extended(u: f32, u_lo: f32, k: f32) -> f32 {
+    let (fac1, fac2) = pow2f_split(k);
+
+    let s = 1.0 + u;
+    let s_lo = (1.0 - s) + u;
+    let tail =
+        (u * u).mul_add(polyvalf(&MINIMAX_F32, u), u_lo.mul_add(u, u_lo));
+
+    (s + (s_lo + tail)) * fac1 * fac2
+}
+
+// exp(p + p_lo) for p in [EXPF_MIN, EXPF_MAX] and |p_lo| <= ulp(p), keeping
+// the low part through the reduction
+#[inline(always)]
+pub(crate) fn expf_extended(p: f32, p_lo: f32) -> f32 {
+    let k = (p * std::f32::consts::LOG2_E).round();
+    let a = k.mul_add(-LN2_HI_F32, p);
+    let b = k.mul_add(-LN2_LO_F32, p_lo);
+    let u = a + b;
+    let u_lo = (a - u) + b;
+
+    expf_scaled_extended(u, u_lo, k)
+}
+
+#[inline(always)]
+fn expm1f_reduced_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (u * u).mul_add(polyvalf_simd(&MINIMAX_F32, u), u)
+}
+
+#[inline(always)]
+fn pow2f_split_simd<const LANES: usize>(
+    k: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k: Simd<i32, LANES> = k.cast();
+    let k1 = k >> Simd::splat(1);
+    let fac1 = Simd::<f32, LANES>::from_bits(
+        (k1 + Simd::splat(127)).cast::<u32>() << Simd::splat(23),
+    );
+    let fac2 = Simd::<f32, LANES>::from_bits(
+        (k - k1 + Simd::splat(127)).cast::<u32>() << Simd::splat(23),
+    );
+
+    (fac1, fac2)
+}
+
+#[inline(always)]
+pub(crate) fn expf_scaled_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    k: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (fac1, fac2) = pow2f_split_simd(k);
+
+    (expm1f_reduced_simd(u) + Simd::splat(1.0)) * fac1 * fac2
+}
+
+#[inline(always)]
+fn expf_scaled_extended_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    u_lo: Simd<f32, LANES>,
+    k: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let (fac1, fac2) = pow2f_split_simd(k);
+
+    let s = one + u;
+    let s_lo = (one - s) + u;
+    let tail =
+        (u * u).mul_add(polyvalf_simd(&MINIMAX_F32, u), u_lo.mul_add(u, u_lo));
+
+    (s + (s_lo + tail)) * fac1 * fac2
+}
+
+#[inline(always)]
+pub(crate) fn expf_extended_simd<const LANES: usize>(
+    p: Simd<f32, LANES>,
+    p_lo: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k = (p * Simd::splat(std::f32::consts::LOG2_E)).round();
+    let a = k.mul_add(Simd::splat(-LN2_HI_F32), p);
+    let b = k.mul_add(Simd::splat(-LN2_LO_F32), p_lo);
+    let u = a + b;
+    let u_lo = (a - u) + b;
+
+    expf_scaled_extended_simd(u, u_lo, k)
+}
+
+pub fn expf(x: f32) -> f32 {
+    let x = x.clamp(EXPF_MIN, EXPF_MAX);
+
+    let k = (x * std::f32::consts::LOG2_E).round();
+    let u = k.mul_add(-LN2_HI_F32, x);
+    let u = k.mul_add(-LN2_LO_F32, u);
+
+    expf_scaled(u, k)
+}
+
+/// The `f32` version of [`exp2`].
+pub fn exp2f(x: f32) -> f32 {
+    let x = x.clamp(EXP2F_MIN, EXP2F_MAX);
+
+    let k = x.round();
+    let u = (x - k) * std::f32::consts::LN_2;
+
+    expf_scaled(u, k)
+}
+
+/// The `f32` version of [`exp10`].
+pub fn exp10f(x: f32) -> f32 {
+    let x = x.clamp(EXP10F_MIN, EXP10F_MAX);
+
+    let k = (x * std::f32::consts::LOG2_10).round();
+    let f = k.mul_add(-LOG10_2_HI_F32, x);
+    let f = k.mul_add(-LOG10_2_LO_F32, f);
+
+    expf_scaled(f * std::f32::consts::LN_10, k)
+}
+
+/// The `f32` version of [`expm1`].
+pub fn expm1f(x: f32) -> f32 {
+    if x.is_nan() || x == 0.0 {
+        return x;
+    }
+
+    let x = x.clamp(EXPM1F_MIN, EXPF_MAX);
+
+    let k = (x * std::f32::consts::LOG2_E).round();
+    let u = k.mul_add(-LN2_HI_F32, x);
+    let u = k.mul_add(-LN2_LO_F32, u);
+
+    let expm1u = expm1f_reduced(u);
+    let (fac1, fac2) = pow2f_split(k);
+
+    if k > EXPM1F_BIG_K {
+        (expm1u + 1.0) * fac1 * fac2
+    } else {
+        let fac = fac1 * fac2;
+        fac.mul_add(expm1u, fac - 1.0)
+    }
+}
+
+#[inline(always)]
+pub fn expf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.simd_clamp(Simd::splat(EXPF_MIN), Simd::splat(EXPF_MAX));
+
+    let k = (x * Simd::splat(std::f32::consts::LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI_F32), x);
+    let u = k.mul_add(Simd::splat(-LN2_LO_F32), u);
+
+    expf_scaled_simd(u, k)
+}
+
+/// The SIMD version of [`exp2f`].
+#[inline(always)]
+pub fn exp2f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.simd_clamp(Simd::splat(EXP2F_MIN), Simd::splat(EXP2F_MAX));
+
+    let k = x.round();
+    let u = (x - k) * Simd::splat(std::f32::consts::LN_2);
+
+    expf_scaled_simd(u, k)
+}
+
+/// The SIMD version of [`exp10f`].
+#[inline(always)]
+pub fn exp10f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.simd_clamp(Simd::splat(EXP10F_MIN), Simd::splat(EXP10F_MAX));
+
+    let k = (x * Simd::splat(std::f32::consts::LOG2_10)).round();
+    let f = k.mul_add(Simd::splat(-LOG10_2_HI_F32), x);
+    let f = k.mul_add(Simd::splat(-LOG10_2_LO_F32), f);
+
+    expf_scaled_simd(f * Simd::splat(std::f32::consts::LN_10), k)
+}
+
+/// The SIMD version of [`expm1f`].
+#[inline(always)]
+pub fn expm1f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let xc = x.simd_clamp(Simd::splat(EXPM1F_MIN), Simd::splat(EXPF_MAX));
+
+    let k = (xc * Simd::splat(std::f32::consts::LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI_F32), xc);
+    let u = k.mul_add(Simd::splat(-LN2_LO_F32), u);
+
+    let expm1u = expm1f_reduced_simd(u);
+    let (fac1, fac2) = pow2f_split_simd(k);
+
+    let fac = fac1 * fac2;
+    let y_small = fac.mul_add(expm1u, fac - Simd::splat(1.0));
+    let y_big = (expm1u + Simd::splat(1.0)) * fac1 * fac2;
+
+    let y = k.simd_gt(Simd::splat(EXPM1F_BIG_K)).select(y_big, y_small);
+
+    x.simd_eq(Simd::splat(0.0)).select(x, y)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::PI;
+    use std::{f32::consts::PI as PI_F32, f64::consts::PI, simd::Simd};
 
     use crate::{
-        tests::{accuracy_test, accuracy_test_simd, speed_test_simd_iterated},
+        exp::{EXP10_MAX, EXP10_MIN, EXP2_MAX, EXP2_MIN, EXP_MAX, EXP_MIN},
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, speed_test_simd_iterated, ulp_diff,
+        },
         *,
     };
 
@@ -66,6 +647,237 @@ mod tests {
         accuracy_test_simd(X, |x: f64| x.exp(), |x| x.exp());
     }
 
+    const X_F32: [f32; 8] = [
+        PI_F32 * 2.0,
+        PI_F32,
+        -PI_F32 * 4.0,
+        1.78,
+        PI_F32 * 8.0,
+        0.5,
+        1.0,
+        -1.0,
+    ];
+
+    #[test]
+    fn test_expf() {
+        accuracy_testf(&X_F32, |x: f32| x.exp(), expf);
+    }
+
+    #[test]
+    fn test_expf_simd() {
+        accuracy_test_simdf(X_F32, |x: f32| x.exp(), |x| x.exp());
+    }
+
+    #[test]
+    fn test_exp_special_values() {
+        let x = [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            0.0,
+            -0.0,
+            f64::from_bits(EXP_MAX.to_bits() + 1),
+            EXP_MIN,
+            f64::from_bits(EXP_MIN.to_bits() + 1),
+        ];
+        let y = [
+            f64::NAN,
+            f64::INFINITY,
+            0.0,
+            1.0,
+            1.0,
+            f64::INFINITY,
+            f64::from_bits(1),
+            0.0,
+        ];
+
+        let y_simd = Simd::from(x).exp().to_array();
+
+        for ((&x, &y), y_simd) in x.iter().zip(&y).zip(y_simd) {
+            assert_eq!(ulp_diff(exp(x), y), 0, "{x}");
+            assert_eq!(ulp_diff(y_simd, y), 0, "{x}");
+        }
+
+        for x in [750.0, 1e10, 1e300, f64::MAX] {
+            assert_eq!(exp(x), f64::INFINITY);
+            assert_eq!(exp(-x), 0.0);
+            assert_eq!(Simd::<f64, 4>::splat(x).exp()[0], f64::INFINITY);
+            assert_eq!(Simd::<f64, 4>::splat(-x).exp()[0], 0.0);
+        }
+    }
+
+    #[test]
+    fn test_exp_underflow() {
+        // exp(x) is subnormal for all of these
+        let x: [f64; 8] = [
+            -708.5, -710.0, -715.0, -720.0, -730.0, -740.0, -744.0, -745.0,
+        ];
+
+        let y_simd = Simd::from(x).exp().to_array();
+
+        for (&x, y_simd) in x.iter().zip(y_simd) {
+            let y_std = x.exp();
+
+            for y in [exp(x), y_simd] {
+                assert!(y > 0.0 && y < f64::MIN_POSITIVE, "{x}");
+                assert!(ulp_diff(y, y_std) <= 1, "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let x = EXP_MIN + (EXP_MAX - EXP_MIN) * (i as f64 / N as f64);
+            let y_std = x.exp();
+
+            assert!(ulp_diff(exp(x), y_std) <= 1, "{x}");
+            assert!(ulp_diff(Simd::<f64, 4>::splat(x).exp()[0], y_std) <= 1);
+        }
+
+        assert_eq!(exp(EXP_MAX), EXP_MAX.exp());
+        assert_eq!(Simd::<f64, 4>::splat(EXP_MAX).exp()[0], EXP_MAX.exp());
+    }
+
+    #[test]
+    fn test_exp2() {
+        accuracy_test(&X, |x: f64| x.exp2(), exp2);
+        accuracy_test_simd(X, |x: f64| x.exp2(), |x| x.exp2());
+    }
+
+    #[test]
+    fn test_exp2_integer() {
+        for n in -1074..1024 {
+            let x = n as f64;
+
+            assert_eq!(exp2(x), x.exp2());
+            assert_eq!(Simd::<f64, 4>::splat(x).exp2()[0], x.exp2());
+        }
+    }
+
+    #[test]
+    fn test_exp10() {
+        accuracy_test(&X, |x: f64| 10f64.powf(x), exp10);
+        accuracy_test_simd(X, |x: f64| 10f64.powf(x), |x| x.exp10());
+    }
+
+    #[test]
+    fn test_exp2_exp10_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let t = i as f64 / N as f64;
+
+            let x = EXP2_MIN + (EXP2_MAX - EXP2_MIN) * t;
+            let y_std = x.exp2();
+            assert!(ulp_diff(exp2(x), y_std) <= 1, "{x}");
+            assert!(ulp_diff(Simd::<f64, 4>::splat(x).exp2()[0], y_std) <= 1);
+
+            let x = EXP10_MIN + (EXP10_MAX - EXP10_MIN) * t;
+            let y_std = 10f64.powf(x);
+            assert!(ulp_diff(exp10(x), y_std) <= 1, "{x}");
+            assert!(ulp_diff(Simd::<f64, 4>::splat(x).exp10()[0], y_std) <= 1);
+        }
+    }
+
+    #[test]
+    fn test_exp2_exp10_special_values() {
+        let x = [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            -0.0,
+            1024.0,
+            -1075.0,
+            1e300,
+            -1e300,
+        ];
+        let y = [
+            f64::NAN,
+            f64::INFINITY,
+            0.0,
+            1.0,
+            f64::INFINITY,
+            0.0,
+            f64::INFINITY,
+            0.0,
+        ];
+
+        let y2_simd = Simd::from(x).exp2().to_array();
+        let y10_simd = Simd::from(x).exp10().to_array();
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(exp2(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y2_simd[i], y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(exp10(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y10_simd[i], y[i]), 0, "{}", x[i]);
+        }
+    }
+
+    #[test]
+    fn test_expm1() {
+        accuracy_test(&X, |x: f64| x.exp_m1(), expm1);
+        accuracy_test_simd(X, |x: f64| x.exp_m1(), |x| x.exp_m1());
+    }
+
+    #[test]
+    fn test_expm1_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..=N {
+            let t = 2.0 * (i as f64 / N as f64) - 1.0;
+
+            // Near zero, where exp(x) - 1 cancels
+            for x in [t * 1e-5, t * 1e-300, t] {
+                let y_std = x.exp_m1();
+                assert!(ulp_diff(expm1(x), y_std) <= 2, "{x}");
+                assert!(
+                    ulp_diff(Simd::<f64, 4>::splat(x).exp_m1()[0], y_std) <= 2
+                );
+            }
+
+            // Far from zero, where it agrees with exp(x) - 1
+            let x = (1.0 + 699.0 * t.abs()).copysign(t);
+            let y = Simd::<f64, 4>::splat(x).exp_m1()[0];
+            assert!((expm1(x) - (exp(x) - 1.0)).abs() <= 1e-15 * y.abs());
+            assert!(ulp_diff(y, x.exp_m1()) <= 2, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_expm1_special_values() {
+        let x = [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            0.0,
+            -0.0,
+            f64::from_bits(EXP_MAX.to_bits() + 1),
+            -1000.0,
+            -f64::from_bits(1),
+        ];
+        let y = [
+            f64::NAN,
+            f64::INFINITY,
+            -1.0,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            -1.0,
+            -f64::from_bits(1),
+        ];
+
+        let y_simd = Simd::from(x).exp_m1().to_array();
+
+        for ((&x, &y), y_simd) in x.iter().zip(&y).zip(y_simd) {
+            assert_eq!(expm1(x).to_bits(), y.to_bits(), "{x}");
+            assert_eq!(ulp_diff(y_simd, y), 0, "{x}");
+            assert_eq!(y_simd.is_sign_negative(), y.is_sign_negative());
+        }
+    }
+
     #[test]
     fn test_exp_simd_speed() {
         const ITERS: usize = 1000000;
@@ -0,0 +1,989 @@
+use std::{
+    f64::consts::PI,
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    exp,
+    exp::{expf_extended, expf_extended_simd},
+    exp_simd, ln, ln_simd, lnf, lnf_simd, log1p, log1p_simd, log1pf,
+    log1pf_simd, polyval, polyval_simd, polyvalf, polyvalf_simd, pow,
+    pow::{lnf_extended, lnf_extended_simd},
+    pow_simd, sinpi, sinpi_simd, sinpif, sinpif_simd,
+};
+
+// f(u) = (1 / gamma(1 + u) - 1) / (u (u - 1))
+// domain: 0 <= u <= 1
+//
+// 1 / gamma is entire, so this converges quickly, and writing it with the
+// factor u (u - 1) keeps lgamma accurate next to its zeros at 1 and 2.
+const GAMMA_MINIMAX: [f64; 16] = [
+    -2.759755797782535e-10,
+    1.2316430550020962e-9,
+    1.566018398522931e-8,
+    -2.0350895826210358e-7,
+    9.453862106347367e-7,
+    -3.1886522822524533e-7,
+    -2.0444780253006125e-5,
+    0.0001076011327361286,
+    -0.00010763894216510711,
+    -0.0012728069668700057,
+    0.005946136364553073,
+    -0.003675835174928091,
+    -0.04587356972941848,
+    0.12066504165281455,
+    0.07866240661872105,
+    -0.5772156649015329,
+];
+
+// f(t) = (lgamma(x) - (x - 1/2) ln(x) + x - ln(2 pi) / 2) x, t = 1 / x^2
+// domain: x >= 8
+pub(crate) const STIRLING_MINIMAX: [f64; 7] = [
+    0.005069245810692784,
+    -0.0018905397792161793,
+    0.0008414728455994591,
+    -0.0005952366069140942,
+    0.0007936507898346793,
+    -0.0027777777777740985,
+    0.08333333333333333,
+];
+
+// ln(pi)
+const LN_PI: f64 = 1.1447298858494002;
+
+// ln(2 pi) / 2 - 1/2
+const HALF_LN_2PI_M_HALF: f64 = 0.4189385332046727;
+
+// sqrt(2 pi)
+const SQRT_2PI: f64 = 2.5066282746310007;
+
+// From here on Stirling's series is used instead of the recurrence
+const GAMMA_BIG: f64 = 8.0;
+
+// Beyond this gamma(x) overflows
+const GAMMA_MAX: f64 = 171.6243769563027;
+
+// From here on gamma(x) for x < 0 is close to or below the smallest normal,
+// and beyond GAMMA_NEG_MAX it underflows to zero
+const GAMMA_NEG_BIG: f64 = 170.0;
+const GAMMA_NEG_MAX: f64 = 190.0;
+
+// 2^-512
+const TWO_M512: f64 = 7.458340731200207e-155;
+
+// Below this in magnitude gamma(x) is 1 / x and lgamma(x) is -ln(|x|) to
+// working precision. For negative x the reflection would lose them to the
+// underflow of sin(pi x) and the overflow of gamma(-x).
+const GAMMA_TINY: f64 = 5.551115123125783e-17;
+
+// v such that 1 / gamma(1 + u) = 1 + v
+#[inline(always)]
+fn rgamma_poly(u: f64) -> f64 {
+    (u * (u - 1.0)) * polyval(&GAMMA_MINIMAX, u)
+}
+
+// (lgamma(x) - (x - 1/2) ln(x) + x - ln(2 pi) / 2) for x >= GAMMA_BIG
+#[inline(always)]
+fn stirling_correction(x: f64) -> f64 {
+    let t = x.recip();
+    polyval(&STIRLING_MINIMAX, t * t) * t
+}
+
+// gamma(x) scale^2 for x >= GAMMA_BIG, where scale is a power of two
+#[inline(always)]
+fn tgamma_big(x: f64, scale: f64) -> f64 {
+    // x^(x - 1/2) is split in two halves so that it does not overflow
+    // before exp(-x) brings it back down
+    let p = pow(x, 0.5 * x - 0.25) * scale;
+    (p * exp(-x)) * p * (SQRT_2PI * exp(stirling_correction(x)))
+}
+
+// gamma(x) for x > 0
+fn tgamma_pos(x: f64) -> f64 {
+    if x > GAMMA_MAX {
+        f64::INFINITY
+    } else if x >= GAMMA_BIG {
+        tgamma_big(x, 1.0)
+    } else if x < 1.0 {
+        1.0 / x.mul_add(rgamma_poly(x), x)
+    } else {
+        // gamma(k + 1 + u) = (1 + u) ... (k + u) gamma(1 + u)
+        let u = x - x.floor();
+
+        let mut p = 1.0;
+        for k in 1..x as usize {
+            p *= k as f64 + u;
+        }
+
+        p / (1.0 + rgamma_poly(u))
+    }
+}
+
+// lgamma(x) for x > 0
+fn lgamma_pos(x: f64) -> f64 {
+    if x >= GAMMA_BIG {
+        (x - 0.5) * (ln(x) - 1.0) + HALF_LN_2PI_M_HALF + stirling_correction(x)
+    } else if x < 1.0 {
+        -log1p(rgamma_poly(x)) - ln(x)
+    } else {
+        let u = x - x.floor();
+        let v = rgamma_poly(u);
+
+        // ln((1 + u) / (1 + v)) goes through log1p to stay accurate close to
+        // x = 2, and the remaining factors are at least 2
+        let mut p = 1.0;
+        for k in 2..x as usize {
+            p *= k as f64 + u;
+        }
+
+        let t = if x >= 2.0 { u } else { 0.0 };
+
+        log1p((t - v) / (1.0 + v)) + ln(p)
+    }
+}
+
+/// Computes the gamma function, `(x - 1)!` for positive integers `x`.
+/// Returns NaN at the poles at the negative integers, and infinity with the
+/// sign of `x` at zero.
+pub fn tgamma(x: f64) -> f64 {
+    if x.is_nan() || x > 0.0 {
+        tgamma_pos(x)
+    } else if x > -GAMMA_TINY {
+        // Also infinity with the sign of x at zero
+        1.0 / x
+    } else if x == x.floor() {
+        f64::NAN
+    } else if x <= -GAMMA_NEG_BIG {
+        // Same reflection as below, but with gamma(-x) scaled by 2^-1024 so
+        // that it does not overflow, and the scale put back in two steps so
+        // that subnormal results survive
+        let a = (-x).min(GAMMA_NEG_MAX);
+        let s = sinpi(x);
+        let g = tgamma_big(a, TWO_M512);
+
+        PI / ((s * a) * g) * TWO_M512 * TWO_M512
+    } else {
+        // Reflection, gamma(x) = pi / (sin(pi x) (-x) gamma(-x)), where the
+        // order of the products avoids overflow for large |x| and underflow
+        // for small
+        let a = -x;
+        let s = sinpi(x);
+        let g = tgamma_pos(a);
+
+        PI / if a < 1.0 { s * (a * g) } else { (s * a) * g }
+    }
+}
+
+/// Computes `ln(|gamma(x)|)` along with the sign of `gamma(x)`, which is
+/// `-1` or `1`. For negative `x` the error is small relative to `max(1, |y|)`
+/// rather than to the result, which matters close to the zeros of lgamma.
+pub fn lgamma(x: f64) -> (f64, i32) {
+    if x.is_nan() || x > 0.0 {
+        (lgamma_pos(x), 1)
+    } else if x == x.floor() {
+        let sign = if x == 0.0 && x.is_sign_negative() {
+            -1
+        } else {
+            1
+        };
+        (f64::INFINITY, sign)
+    } else if x > -GAMMA_TINY {
+        (-ln(-x), -1)
+    } else {
+        let s = sinpi(x);
+        let sign = if s < 0.0 { -1 } else { 1 };
+
+        let y = if x > -1.0 {
+            LN_PI - ln(s.abs()) - lgamma_pos(1.0 - x)
+        } else {
+            LN_PI - ln((s * x).abs()) - lgamma_pos(-x)
+        };
+
+        (y, sign)
+    }
+}
+
+#[inline(always)]
+fn rgamma_poly_simd<const LANES: usize>(u: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (u * (u - Simd::splat(1.0))) * polyval_simd(&GAMMA_MINIMAX, u)
+}
+
+#[inline(always)]
+fn stirling_correction_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let t = x.recip();
+    polyval_simd(&STIRLING_MINIMAX, t * t) * t
+}
+
+// Product of k + u over first <= k < floor(x), for the lanes below GAMMA_BIG
+#[inline(always)]
+fn shift_product_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    u: Simd<f64, LANES>,
+    first: usize,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let n = x.simd_min(Simd::splat(GAMMA_BIG)).floor();
+
+    let mut p = Simd::splat(1.0);
+    for k in first..GAMMA_BIG as usize {
+        let k = Simd::splat(k as f64);
+        let mask = k.simd_lt(n);
+
+        if !mask.any() {
+            break;
+        }
+
+        p = mask.select(p * (k + u), p);
+    }
+
+    p
+}
+
+#[inline(always)]
+fn tgamma_big_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    scale: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let p = pow_simd(x, Simd::splat(0.5) * x - Simd::splat(0.25)) * scale;
+    (p * exp_simd(-x))
+        * p
+        * (Simd::splat(SQRT_2PI) * exp_simd(stirling_correction_simd(x)))
+}
+
+// gamma(x) for lanes with x > 0; other lanes are garbage except NaN
+#[inline(always)]
+fn tgamma_pos_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let small = x.simd_lt(one);
+    let big = x.simd_ge(Simd::splat(GAMMA_BIG));
+
+    let u = small.select(x, x - x.floor());
+    let v = rgamma_poly_simd(u);
+    let p = shift_product_simd(x, u, 1);
+
+    let mut y = small.select(one / x.mul_add(v, x), p / (one + v));
+
+    if big.any() {
+        // Clamped so that lanes past GAMMA_MAX do not turn into inf * 0 = NaN
+        let xb = x.simd_clamp(Simd::splat(GAMMA_BIG), Simd::splat(GAMMA_MAX));
+
+        y = big.select(tgamma_big_simd(xb, Simd::splat(1.0)), y);
+    }
+
+    x.simd_gt(Simd::splat(GAMMA_MAX))
+        .select(Simd::splat(f64::INFINITY), y)
+}
+
+// lgamma(x) for lanes with x > 0; other lanes are garbage except NaN
+#[inline(always)]
+fn lgamma_pos_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let small = x.simd_lt(one);
+    let big = x.simd_ge(Simd::splat(GAMMA_BIG));
+
+    let u = small.select(x, x - x.floor());
+    let v = rgamma_poly_simd(u);
+    let p = shift_product_simd(x, u, 2);
+
+    let t = x.simd_ge(Simd::splat(2.0)).select(u, Simd::splat(0.0));
+
+    // Both the small and the shifted case are a log1p and a log, so they share
+    // the two evaluations
+    let y = log1p_simd(small.select(v, (t - v) / (one + v)))
+        + ln_simd(small.select(x, p));
+    let mut y = small.select(-y, y);
+
+    if big.any() {
+        let xb = x.simd_max(Simd::splat(GAMMA_BIG));
+
+        let y_big = (xb - Simd::splat(0.5)) * (ln_simd(xb) - one)
+            + Simd::splat(HALF_LN_2PI_M_HALF)
+            + stirling_correction_simd(xb);
+
+        y = big.select(y_big, y);
+    }
+
+    y
+}
+
+/// The SIMD version of [`tgamma`].
+#[inline(always)]
+pub fn tgamma_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+    let neg = x.simd_lt(Simd::splat(0.0));
+
+    let mut y = tgamma_pos_simd(a);
+
+    if neg.any() {
+        let s = sinpi_simd(x);
+        let d = a.simd_lt(Simd::splat(1.0)).select(s * (a * y), (s * a) * y);
+
+        let mut y_neg = Simd::splat(PI) / d;
+
+        let far = x.simd_le(Simd::splat(-GAMMA_NEG_BIG));
+        if far.any() {
+            let scale = Simd::splat(TWO_M512);
+            let ab = a.simd_clamp(
+                Simd::splat(GAMMA_NEG_BIG),
+                Simd::splat(GAMMA_NEG_MAX),
+            );
+            let g = tgamma_big_simd(ab, scale);
+
+            let y_far = Simd::splat(PI) / ((s * ab) * g) * scale * scale;
+            y_neg = far.select(y_far, y_neg);
+        }
+
+        let y_neg = x.simd_eq(x.floor()).select(Simd::splat(f64::NAN), y_neg);
+
+        y = neg.select(y_neg, y);
+    }
+
+    let tiny =
+        x.simd_gt(Simd::splat(-GAMMA_TINY)) & x.simd_le(Simd::splat(0.0));
+
+    tiny.select(x.recip(), y)
+}
+
+/// The SIMD version of [`lgamma`].
+#[inline(always)]
+pub fn lgamma_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<i32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let one = Simd::splat(1.0);
+
+    let neg = x.simd_lt(zero);
+    let near = x.simd_gt(-one);
+
+    // Reflected lanes need lgamma(1 - x) for -1 < x < 0 and lgamma(-x) below
+    let z = neg.select(near.select(one - x, -x), x);
+
+    let mut y = lgamma_pos_simd(z);
+    let mut sign = one;
+
+    if neg.any() {
+        let s = sinpi_simd(x);
+
+        let y_neg =
+            Simd::splat(LN_PI) - ln_simd(near.select(s, s * x).abs()) - y;
+
+        let y_tiny = -ln_simd(-x);
+
+        y = neg.select(y_neg, y);
+        y = (neg & x.simd_gt(Simd::splat(-GAMMA_TINY))).select(y_tiny, y);
+        sign = (neg & s.simd_lt(zero)).select(-one, sign);
+    }
+
+    // Poles at zero and the negative integers, including -inf
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+
+    let y = pole.select(Simd::splat(f64::INFINITY), y);
+    let sign = pole.select(one, sign);
+    let sign = (x.simd_eq(zero) & x.is_sign_negative()).select(-one, sign);
+
+    (y, sign.cast())
+}
+
+// f(u) = (1 / gamma(1 + u) - 1) / (u (u - 1))
+// domain: 0 <= u <= 1
+const GAMMA_MINIMAX_F32: [f32; 8] = [
+    1.419_582_3e-4,
+    -1.587_805_3e-3,
+    6.177_170_7e-3,
+    -3.774_987_3e-3,
+    -4.584_970_7e-2,
+    1.206_621_6e-1,
+    7.866_254_4e-2,
+    -5.772_157e-1,
+];
+
+// f(t) = (lgamma(x) - (x - 1/2) ln(x) + x - ln(2 pi) / 2) x, t = 1 / x^2
+// domain: x >= 8
+pub(crate) const STIRLING_MINIMAX_F32: [f32; 3] =
+    [7.800_347e-4, -2.777_698_4e-3, 8.333_333_6e-2];
+
+const LN_PI_F32: f32 = 1.144_729_9;
+const HALF_LN_2PI_M_HALF_F32: f32 = 4.189_385_5e-1;
+
+// ln(2 pi) / 2, split in two
+const HALF_LN_2PI_HI_F32: f32 = 9.189_385e-1;
+const HALF_LN_2PI_LO_F32: f32 = 1.563_417_7e-8;
+
+// ln(2 pi) / 2 - 64 ln(2), split in two
+const HALF_LN_2PI_M64_LN2_HI_F32: f32 = -4.344_248_2e1;
+const HALF_LN_2PI_M64_LN2_LO_F32: f32 = 9.719_971e-7;
+
+// 2^-64
+const TWO_M64_F32: f32 = 5.421_011e-20;
+
+const GAMMA_BIG_F32: f32 = 8.0;
+const GAMMA_MAX_F32: f32 = 35.040_1;
+const GAMMA_NEG_BIG_F32: f32 = 34.0;
+const GAMMA_NEG_MAX_F32: f32 = 45.0;
+const GAMMA_TINY_F32: f32 = 2.980_232_2e-8;
+
+#[inline(always)]
+fn rgammaf_poly(u: f32) -> f32 {
+    (u * (u - 1.0)) * polyvalf(&GAMMA_MINIMAX_F32, u)
+}
+
+#[inline(always)]
+fn stirlingf_correction(x: f32) -> f32 {
+    let t = x.recip();
+    polyvalf(&STIRLING_MINIMAX_F32, t * t) * t
+}
+
+// gamma(x) exp(c) / sqrt(2 pi) with c = c_hi + c_lo, for
+// GAMMA_BIG_F32 <= x <= GAMMA_NEG_MAX_F32. The exponent is kept as an
+// unevaluated sum, since exp turns its absolute error into relative error.
+#[inline(always)]
+fn tgammaf_big(x: f32, c_hi: f32, c_lo: f32) -> f32 {
+    let (l, l_lo) = lnf_extended(x);
+
+    let t = x - 0.5;
+    let p = t * l;
+    let p_lo = t.mul_add(l, -p) + t * l_lo;
+
+    let q = p - x;
+    let q_lo = ((p - q) - x) + p_lo;
+
+    let r = q + c_hi;
+    let r_lo = ((q - r) + c_hi) + (q_lo + (c_lo + stirlingf_correction(x)));
+
+    expf_extended(r, r_lo)
+}
+
+fn tgammaf_pos(x: f32) -> f32 {
+    if x > GAMMA_MAX_F32 {
+        f32::INFINITY
+    } else if x >= GAMMA_BIG_F32 {
+        tgammaf_big(x, HALF_LN_2PI_HI_F32, HALF_LN_2PI_LO_F32)
+    } else if x < 1.0 {
+        1.0 / x.mul_add(rgammaf_poly(x), x)
+    } else {
+        let u = x - x.floor();
+
+        // The product is carried as p + p_lo, and the division by 1 + v
+        // corrected with its residual, so that the roundings do not add up
+        let mut p = 1.0;
+        let mut p_lo = 0.0;
+        for k in 1..x as usize {
+            let f = k as f32 + u;
+            let q = p * f;
+
+            p_lo = p.mul_add(f, -q) + p_lo * f;
+            p = q;
+        }
+
+        let v = rgammaf_poly(u);
+        let w = 1.0 + v;
+        let w_lo = (1.0 - w) + v;
+        let y = p / w;
+
+        y + (y.mul_add(-w, p) + (p_lo - y * w_lo)) / w
+    }
+}
+
+fn lgammaf_pos(x: f32) -> f32 {
+    if x >= GAMMA_BIG_F32 {
+        (x - 0.5) * (lnf(x) - 1.0)
+            + HALF_LN_2PI_M_HALF_F32
+            + stirlingf_correction(x)
+    } else if x < 1.0 {
+        -log1pf(rgammaf_poly(x)) - lnf(x)
+    } else {
+        let u = x - x.floor();
+        let v = rgammaf_poly(u);
+
+        let mut p = 1.0;
+        for k in 2..x as usize {
+            p *= k as f32 + u;
+        }
+
+        let t = if x >= 2.0 { u } else { 0.0 };
+
+        log1pf((t - v) / (1.0 + v)) + lnf(p)
+    }
+}
+
+/// The `f32` version of [`tgamma`].
+pub fn tgammaf(x: f32) -> f32 {
+    use std::f32::consts::PI;
+
+    if x.is_nan() || x > 0.0 {
+        tgammaf_pos(x)
+    } else if x > -GAMMA_TINY_F32 {
+        1.0 / x
+    } else if x == x.floor() {
+        f32::NAN
+    } else if x <= -GAMMA_NEG_BIG_F32 {
+        let a = (-x).min(GAMMA_NEG_MAX_F32);
+        let s = sinpif(x);
+        let g = tgammaf_big(
+            a,
+            HALF_LN_2PI_M64_LN2_HI_F32,
+            HALF_LN_2PI_M64_LN2_LO_F32,
+        );
+
+        PI / ((s * a) * g) * TWO_M64_F32
+    } else {
+        let a = -x;
+        let s = sinpif(x);
+        let g = tgammaf_pos(a);
+
+        PI / if a < 1.0 { s * (a * g) } else { (s * a) * g }
+    }
+}
+
+/// The `f32` version of [`lgamma`].
+pub fn lgammaf(x: f32) -> (f32, i32) {
+    if x.is_nan() || x > 0.0 {
+        (lgammaf_pos(x), 1)
+    } else if x == x.floor() {
+        let sign = if x == 0.0 && x.is_sign_negative() {
+            -1
+        } else {
+            1
+        };
+        (f32::INFINITY, sign)
+    } else if x > -GAMMA_TINY_F32 {
+        (-lnf(-x), -1)
+    } else {
+        let s = sinpif(x);
+        let sign = if s < 0.0 { -1 } else { 1 };
+
+        let y = if x > -1.0 {
+            LN_PI_F32 - lnf(s.abs()) - lgammaf_pos(1.0 - x)
+        } else {
+            LN_PI_F32 - lnf((s * x).abs()) - lgammaf_pos(-x)
+        };
+
+        (y, sign)
+    }
+}
+
+#[inline(always)]
+fn rgammaf_poly_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (u * (u - Simd::splat(1.0))) * polyvalf_simd(&GAMMA_MINIMAX_F32, u)
+}
+
+#[inline(always)]
+fn stirlingf_correction_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let t = x.recip();
+    polyvalf_simd(&STIRLING_MINIMAX_F32, t * t) * t
+}
+
+// Product of k + u over first <= k < floor(x) as p + p_lo, for the lanes
+// below GAMMA_BIG_F32
+#[inline(always)]
+fn shift_productf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    u: Simd<f32, LANES>,
+    first: usize,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let n = x.simd_min(Simd::splat(GAMMA_BIG_F32)).floor();
+
+    let mut p = Simd::splat(1.0);
+    let mut p_lo = Simd::splat(0.0);
+    for k in first..GAMMA_BIG_F32 as usize {
+        let k = Simd::splat(k as f32);
+        let mask = k.simd_lt(n);
+
+        if !mask.any() {
+            break;
+        }
+
+        let f = k + u;
+        let q = p * f;
+
+        p_lo = mask.select(p.mul_add(f, -q) + p_lo * f, p_lo);
+        p = mask.select(q, p);
+    }
+
+    (p, p_lo)
+}
+
+#[inline(always)]
+fn tgammaf_big_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    c_hi: Simd<f32, LANES>,
+    c_lo: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (l, l_lo) = lnf_extended_simd(x);
+
+    let t = x - Simd::splat(0.5);
+    let p = t * l;
+    let p_lo = t.mul_add(l, -p) + t * l_lo;
+
+    let q = p - x;
+    let q_lo = ((p - q) - x) + p_lo;
+
+    let r = q + c_hi;
+    let r_lo =
+        ((q - r) + c_hi) + (q_lo + (c_lo + stirlingf_correction_simd(x)));
+
+    expf_extended_simd(r, r_lo)
+}
+
+#[inline(always)]
+fn tgammaf_pos_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let small = x.simd_lt(one);
+    let big = x.simd_ge(Simd::splat(GAMMA_BIG_F32));
+
+    let u = small.select(x, x - x.floor());
+    let v = rgammaf_poly_simd(u);
+    let (p, p_lo) = shift_productf_simd(x, u, 1);
+
+    let w = one + v;
+    let w_lo = (one - w) + v;
+    let q = p / w;
+    let q = q + (q.mul_add(-w, p) + (p_lo - q * w_lo)) / w;
+
+    let mut y = small.select(one / x.mul_add(v, x), q);
+
+    if big.any() {
+        let xb = x
+            .simd_clamp(Simd::splat(GAMMA_BIG_F32), Simd::splat(GAMMA_MAX_F32));
+        let g = tgammaf_big_simd(
+            xb,
+            Simd::splat(HALF_LN_2PI_HI_F32),
+            Simd::splat(HALF_LN_2PI_LO_F32),
+        );
+
+        y = big.select(g, y);
+    }
+
+    x.simd_gt(Simd::splat(GAMMA_MAX_F32))
+        .select(Simd::splat(f32::INFINITY), y)
+}
+
+#[inline(always)]
+fn lgammaf_pos_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let small = x.simd_lt(one);
+    let big = x.simd_ge(Simd::splat(GAMMA_BIG_F32));
+
+    let u = small.select(x, x - x.floor());
+    let v = rgammaf_poly_simd(u);
+    let (p, _) = shift_productf_simd(x, u, 2);
+
+    let t = x.simd_ge(Simd::splat(2.0)).select(u, Simd::splat(0.0));
+
+    let y = log1pf_simd(small.select(v, (t - v) / (one + v)))
+        + lnf_simd(small.select(x, p));
+    let mut y = small.select(-y, y);
+
+    if big.any() {
+        let xb = x.simd_max(Simd::splat(GAMMA_BIG_F32));
+
+        let y_big = (xb - Simd::splat(0.5)) * (lnf_simd(xb) - one)
+            + Simd::splat(HALF_LN_2PI_M_HALF_F32)
+            + stirlingf_correction_simd(xb);
+
+        y = big.select(y_big, y);
+    }
+
+    y
+}
+
+/// The SIMD version of [`tgammaf`].
+#[inline(always)]
+pub fn tgammaf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::PI;
+
+    let a = x.abs();
+    let neg = x.simd_lt(Simd::splat(0.0));
+
+    let mut y = tgammaf_pos_simd(a);
+
+    if neg.any() {
+        let s = sinpif_simd(x);
+        let d = a.simd_lt(Simd::splat(1.0)).select(s * (a * y), (s * a) * y);
+
+        let mut y_neg = Simd::splat(PI) / d;
+
+        let far = x.simd_le(Simd::splat(-GAMMA_NEG_BIG_F32));
+        if far.any() {
+            let ab = a.simd_clamp(
+                Simd::splat(GAMMA_NEG_BIG_F32),
+                Simd::splat(GAMMA_NEG_MAX_F32),
+            );
+            let g = tgammaf_big_simd(
+                ab,
+                Simd::splat(HALF_LN_2PI_M64_LN2_HI_F32),
+                Simd::splat(HALF_LN_2PI_M64_LN2_LO_F32),
+            );
+
+            let y_far =
+                Simd::splat(PI) / ((s * ab) * g) * Simd::splat(TWO_M64_F32);
+            y_neg = far.select(y_far, y_neg);
+        }
+
+        let y_neg = x.simd_eq(x.floor()).select(Simd::splat(f32::NAN), y_neg);
+
+        y = neg.select(y_neg, y);
+    }
+
+    let tiny =
+        x.simd_gt(Simd::splat(-GAMMA_TINY_F32)) & x.simd_le(Simd::splat(0.0));
+
+    tiny.select(x.recip(), y)
+}
+
+/// The SIMD version of [`lgammaf`].
+#[inline(always)]
+pub fn lgammaf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<i32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let one = Simd::splat(1.0);
+
+    let neg = x.simd_lt(zero);
+    let near = x.simd_gt(-one);
+
+    let z = neg.select(near.select(one - x, -x), x);
+
+    let mut y = lgammaf_pos_simd(z);
+    let mut sign = one;
+
+    if neg.any() {
+        let s = sinpif_simd(x);
+
+        let y_neg =
+            Simd::splat(LN_PI_F32) - lnf_simd(near.select(s, s * x).abs()) - y;
+
+        let y_tiny = -lnf_simd(-x);
+
+        y = neg.select(y_neg, y);
+        y = (neg & x.simd_gt(Simd::splat(-GAMMA_TINY_F32))).select(y_tiny, y);
+        sign = (neg & s.simd_lt(zero)).select(-one, sign);
+    }
+
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+
+    let y = pole.select(Simd::splat(f32::INFINITY), y);
+    let sign = pole.select(one, sign);
+    let sign = (x.simd_eq(zero) & x.is_sign_negative()).select(-one, sign);
+
+    (y, sign.cast())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::LN_2, simd::Simd};
+
+    use crate::{tests::ulp_diff, *};
+
+    // (x, gamma(x), ln(|gamma(x)|)), correctly rounded
+    #[rustfmt::skip]
+    const GAMMA_TABLE: [(f64, f64, f64); 20] = [
+        (1e-300, 9.999999999999999e299, 690.7755278982137),
+        (1e-8, 99999999.42278434, 18.42068073818021),
+        (0.5, 1.772453850905516, 0.5723649429247001),
+        (1.0, 1.0, 0.0),
+        (1.5, 0.886226925452758, -0.12078223763524522),
+        (2.0, 1.0, 0.0),
+        (2.5, 1.329340388179137, 0.2846828704729192),
+        (3.0, 2.0, LN_2),
+        (5.0, 24.0, 3.1780538303479458),
+        (7.9, 4122.709484285445, 8.32426586800881),
+        (10.0, 362880.0, 12.801827480081469),
+        (20.5, 5.406242982335075e17, 40.8315009745308),
+        (100.0, 9.332621544394415e155, 359.1342053695754),
+        (171.5, 9.4833675668248e307, 709.1431630309282),
+        (-0.5, -3.544907701811032, 1.2655121234846454),
+        (-1.5, 2.363271801207355, 0.860047015376481),
+        (-2.5, -0.9453087204829419, -0.056243716497674054),
+        (-7.25, 0.0005303977063521478, -7.54188344347575),
+        (-100.5, -3.3536908198076787e-159, -364.90096830942736),
+        (-170.5, -3.3127395215386074e-308, -707.9984331450788),
+    ];
+
+    #[test]
+    fn test_gamma_ulp() {
+        for (x, gamma_ref, lgamma_ref) in GAMMA_TABLE {
+            let xs = Simd::<f64, 4>::splat(x);
+            let sign_ref = gamma_ref.signum() as i32;
+
+            assert!(ulp_diff(tgamma(x), gamma_ref) <= 5, "{x}");
+            if x > 0.0 {
+                assert!(ulp_diff(lgamma(x).0, lgamma_ref) <= 3, "{x}");
+            } else {
+                let err = (lgamma(x).0 - lgamma_ref).abs();
+                assert!(err <= 1e-15 * lgamma_ref.abs().max(1.0), "{x}");
+            }
+            assert_eq!(lgamma(x).1, sign_ref, "{x}");
+
+            let (y, sign) = lgamma_simd(xs);
+            assert_eq!(tgamma_simd(xs)[0], tgamma(x), "{x}");
+            assert_eq!((y[0], sign[0]), lgamma(x), "{x}");
+            assert_eq!(xs.gamma()[0], tgamma(x), "{x}");
+            assert_eq!(xs.ln_gamma().0[0], lgamma(x).0, "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let gammaf_ref = tgamma(xf as f64) as f32;
+            let lgammaf_ref = lgamma(xf as f64).0;
+            let errf = (lgammaf(xf).0 as f64 - lgammaf_ref).abs();
+            assert!(
+                tgammaf(xf).to_bits().abs_diff(gammaf_ref.to_bits()) <= 4,
+                "{x}"
+            );
+            if lgammaf_ref.is_finite() {
+                assert!(errf <= 4e-7 * lgammaf_ref.abs().max(1.0), "{x}");
+            }
+            assert_eq!(lgammaf(xf).1, lgamma(xf as f64).1, "{x}");
+            assert_eq!(tgammaf_simd(xfs)[0], tgammaf(xf), "{x}");
+            assert_eq!(lgammaf_simd(xfs).0[0], lgammaf(xf).0, "{x}");
+            assert_eq!(lgammaf_simd(xfs).1[0], lgammaf(xf).1, "{x}");
+        }
+
+        // Small factorials are exact
+        let mut factorial = 1.0;
+        for n in 1..8 {
+            let x = n as f64;
+            assert_eq!(tgamma(x), factorial);
+            assert_eq!(tgamma_simd(Simd::<f64, 4>::splat(x))[0], factorial);
+            factorial *= x;
+        }
+
+        assert!(ulp_diff(lgamma(1e10).0, 220258509288.81058) <= 1);
+        assert!(ulp_diff(lgamma(1e300).0, 6.897755278982137e302) <= 1);
+    }
+
+    #[test]
+    fn test_gamma_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        #[rustfmt::skip]
+        let table: [(f64, f64, f64, i32); 18] = [
+            (0.0, INF, INF, 1),
+            (-0.0, -INF, INF, -1),
+            // Tiny negative x, where gamma(x) overflows and sin(pi x)
+            // underflows
+            (-1e-310, -INF, 713.8013788281542, -1),
+            (-5e-324, -INF, 744.4400719213812, -1),
+            (-1.0, NAN, INF, 1),
+            (-2.0, NAN, INF, 1),
+            (-1e300, NAN, INF, 1),
+            (INF, INF, INF, 1),
+            (-INF, NAN, INF, 1),
+            (NAN, NAN, NAN, 1),
+            // Overflow of gamma, but not of lgamma
+            (171.7, INF, 710.171612940375, 1),
+            (1e10, INF, 220258509288.81058, 1),
+            // lgamma overflows too
+            (1e307, INF, INF, 1),
+            // Subnormal and underflowing results for negative x
+            (-171.5, 1.9316265431712e-310, -713.1430164116848, 1),
+            (-172.5, -1.11978350329e-312, -718.2934136481563, -1),
+            (-175.25, 1.085373e-318, -732.1401377472422, 1),
+            (-177.5, 5e-324, -744.1314465173804, 1),
+            (-200.5, -0.0, -864.7382878706798, -1),
+        ];
+
+        for (x, gamma_ref, lgamma_ref, sign_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+            let (y, sign) = lgamma_simd(xs);
+
+            assert_eq!(ulp_diff(tgamma(x), gamma_ref), 0, "{x}");
+            assert_eq!(ulp_diff(tgamma_simd(xs)[0], gamma_ref), 0, "{x}");
+            assert!(ulp_diff(lgamma(x).0, lgamma_ref) <= 1, "{x}");
+            assert!(ulp_diff(y[0], lgamma_ref) <= 1, "{x}");
+            assert_eq!(lgamma(x).1, sign_ref, "{x}");
+            assert_eq!(sign[0], sign_ref, "{x}");
+        }
+
+        assert!(tgamma(-200.5).is_sign_negative());
+        assert!(tgamma(-201.5).is_sign_positive());
+
+        // The f32 versions have the same special values, overflow and
+        // underflow, at their own thresholds
+        for x in [
+            0.0, -0.0, -1.0, INF, -INF, NAN, 35.04, 35.05, 1e-40, -1e-45,
+            4.1e36, -35.5, -38.5, -44.5, -100.5,
+        ] {
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let (yf, signf) = lgammaf(xf);
+            let (y_ref, sign_ref) = lgamma(xf as f64);
+
+            assert_eq!(
+                ulp_diff(tgammaf(xf) as f64, tgamma(xf as f64) as f32 as f64),
+                0
+            );
+            assert!(
+                yf.to_bits().abs_diff((y_ref as f32).to_bits()) <= 2,
+                "{x}"
+            );
+            assert_eq!(signf, sign_ref, "{x}");
+            assert_eq!(
+                ulp_diff(tgammaf_simd(xfs)[0] as f64, tgammaf(xf) as f64),
+                0
+            );
+            assert_eq!(ulp_diff(lgammaf_simd(xfs).0[0] as f64, yf as f64), 0);
+        }
+
+        assert!(tgammaf(-0.0).is_sign_negative());
+        assert!(tgammaf(-44.5).is_sign_negative());
+    }
+}
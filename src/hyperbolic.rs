@@ -0,0 +1,842 @@
+use std::{
+    f64::consts::{LN_2, LOG2_E},
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    exp::{
+        exp_scaled, exp_scaled_simd, expf_scaled, expf_scaled_simd, LN2_HI,
+        LN2_HI_F32, LN2_LO, LN2_LO_F32,
+    },
+    expm1, expm1_simd, expm1f, expm1f_simd, ln, ln_simd, lnf, lnf_simd, log1p,
+    log1p_simd, log1pf, log1pf_simd,
+};
+
+// Above this, exp(-|x|) is lost next to exp(|x|) in sinh and cosh, and tanh
+// rounds to 1
+const HYP_BIG: f64 = 22.0;
+
+// sinh and cosh overflow a little below this. Clamping here keeps the
+// exponent in half_exp in range.
+const HYP_MAX: f64 = 711.0;
+
+// exp(x) / 2 for x in [HYP_BIG, HYP_MAX]. Halving through the exponent
+// instead of the result means it does not overflow before sinh and cosh do.
+#[inline(always)]
+fn half_exp(x: f64) -> f64 {
+    let k = (x * LOG2_E).round();
+    let u = k.mul_add(-LN2_HI, x);
+    let u = k.mul_add(-LN2_LO, u);
+
+    exp_scaled(u, k - 1.0)
+}
+
+/// Computes the hyperbolic sine.
+pub fn sinh(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if x.is_nan() {
+        x
+    } else if a < HYP_BIG {
+        // sinh(a) = (t + t / (t + 1)) / 2 with t = exp(a) - 1, which does not
+        // cancel near zero the way exp(a) - exp(-a) does
+        let t = expm1(a);
+
+        if a < 1.0 {
+            0.5 * (2.0 * t - t * t / (t + 1.0))
+        } else {
+            0.5 * (t + t / (t + 1.0))
+        }
+    } else if a < HYP_MAX {
+        half_exp(a)
+    } else {
+        f64::INFINITY
+    };
+
+    y.copysign(x)
+}
+
+/// Computes the hyperbolic cosine.
+pub fn cosh(x: f64) -> f64 {
+    let a = x.abs();
+
+    if x.is_nan() {
+        x
+    } else if a < HYP_BIG {
+        // t = exp(a) - 1, and below ln(2) / 2 cosh(a) = 1 + t^2 / (2 (1 + t))
+        let t = expm1(a);
+        let w = 1.0 + t;
+
+        if a < 0.5 * LN_2 {
+            1.0 + (t * t) / (w + w)
+        } else {
+            0.5 * w + 0.5 / w
+        }
+    } else if a < HYP_MAX {
+        half_exp(a)
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Computes the hyperbolic tangent.
+pub fn tanh(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if x.is_nan() {
+        x
+    } else if a < 1.0 {
+        let t = expm1(-2.0 * a);
+        -t / (t + 2.0)
+    } else if a < HYP_BIG {
+        let t = expm1(2.0 * a);
+        1.0 - 2.0 / (t + 2.0)
+    } else {
+        1.0
+    };
+
+    y.copysign(x)
+}
+
+#[inline(always)]
+fn half_exp_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.simd_clamp(Simd::splat(HYP_BIG), Simd::splat(HYP_MAX));
+
+    let k = (x * Simd::splat(LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI), x);
+    let u = k.mul_add(Simd::splat(-LN2_LO), u);
+
+    exp_scaled_simd(u, k - Simd::splat(1.0))
+}
+
+/// The SIMD version of [`sinh`].
+#[inline(always)]
+pub fn sinh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+
+    let one = Simd::splat(1.0);
+
+    let t = expm1_simd(a.simd_min(Simd::splat(HYP_BIG)));
+    let y_small = a
+        .simd_lt(one)
+        .select(Simd::splat(2.0) * t - t * t / (t + one), t + t / (t + one))
+        * Simd::splat(0.5);
+
+    let y = a
+        .simd_lt(Simd::splat(HYP_BIG))
+        .select(y_small, half_exp_simd(a));
+    let y = a
+        .simd_ge(Simd::splat(HYP_MAX))
+        .select(Simd::splat(f64::INFINITY), y);
+
+    x.is_nan().select(x, y.copysign(x))
+}
+
+/// The SIMD version of [`cosh`].
+#[inline(always)]
+pub fn cosh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let half = Simd::splat(0.5);
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+
+    let t = expm1_simd(a.simd_min(Simd::splat(HYP_BIG)));
+    let w = one + t;
+
+    let y_small = one + (t * t) / (w + w);
+    let y_mid = half * w + half / w;
+
+    let y = a.simd_lt(Simd::splat(0.5 * LN_2)).select(y_small, y_mid);
+    let y = a.simd_lt(Simd::splat(HYP_BIG)).select(y, half_exp_simd(a));
+    let y = a
+        .simd_ge(Simd::splat(HYP_MAX))
+        .select(Simd::splat(f64::INFINITY), y);
+
+    x.is_nan().select(x, y)
+}
+
+/// The SIMD version of [`tanh`].
+#[inline(always)]
+pub fn tanh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+    let two = Simd::splat(2.0);
+
+    let a = x.abs().simd_min(Simd::splat(HYP_BIG));
+
+    let small = a.simd_lt(one);
+
+    let t = expm1_simd(small.select(-two * a, two * a));
+    let y = small.select(-t / (t + two), one - two / (t + two));
+
+    let y = a.simd_ge(Simd::splat(HYP_BIG)).select(one, y);
+
+    x.is_nan().select(x, y.copysign(x))
+}
+
+// The f32 thresholds, with the same meaning as above
+const HYPF_BIG: f32 = 9.0;
+const HYPF_MAX: f32 = 90.0;
+
+#[inline(always)]
+fn half_expf(x: f32) -> f32 {
+    let k = (x * std::f32::consts::LOG2_E).round();
+    let u = k.mul_add(-LN2_HI_F32, x);
+    let u = k.mul_add(-LN2_LO_F32, u);
+
+    expf_scaled(u, k - 1.0)
+}
+
+/// The `f32` version of [`sinh`].
+pub fn sinhf(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if x.is_nan() {
+        x
+    } else if a < HYPF_BIG {
+        let t = expm1f(a);
+
+        if a < 1.0 {
+            0.5 * (2.0 * t - t * t / (t + 1.0))
+        } else {
+            0.5 * (t + t / (t + 1.0))
+        }
+    } else {
+        half_expf(a.min(HYPF_MAX))
+    };
+
+    y.copysign(x)
+}
+
+/// The `f32` version of [`cosh`].
+pub fn coshf(x: f32) -> f32 {
+    let a = x.abs();
+
+    if x.is_nan() {
+        x
+    } else if a < HYPF_BIG {
+        let t = expm1f(a);
+        let w = 1.0 + t;
+
+        if a < 0.5 * std::f32::consts::LN_2 {
+            1.0 + (t * t) / (w + w)
+        } else {
+            0.5 * w + 0.5 / w
+        }
+    } else {
+        half_expf(a.min(HYPF_MAX))
+    }
+}
+
+/// The `f32` version of [`tanh`].
+pub fn tanhf(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if x.is_nan() {
+        x
+    } else if a < 1.0 {
+        let t = expm1f(-2.0 * a);
+        -t / (t + 2.0)
+    } else if a < HYPF_BIG {
+        let t = expm1f(2.0 * a);
+        1.0 - 2.0 / (t + 2.0)
+    } else {
+        1.0
+    };
+
+    y.copysign(x)
+}
+
+#[inline(always)]
+fn half_expf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x.simd_clamp(Simd::splat(HYPF_BIG), Simd::splat(HYPF_MAX));
+
+    let k = (x * Simd::splat(std::f32::consts::LOG2_E)).round();
+    let u = k.mul_add(Simd::splat(-LN2_HI_F32), x);
+    let u = k.mul_add(Simd::splat(-LN2_LO_F32), u);
+
+    expf_scaled_simd(u, k - Simd::splat(1.0))
+}
+
+/// The SIMD version of [`sinhf`].
+#[inline(always)]
+pub fn sinhf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = x.abs();
+
+    let one = Simd::splat(1.0);
+
+    let t = expm1f_simd(a.simd_min(Simd::splat(HYPF_BIG)));
+    let y_small = a
+        .simd_lt(one)
+        .select(Simd::splat(2.0) * t - t * t / (t + one), t + t / (t + one))
+        * Simd::splat(0.5);
+
+    let y = a
+        .simd_lt(Simd::splat(HYPF_BIG))
+        .select(y_small, half_expf_simd(a));
+
+    x.is_nan().select(x, y.copysign(x))
+}
+
+/// The SIMD version of [`coshf`].
+#[inline(always)]
+pub fn coshf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let half = Simd::splat(0.5);
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+
+    let t = expm1f_simd(a.simd_min(Simd::splat(HYPF_BIG)));
+    let w = one + t;
+
+    let y_small = one + (t * t) / (w + w);
+    let y_mid = half * w + half / w;
+
+    let y = a
+        .simd_lt(Simd::splat(0.5 * std::f32::consts::LN_2))
+        .select(y_small, y_mid);
+    let y = a
+        .simd_lt(Simd::splat(HYPF_BIG))
+        .select(y, half_expf_simd(a));
+
+    x.is_nan().select(x, y)
+}
+
+/// The SIMD version of [`tanhf`].
+#[inline(always)]
+pub fn tanhf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+    let two = Simd::splat(2.0);
+
+    let a = x.abs().simd_min(Simd::splat(HYPF_BIG));
+
+    let small = a.simd_lt(one);
+
+    let t = expm1f_simd(small.select(-two * a, two * a));
+    let y = small.select(-t / (t + two), one - two / (t + two));
+
+    let y = a.simd_ge(Simd::splat(HYPF_BIG)).select(one, y);
+
+    x.is_nan().select(x, y.copysign(x))
+}
+
+// Above this, sqrt(x^2 + 1) and sqrt(x^2 - 1) round to x, and
+// asinh(x) = acosh(x) = ln(2 x)
+const AHYP_BIG: f64 = 268_435_456.0;
+
+/// Computes the inverse hyperbolic sine.
+pub fn asinh(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if a > AHYP_BIG {
+        // Also covers infinity, and ln(a) + ln(2) does not overflow
+        ln(a) + LN_2
+    } else if a > 2.0 {
+        ln(2.0 * a + 1.0 / ((a * a + 1.0).sqrt() + a))
+    } else {
+        // Accurate near zero, where ln(a + sqrt(a^2 + 1)) would round
+        let a2 = a * a;
+        log1p(a + a2 / (1.0 + (1.0 + a2).sqrt()))
+    };
+
+    y.copysign(x)
+}
+
+/// Computes the inverse hyperbolic cosine. Returns NaN for `x < 1`.
+pub fn acosh(x: f64) -> f64 {
+    if x < 1.0 || x.is_nan() {
+        f64::NAN
+    } else if x > AHYP_BIG {
+        ln(x) + LN_2
+    } else if x > 2.0 {
+        ln(2.0 * x - 1.0 / (x + (x * x - 1.0).sqrt()))
+    } else {
+        // x - 1 is exact here, which keeps the result accurate near 1
+        let t = x - 1.0;
+        log1p(t + (2.0 * t + t * t).sqrt())
+    }
+}
+
+/// Computes the inverse hyperbolic tangent. Returns infinity with the
+/// sign of `x` at `-1` and `1`, and NaN for `|x| > 1`.
+pub fn atanh(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if a > 1.0 || x.is_nan() {
+        f64::NAN
+    } else if a < 0.5 {
+        // atanh(a) = log1p(2 a / (1 - a)) / 2, rearranged to keep the
+        // argument of log1p accurate near zero
+        let t = a + a;
+        0.5 * log1p(t + t * a / (1.0 - a))
+    } else {
+        // Gives inf for a = 1
+        0.5 * log1p((a + a) / (1.0 - a))
+    };
+
+    y.copysign(x)
+}
+
+/// The SIMD version of [`asinh`].
+#[inline(always)]
+pub fn asinh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let a2 = a * a;
+
+    let ln_arg = Simd::splat(2.0) * a + one / ((a2 + one).sqrt() + a);
+    let log1p_arg = a + a2 / (one + (one + a2).sqrt());
+
+    let big = a.simd_gt(Simd::splat(AHYP_BIG));
+    let mid = a.simd_gt(Simd::splat(2.0));
+
+    // Both logarithms in one call, one of them is thrown away
+    let y = mid.select(ln_simd(big.select(a, ln_arg)), log1p_simd(log1p_arg));
+    let y = big.select(y + Simd::splat(LN_2), y);
+
+    y.copysign(x)
+}
+
+/// The SIMD version of [`acosh`].
+#[inline(always)]
+pub fn acosh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+    let two = Simd::splat(2.0);
+
+    let t = x - one;
+
+    let ln_arg = two * x - one / (x + (x * x - one).sqrt());
+    let log1p_arg = t + (two * t + t * t).sqrt();
+
+    let big = x.simd_gt(Simd::splat(AHYP_BIG));
+    let mid = x.simd_gt(two);
+
+    let y = mid.select(ln_simd(big.select(x, ln_arg)), log1p_simd(log1p_arg));
+    let y = big.select(y + Simd::splat(LN_2), y);
+
+    x.simd_lt(one).select(Simd::splat(f64::NAN), y)
+}
+
+/// The SIMD version of [`atanh`].
+#[inline(always)]
+pub fn atanh_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let t = a + a;
+
+    let log1p_arg = a
+        .simd_lt(Simd::splat(0.5))
+        .select(t + t * a / (one - a), t / (one - a));
+
+    let y = Simd::splat(0.5) * log1p_simd(log1p_arg);
+
+    a.simd_gt(one).select(Simd::splat(f64::NAN), y.copysign(x))
+}
+
+// The f32 version of AHYP_BIG
+const AHYPF_BIG: f32 = 4096.0;
+
+/// The `f32` version of [`asinh`].
+pub fn asinhf(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if a > AHYPF_BIG {
+        lnf(a) + std::f32::consts::LN_2
+    } else if a > 2.0 {
+        lnf(2.0 * a + 1.0 / ((a * a + 1.0).sqrt() + a))
+    } else {
+        let a2 = a * a;
+        log1pf(a + a2 / (1.0 + (1.0 + a2).sqrt()))
+    };
+
+    y.copysign(x)
+}
+
+/// The `f32` version of [`acosh`].
+pub fn acoshf(x: f32) -> f32 {
+    if x < 1.0 || x.is_nan() {
+        f32::NAN
+    } else if x > AHYPF_BIG {
+        lnf(x) + std::f32::consts::LN_2
+    } else if x > 2.0 {
+        lnf(2.0 * x - 1.0 / (x + (x * x - 1.0).sqrt()))
+    } else {
+        let t = x - 1.0;
+        log1pf(t + (2.0 * t + t * t).sqrt())
+    }
+}
+
+/// The `f32` version of [`atanh`].
+pub fn atanhf(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if a > 1.0 || x.is_nan() {
+        f32::NAN
+    } else if a < 0.5 {
+        let t = a + a;
+        0.5 * log1pf(t + t * a / (1.0 - a))
+    } else {
+        0.5 * log1pf((a + a) / (1.0 - a))
+    };
+
+    y.copysign(x)
+}
+
+/// The SIMD version of [`asinhf`].
+#[inline(always)]
+pub fn asinhf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let a2 = a * a;
+
+    let ln_arg = Simd::splat(2.0) * a + one / ((a2 + one).sqrt() + a);
+    let log1p_arg = a + a2 / (one + (one + a2).sqrt());
+
+    let big = a.simd_gt(Simd::splat(AHYPF_BIG));
+    let mid = a.simd_gt(Simd::splat(2.0));
+
+    let y = mid.select(lnf_simd(big.select(a, ln_arg)), log1pf_simd(log1p_arg));
+    let y = big.select(y + Simd::splat(std::f32::consts::LN_2), y);
+
+    y.copysign(x)
+}
+
+/// The SIMD version of [`acoshf`].
+#[inline(always)]
+pub fn acoshf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+    let two = Simd::splat(2.0);
+
+    let t = x - one;
+
+    let ln_arg = two * x - one / (x + (x * x - one).sqrt());
+    let log1p_arg = t + (two * t + t * t).sqrt();
+
+    let big = x.simd_gt(Simd::splat(AHYPF_BIG));
+    let mid = x.simd_gt(two);
+
+    let y = mid.select(lnf_simd(big.select(x, ln_arg)), log1pf_simd(log1p_arg));
+    let y = big.select(y + Simd::splat(std::f32::consts::LN_2), y);
+
+    x.simd_lt(one).select(Simd::splat(f32::NAN), y)
+}
+
+/// The SIMD version of [`atanhf`].
+#[inline(always)]
+pub fn atanhf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let a = x.abs();
+    let t = a + a;
+
+    let log1p_arg = a
+        .simd_lt(Simd::splat(0.5))
+        .select(t + t * a / (one - a), t / (one - a));
+
+    let y = Simd::splat(0.5) * log1pf_simd(log1p_arg);
+
+    a.simd_gt(one).select(Simd::splat(f32::NAN), y.copysign(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, speed_test_simd_iterated, ulp_diff,
+        },
+        *,
+    };
+
+    const X: [f64; 8] = [
+        -6.470329170669899,
+        7.608185328297425,
+        3.03226005318477,
+        1.6990497408119154,
+        -5.422265238742455,
+        -3.9968940734442704,
+        0.05683523314814294,
+        -0.4318069330898973,
+    ];
+
+    #[test]
+    fn test_sinh() {
+        accuracy_test(&X, |x| x.sinh(), sinh);
+        accuracy_test_simd(X, |x| x.sinh(), |x| x.sinh());
+    }
+
+    #[test]
+    fn test_cosh() {
+        accuracy_test(&X, |x| x.cosh(), cosh);
+        accuracy_test_simd(X, |x| x.cosh(), |x| x.cosh());
+    }
+
+    #[test]
+    fn test_tanh() {
+        accuracy_test(&X, |x| x.tanh(), tanh);
+        accuracy_test_simd(X, |x| x.tanh(), |x| x.tanh());
+    }
+
+    const X_F32: [f32; 8] = [
+        -6.470329,
+        7.6081853,
+        3.03226,
+        1.6990497,
+        -5.4222655,
+        -3.996894,
+        0.056835234,
+        -0.43180692,
+    ];
+
+    #[test]
+    fn test_hyperbolicf() {
+        accuracy_testf(&X_F32, |x| x.sinh(), sinhf);
+        accuracy_testf(&X_F32, |x| x.cosh(), coshf);
+        accuracy_testf(&X_F32, |x| x.tanh(), tanhf);
+        accuracy_test_simdf(X_F32, |x| x.sinh(), |x| x.sinh());
+        accuracy_test_simdf(X_F32, |x| x.cosh(), |x| x.cosh());
+        accuracy_test_simdf(X_F32, |x| x.tanh(), |x| x.tanh());
+    }
+
+    #[test]
+    fn test_hyperbolic_ulp() {
+        const N: usize = 100000;
+
+        // Including tiny arguments, where sinh(x) and tanh(x) are close to x
+        for (lo, hi) in [(-1e-300, 1e-300), (-1e-5, 1e-5), (-30.0, 30.0)] {
+            for i in 0..N {
+                let x = lo + (hi - lo) * (i as f64 + 0.5) / N as f64;
+                let x_simd = Simd::<f64, 4>::splat(x);
+
+                assert!(ulp_diff(sinh(x), x.sinh()) <= 2, "{x}");
+                assert!(ulp_diff(x_simd.sinh()[0], x.sinh()) <= 2, "{x}");
+                assert!(ulp_diff(cosh(x), x.cosh()) <= 2, "{x}");
+                assert!(ulp_diff(x_simd.cosh()[0], x.cosh()) <= 2, "{x}");
+                assert!(ulp_diff(tanh(x), x.tanh()) <= 3, "{x}");
+                assert!(ulp_diff(x_simd.tanh()[0], x.tanh()) <= 3, "{x}");
+            }
+        }
+
+        // Up to the overflow threshold
+        for i in 0..N {
+            let x = 30.0 + 680.0 * (i as f64 + 0.5) / N as f64;
+            let x_simd = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(sinh(x), x.sinh()) <= 1, "{x}");
+            assert!(ulp_diff(x_simd.sinh()[0], x.sinh()) <= 1, "{x}");
+            assert!(ulp_diff(cosh(-x), x.cosh()) <= 1, "{x}");
+            assert!(ulp_diff(x_simd.cosh()[0], x.cosh()) <= 1, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_simd_matches_scalar() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let x = -720.0 + 1440.0 * (i as f64 + 0.5) / N as f64;
+            let x_simd = Simd::<f64, 4>::splat(x);
+
+            assert_eq!(x_simd.sinh()[0].to_bits(), sinh(x).to_bits(), "{x}");
+            assert_eq!(x_simd.cosh()[0].to_bits(), cosh(x).to_bits(), "{x}");
+            assert_eq!(x_simd.tanh()[0].to_bits(), tanh(x).to_bits(), "{x}");
+
+            let xf = (x / 8.0) as f32;
+            let xf_simd = Simd::<f32, 4>::splat(xf);
+
+            assert_eq!(
+                xf_simd.sinh()[0].to_bits(),
+                sinhf(xf).to_bits(),
+                "{xf}"
+            );
+            assert_eq!(
+                xf_simd.cosh()[0].to_bits(),
+                coshf(xf).to_bits(),
+                "{xf}"
+            );
+            assert_eq!(
+                xf_simd.tanh()[0].to_bits(),
+                tanhf(xf).to_bits(),
+                "{xf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        let x = [0.0, -0.0, INF, -INF, NAN, 710.5, -711.0, 1e300];
+
+        let y_sinh = [0.0, -0.0, INF, -INF, NAN, INF, -INF, INF];
+        let y_cosh = [1.0, 1.0, INF, INF, NAN, INF, INF, INF];
+        let y_tanh = [0.0, -0.0, 1.0, -1.0, NAN, 1.0, -1.0, 1.0];
+
+        let x_simd = Simd::from(x);
+        let (sinh_simd, cosh_simd, tanh_simd) =
+            (x_simd.sinh(), x_simd.cosh(), x_simd.tanh());
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(sinh(x[i]), y_sinh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(sinh_simd[i], y_sinh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(cosh(x[i]), y_cosh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(cosh_simd[i], y_cosh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(tanh(x[i]), y_tanh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(tanh_simd[i], y_tanh[i]), 0, "{}", x[i]);
+        }
+    }
+
+    #[test]
+    fn test_asinh() {
+        accuracy_test(&X, |x| x.asinh(), asinh);
+        accuracy_test_simd(X, |x| x.asinh(), |x| x.asinh());
+    }
+
+    #[test]
+    fn test_acosh() {
+        let x = X.map(|x| 1.0 + x.abs());
+
+        accuracy_test(&x, |x| x.acosh(), acosh);
+        accuracy_test_simd(x, |x| x.acosh(), |x| x.acosh());
+    }
+
+    #[test]
+    fn test_atanh() {
+        let x = X.map(|x| x / 8.0);
+
+        accuracy_test(&x, |x| x.atanh(), atanh);
+        accuracy_test_simd(x, |x| x.atanh(), |x| x.atanh());
+    }
+
+    #[test]
+    fn test_inverse_hyperbolicf() {
+        let x_acosh = X_F32.map(|x| 1.0 + x.abs());
+        let x_atanh = X_F32.map(|x| x / 8.0);
+
+        accuracy_testf(&X_F32, |x| x.asinh(), asinhf);
+        accuracy_testf(&x_acosh, |x| x.acosh(), acoshf);
+        accuracy_testf(&x_atanh, |x| x.atanh(), atanhf);
+        accuracy_test_simdf(X_F32, |x| x.asinh(), |x| x.asinh());
+        accuracy_test_simdf(x_acosh, |x| x.acosh(), |x| x.acosh());
+        accuracy_test_simdf(x_atanh, |x| x.atanh(), |x| x.atanh());
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let t = (i as f64 + 0.5) / N as f64;
+
+            // asinh over the whole range, including huge and tiny arguments
+            let x = (-1020.0 + 2040.0 * t).exp2();
+            let x_simd = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(asinh(x), x.asinh()) <= 2, "{x}");
+            assert!(ulp_diff(x_simd.asinh()[0], x.asinh()) <= 2, "{x}");
+
+            // acosh close to 1 and up to huge arguments
+            let x = 1.0 + (-50.0 + 1070.0 * t).exp2();
+            let x_simd = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(acosh(x), x.acosh()) <= 2, "{x}");
+            assert!(ulp_diff(x_simd.acosh()[0], x.acosh()) <= 2, "{x}");
+
+            // atanh close to 0 and close to 1. The reference has no
+            // cancellation, unlike std's atanh, which is off by a few ulps
+            // around |x| = 0.45.
+            for x in [-(-1000.0 * t).exp2(), 1.0 - (-52.0 * t).exp2()] {
+                let x_simd = Simd::<f64, 4>::splat(x);
+                let y_ref = 0.5 * (x.ln_1p() - (-x).ln_1p());
+
+                assert!(ulp_diff(atanh(x), y_ref) <= 2, "{x}");
+                assert!(ulp_diff(x_simd.atanh()[0], y_ref) <= 2, "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        let x = [0.0, -0.0, 1.0, -1.0, 1e-300, -INF, INF, NAN];
+
+        let y_asinh = [0.0, -0.0, 1f64.asinh(), -1f64.asinh(), 1e-300];
+        let y_asinh = [y_asinh.as_slice(), &[-INF, INF, NAN]].concat();
+        let y_acosh = [NAN, NAN, 0.0, NAN, NAN, NAN, INF, NAN];
+        let y_atanh = [0.0, -0.0, INF, -INF, 1e-300, NAN, NAN, NAN];
+
+        let x_simd = Simd::from(x);
+        let (asinh_simd, acosh_simd, atanh_simd) =
+            (x_simd.asinh(), x_simd.acosh(), x_simd.atanh());
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(asinh(x[i]), y_asinh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(asinh_simd[i], y_asinh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(acosh(x[i]), y_acosh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(acosh_simd[i], y_acosh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(atanh(x[i]), y_atanh[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(atanh_simd[i], y_atanh[i]), 0, "{}", x[i]);
+        }
+
+        for x in [1.5, -2.0, 1e300] {
+            assert!(atanh(x).is_nan());
+            assert!(Simd::<f64, 4>::splat(x).atanh()[0].is_nan());
+        }
+    }
+
+    #[test]
+    fn test_tanh_simd_speed() {
+        const ITERS: usize = 1000000;
+
+        speed_test_simd_iterated(X, |x| x.tanh(), |x| x.tanh(), ITERS);
+    }
+}
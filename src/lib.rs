@@ -12,8 +12,32 @@ pub use trig::*;
 mod invtrig;
 pub use invtrig::*;
 
+mod hyperbolic;
+pub use hyperbolic::*;
+
+mod erf;
+pub use erf::*;
+
+mod gamma;
+pub use gamma::*;
+
+mod polygamma;
+pub use polygamma::*;
+
+mod bessel;
+pub use bessel::*;
+
+mod modified_bessel;
+pub use modified_bessel::*;
+
 mod log;
 pub use log::*;
 
+mod pow;
+pub use pow::*;
+
+mod root;
+pub use root::*;
+
 mod simdfloatmath_trait;
 pub use simdfloatmath_trait::SimdFloatMath;
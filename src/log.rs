@@ -1,38 +1,33 @@
 use std::{
-    f64::consts::{FRAC_1_SQRT_2, LN_2, SQRT_2},
-    simd::{prelude::*, LaneCount, Simd, SupportedLaneCount},
+    f64::consts::{FRAC_1_SQRT_2, LOG10_E, LOG2_E, SQRT_2},
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
 };
 
-use crate::{polyval, polyval_simd, powi_simd_pos};
-
-// f(x) = ln(x + 1)
-// domain: (2^(-1/4) - 1, 2^(1/4) - 1)
-const TAYLOR: [f64; 18] = [
-    0.05830012414910911,
-    -0.0724454848037986,
-    0.06745456264338053,
-    -0.07071157326414279,
-    0.0768621149324746,
-    -0.08336128638736695,
-    0.09091112804841374,
-    -0.09999937157336908,
-    0.1111110784241662,
-    -0.12500000775209813,
-    0.14285714306426145,
-    -0.1666666666256715,
-    0.2,
-    -0.25,
-    0.3333333333333333,
-    -0.5,
-    1.0,
-    -7.458340731200207e-155,
+use crate::{polyval, polyval_simd, polyvalf, polyvalf_simd};
+
+// f(z) = (2 atanh(s) - 2 s) / (s z), z = s^2, s = x / (2 + x)
+// domain: x in [sqrt(1/2) - 1, sqrt(2) - 1], i.e. z in [0, 0.0295]
+const MINIMAX: [f64; 7] = [
+    0.14810362639819422,
+    0.15313179277392916,
+    0.18183570998444593,
+    0.2222219910037862,
+    0.2857142872920423,
+    0.39999999999520114,
+    0.666666666666671,
 ];
 
-const LNSQRT2: f64 = 0.34657359027997264;
-const LN2POW4TH: f64 = 0.17328679513998632;
+// ln(2) and log10(2) split so that n * *_HI is exact for every exponent n
+const LN2_HI: f64 = 0.6931471803691238;
+const LN2_LO: f64 = 1.9082149292705877e-10;
+const LOG10_2_HI: f64 = 0.30102999566395283;
+const LOG10_2_LO: f64 = 2.8363394551044964e-14;
 
-const TWOPOW4TH: f64 = 1.189207115002721;
-const TWOPOW4TH_INV: f64 = 0.8408964152537145;
+const ONE_BITS: u64 = 0x3ff0000000000000;
+const MANTISSA_MASK: u64 = 0x000fffffffffffff;
+
+// 2^54, used to renormalise subnormal inputs
+pub(crate) const SUBNORMAL_SCALE: f64 = 18_014_398_509_481_984.0;
 
 fn fake_log2(x: f64) -> i32 {
     const MASK: u64 = 0x7ff0000000000000;
@@ -41,36 +36,114 @@ fn fake_log2(x: f64) -> i32 {
 
     let exp2 = (x & MASK) >> 52;
 
-    (exp2 - 1023) as i32
+    exp2 as i32 - 1023
 }
 
-pub fn ln(x: f64) -> f64 {
-    let n = fake_log2(x);
+// Results for zero, negative, infinite and NaN inputs
+fn ln_special(x: f64) -> Option<f64> {
+    if x.is_nan() || x < 0.0 {
+        Some(f64::NAN)
+    } else if x == 0.0 {
+        Some(f64::NEG_INFINITY)
+    } else if x == f64::INFINITY {
+        Some(f64::INFINITY)
+    } else {
+        None
+    }
+}
 
-    let n = if n < 0 { n + 1 } else { n };
+// x = 2^n (1 + f) with 1 + f in [sqrt(1/2), sqrt(2)), for positive finite x.
+// Both n and f are exact.
+pub(crate) fn ln_reduce(x: f64) -> (f64, f64) {
+    // Subnormals have no implicit leading bit, so they are scaled up first
+    let (x, n_sub) = if x < f64::MIN_POSITIVE {
+        (x * SUBNORMAL_SCALE, -54)
+    } else {
+        (x, 0)
+    };
 
-    let x = x * 2f64.powi(-n);
+    let n = fake_log2(x) + n_sub;
+    let m = f64::from_bits((x.to_bits() & MANTISSA_MASK) | ONE_BITS);
 
-    let (nsq2, fsq2) = if x > 1.0 {
-        (1.0, FRAC_1_SQRT_2)
+    if m > SQRT_2 {
+        ((n + 1) as f64, 0.5 * m - 1.0)
     } else {
-        (-1.0, SQRT_2)
-    };
+        (n as f64, m - 1.0)
+    }
+}
+
+// ln(1 + f) for f in [sqrt(1/2) - 1, sqrt(2) - 1]
+fn ln1p_kernel(f: f64) -> f64 {
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let r = z * polyval(&MINIMAX, z);
+
+    // 2 atanh(s) = f - s (f - r), rearranged to keep the large terms exact
+    let hfsq = 0.5 * f * f;
+    f - (hfsq - s * (hfsq + r))
+}
+
+pub fn ln(x: f64) -> f64 {
+    if let Some(y) = ln_special(x) {
+        return y;
+    }
 
-    let x = x * fsq2;
+    let (n, f) = ln_reduce(x);
 
-    let (n2p4, f2p4) = if x > 1.0 {
-        (1.0, TWOPOW4TH_INV)
+    n.mul_add(LN2_HI, n.mul_add(LN2_LO, ln1p_kernel(f)))
+}
+
+/// Computes the base-2 logarithm, exact at the powers of two.
+pub fn log2(x: f64) -> f64 {
+    if let Some(y) = ln_special(x) {
+        return y;
+    }
+
+    let (n, f) = ln_reduce(x);
+
+    // Exact for powers of two, as f = 0
+    ln1p_kernel(f).mul_add(LOG2_E, n)
+}
+
+/// Computes the base-10 logarithm.
+pub fn log10(x: f64) -> f64 {
+    if let Some(y) = ln_special(x) {
+        return y;
+    }
+
+    let (n, f) = ln_reduce(x);
+
+    n.mul_add(LOG10_2_HI, ln1p_kernel(f).mul_add(LOG10_E, n * LOG10_2_LO))
+}
+
+/// Computes `ln(1 + x)`, accurate also for `x` close to zero.
+pub fn log1p(x: f64) -> f64 {
+    // Close to zero the kernel is used directly, so 1 + x is never rounded
+    if (FRAC_1_SQRT_2 - 1.0..SQRT_2 - 1.0).contains(&x) {
+        return ln1p_kernel(x);
+    }
+
+    let u = 1.0 + x;
+
+    if let Some(y) = ln_special(u) {
+        return y;
+    }
+
+    // ln(1 + x) = ln(u) + c / u, where c is the rounding error in u
+    let c = if x > 1.0 {
+        1.0 - (u - x)
     } else {
-        (-1.0, TWOPOW4TH)
+        x - (u - 1.0)
     };
 
-    let x = x * f2p4;
+    let (n, f) = ln_reduce(u);
+
+    n.mul_add(LN2_HI, n.mul_add(LN2_LO, ln1p_kernel(f) + c / u))
+}
 
-    polyval(&TAYLOR, x - 1.0)
-        + (n as f64) * LN_2
-        + nsq2 * LNSQRT2
-        + n2p4 * LN2POW4TH
+/// Computes the logarithm of `x` to the given `base`.
+pub fn log_base(x: f64, base: f64) -> f64 {
+    log2(x) / log2(base)
 }
 
 #[inline(always)]
@@ -87,36 +160,422 @@ where
     (exp2 - Simd::splat(1023)).cast()
 }
 
+#[inline(always)]
+fn ln_special_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    y: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = x
+        .simd_eq(Simd::splat(f64::INFINITY))
+        .select(Simd::splat(f64::INFINITY), y);
+    let y = x
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(f64::NEG_INFINITY), y);
+
+    // Negative numbers and NaN
+    (x.simd_lt(Simd::splat(0.0)) | x.is_nan()).select(Simd::splat(f64::NAN), y)
+}
+
+#[inline(always)]
+pub(crate) fn ln_reduce_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let subnormal = x.simd_lt(Simd::splat(f64::MIN_POSITIVE));
+    let x = subnormal.select(x * Simd::splat(SUBNORMAL_SCALE), x);
+    let n_sub = subnormal.select(Simd::splat(-54.0), Simd::splat(0.0));
+
+    let n = fake_log2_simd(x).cast() + n_sub;
+    let m = Simd::<f64, LANES>::from_bits(
+        (x.to_bits() & Simd::splat(MANTISSA_MASK)) | Simd::splat(ONE_BITS),
+    );
+
+    let big = m.simd_gt(Simd::splat(SQRT_2));
+
+    let n = big.select(n + Simd::splat(1.0), n);
+    let f = big.select(m * Simd::splat(0.5), m) - Simd::splat(1.0);
+
+    (n, f)
+}
+
+#[inline(always)]
+fn ln1p_kernel_simd<const LANES: usize>(f: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let s = f / (Simd::splat(2.0) + f);
+    let z = s * s;
+    let r = z * polyval_simd(&MINIMAX, z);
+
+    let hfsq = Simd::splat(0.5) * f * f;
+    f - (hfsq - s * (hfsq + r))
+}
+
 #[inline(always)]
 pub fn ln_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    let n = fake_log2_simd(x);
+    let (n, f) = ln_reduce_simd(x);
 
-    let n = n.is_negative().select(n + Simd::splat(1), n);
+    let y = n.mul_add(
+        Simd::splat(LN2_HI),
+        n.mul_add(Simd::splat(LN2_LO), ln1p_kernel_simd(f)),
+    );
 
-    let x = x * powi_simd_pos(
-        n.is_positive()
-            .cast()
-            .select(Simd::splat(0.5), Simd::splat(2.0)),
-        n.abs().cast(),
+    ln_special_simd(x, y)
+}
+
+/// The SIMD version of [`log2`].
+#[inline(always)]
+pub fn log2_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (n, f) = ln_reduce_simd(x);
+
+    let y = ln1p_kernel_simd(f).mul_add(Simd::splat(LOG2_E), n);
+
+    ln_special_simd(x, y)
+}
+
+/// The SIMD version of [`log10`].
+#[inline(always)]
+pub fn log10_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (n, f) = ln_reduce_simd(x);
+
+    let y = n.mul_add(
+        Simd::splat(LOG10_2_HI),
+        ln1p_kernel_simd(f)
+            .mul_add(Simd::splat(LOG10_E), n * Simd::splat(LOG10_2_LO)),
     );
 
-    let ssq2 = x - Simd::splat(1.0);
-    let x = x * ssq2
-        .is_sign_positive()
-        .select(Simd::splat(FRAC_1_SQRT_2), Simd::splat(SQRT_2));
+    ln_special_simd(x, y)
+}
+
+/// The SIMD version of [`log1p`].
+#[inline(always)]
+pub fn log1p_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let u = one + x;
+    let c = x.simd_gt(one).select(one - (u - x), x - (u - one));
+
+    let (n, f) = ln_reduce_simd(u);
+
+    // Close to zero the kernel is used directly, so 1 + x is never rounded
+    let small = x.simd_ge(Simd::splat(FRAC_1_SQRT_2 - 1.0))
+        & x.simd_lt(Simd::splat(SQRT_2 - 1.0));
+
+    let n = small.select(Simd::splat(0.0), n);
+    let f = small.select(x, f);
+    let c = small.select(Simd::splat(0.0), c / u);
+
+    let y = n.mul_add(
+        Simd::splat(LN2_HI),
+        n.mul_add(Simd::splat(LN2_LO), ln1p_kernel_simd(f) + c),
+    );
+
+    // The +0.0 terms above would turn -0.0 into +0.0
+    x.simd_eq(Simd::splat(0.0)).select(x, ln_special_simd(u, y))
+}
+
+/// The SIMD version of [`log_base`].
+#[inline(always)]
+pub fn log_base_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    base: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    log2_simd(x) / log2_simd(base)
+}
+
+// f(x) = (ln(x + 1) - x + x^2 / 2) / x^3
+// domain: (sqrt(1/2) - 1, sqrt(2) - 1)
+const MINIMAX_F32: [f32; 9] = [
+    7.105_198e-2,
+    -1.196_210_3e-1,
+    1.188_638_9e-1,
+    -1.235_897_3e-1,
+    1.421_344_6e-1,
+    -1.667_034_9e-1,
+    2.000_251_1e-1,
+    -2.499_997_3e-1,
+    3.333_330_8e-1,
+];
+
+// ln(2) and log10(2) split so that n * *_HI_F32 is exact for every exponent n
+const LN2_HI_F32: f32 = 0.693_359_4;
+const LN2_LO_F32: f32 = -2.121_944_4e-4;
+const LOG10_2_HI_F32: f32 = 0.300_781_25;
+const LOG10_2_LO_F32: f32 = 2.487_456_6e-4;
+
+// Bits of sqrt(1/2). Offsetting by this before extracting the exponent puts
+// the mantissa in [sqrt(1/2), sqrt(2)) instead of [1, 2).
+const FRAC_1_SQRT_2_BITS_F32: u32 = 0x3f3504f3;
+const ONE_BITS_F32: u32 = 0x3f800000;
+const MANTISSA_MASK_F32: u32 = 0x007fffff;
+
+// 2^25, used to renormalise subnormal inputs
+pub(crate) const SUBNORMAL_SCALE_F32: f32 = 33_554_432.0;
+
+// Results for zero, negative, infinite and NaN inputs
+fn lnf_special(x: f32) -> Option<f32> {
+    if x.is_nan() || x < 0.0 {
+        Some(f32::NAN)
+    } else if x == 0.0 {
+        Some(f32::NEG_INFINITY)
+    } else if x == f32::INFINITY {
+        Some(f32::INFINITY)
+    } else {
+        None
+    }
+}
+
+// x = 2^n (1 + f) with 1 + f in [sqrt(1/2), sqrt(2)), for positive finite x
+pub(crate) fn lnf_reduce(x: f32) -> (f32, f32) {
+    let (x, n_sub) = if x < f32::MIN_POSITIVE {
+        (x * SUBNORMAL_SCALE_F32, -25)
+    } else {
+        (x, 0)
+    };
 
-    let s2p4 = x - Simd::splat(1.0);
-    let x = x * s2p4
-        .is_sign_positive()
-        .select(Simd::splat(TWOPOW4TH_INV), Simd::splat(TWOPOW4TH));
+    let ix = x.to_bits() + (ONE_BITS_F32 - FRAC_1_SQRT_2_BITS_F32);
+    let n = ((ix >> 23) as i32 - 127 + n_sub) as f32;
+    let m = f32::from_bits((ix & MANTISSA_MASK_F32) + FRAC_1_SQRT_2_BITS_F32);
+
+    (n, m - 1.0)
+}
 
-    polyval_simd(&TAYLOR, x - Simd::splat(1.0))
-        + n.cast() * Simd::splat(LN_2)
-        + Simd::splat(LNSQRT2).copysign(ssq2)
-        + Simd::splat(LN2POW4TH).copysign(s2p4)
+// ln(1 + f) for f in [sqrt(1/2) - 1, sqrt(2) - 1]
+fn ln1pf_kernel(f: f32) -> f32 {
+    let f2 = f * f;
+    (f2 * f).mul_add(polyvalf(&MINIMAX_F32, f), f2.mul_add(-0.5, f))
+}
+
+pub fn lnf(x: f32) -> f32 {
+    if let Some(y) = lnf_special(x) {
+        return y;
+    }
+
+    let (n, f) = lnf_reduce(x);
+
+    n.mul_add(LN2_HI_F32, n.mul_add(LN2_LO_F32, ln1pf_kernel(f)))
+}
+
+/// The `f32` version of [`log2`].
+pub fn log2f(x: f32) -> f32 {
+    if let Some(y) = lnf_special(x) {
+        return y;
+    }
+
+    let (n, f) = lnf_reduce(x);
+
+    ln1pf_kernel(f).mul_add(std::f32::consts::LOG2_E, n)
+}
+
+/// The `f32` version of [`log10`].
+pub fn log10f(x: f32) -> f32 {
+    if let Some(y) = lnf_special(x) {
+        return y;
+    }
+
+    let (n, f) = lnf_reduce(x);
+
+    n.mul_add(
+        LOG10_2_HI_F32,
+        ln1pf_kernel(f).mul_add(std::f32::consts::LOG10_E, n * LOG10_2_LO_F32),
+    )
+}
+
+/// The `f32` version of [`log1p`].
+pub fn log1pf(x: f32) -> f32 {
+    use std::f32::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    if (FRAC_1_SQRT_2 - 1.0..SQRT_2 - 1.0).contains(&x) {
+        return ln1pf_kernel(x);
+    }
+
+    let u = 1.0 + x;
+
+    if let Some(y) = lnf_special(u) {
+        return y;
+    }
+
+    let c = if x > 1.0 {
+        1.0 - (u - x)
+    } else {
+        x - (u - 1.0)
+    };
+
+    let (n, f) = lnf_reduce(u);
+
+    n.mul_add(LN2_HI_F32, n.mul_add(LN2_LO_F32, ln1pf_kernel(f) + c / u))
+}
+
+/// The `f32` version of [`log_base`].
+pub fn log_basef(x: f32, base: f32) -> f32 {
+    log2f(x) / log2f(base)
+}
+
+#[inline(always)]
+fn lnf_special_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    y: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = x
+        .simd_eq(Simd::splat(f32::INFINITY))
+        .select(Simd::splat(f32::INFINITY), y);
+    let y = x
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(f32::NEG_INFINITY), y);
+
+    // Negative numbers and NaN
+    (x.simd_lt(Simd::splat(0.0)) | x.is_nan()).select(Simd::splat(f32::NAN), y)
+}
+
+#[inline(always)]
+pub(crate) fn lnf_reduce_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let subnormal = x.simd_lt(Simd::splat(f32::MIN_POSITIVE));
+    let x = subnormal.select(x * Simd::splat(SUBNORMAL_SCALE_F32), x);
+    let n_sub = subnormal.select(Simd::splat(-25), Simd::splat(0));
+
+    let ix = x.to_bits() + Simd::splat(ONE_BITS_F32 - FRAC_1_SQRT_2_BITS_F32);
+    let n: Simd<f32, LANES> =
+        ((ix >> Simd::splat(23)).cast::<i32>() - Simd::splat(127) + n_sub)
+            .cast();
+    let m = Simd::<f32, LANES>::from_bits(
+        (ix & Simd::splat(MANTISSA_MASK_F32))
+            + Simd::splat(FRAC_1_SQRT_2_BITS_F32),
+    );
+
+    (n, m - Simd::splat(1.0))
+}
+
+#[inline(always)]
+fn ln1pf_kernel_simd<const LANES: usize>(
+    f: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let f2 = f * f;
+    (f2 * f).mul_add(
+        polyvalf_simd(&MINIMAX_F32, f),
+        f2.mul_add(Simd::splat(-0.5), f),
+    )
+}
+
+#[inline(always)]
+pub fn lnf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (n, f) = lnf_reduce_simd(x);
+
+    let y = n.mul_add(
+        Simd::splat(LN2_HI_F32),
+        n.mul_add(Simd::splat(LN2_LO_F32), ln1pf_kernel_simd(f)),
+    );
+
+    lnf_special_simd(x, y)
+}
+
+/// The SIMD version of [`log2f`].
+#[inline(always)]
+pub fn log2f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (n, f) = lnf_reduce_simd(x);
+
+    let y =
+        ln1pf_kernel_simd(f).mul_add(Simd::splat(std::f32::consts::LOG2_E), n);
+
+    lnf_special_simd(x, y)
+}
+
+/// The SIMD version of [`log10f`].
+#[inline(always)]
+pub fn log10f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (n, f) = lnf_reduce_simd(x);
+
+    let y = n.mul_add(
+        Simd::splat(LOG10_2_HI_F32),
+        ln1pf_kernel_simd(f).mul_add(
+            Simd::splat(std::f32::consts::LOG10_E),
+            n * Simd::splat(LOG10_2_LO_F32),
+        ),
+    );
+
+    lnf_special_simd(x, y)
+}
+
+/// The SIMD version of [`log1pf`].
+#[inline(always)]
+pub fn log1pf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    let one = Simd::splat(1.0);
+
+    let u = one + x;
+    let c = x.simd_gt(one).select(one - (u - x), x - (u - one));
+
+    let (n, f) = lnf_reduce_simd(u);
+
+    let small = x.simd_ge(Simd::splat(FRAC_1_SQRT_2 - 1.0))
+        & x.simd_lt(Simd::splat(SQRT_2 - 1.0));
+
+    let n = small.select(Simd::splat(0.0), n);
+    let f = small.select(x, f);
+    let c = small.select(Simd::splat(0.0), c / u);
+
+    let y = n.mul_add(
+        Simd::splat(LN2_HI_F32),
+        n.mul_add(Simd::splat(LN2_LO_F32), ln1pf_kernel_simd(f) + c),
+    );
+
+    x.simd_eq(Simd::splat(0.0))
+        .select(x, lnf_special_simd(u, y))
+}
+
+/// The SIMD version of [`log_basef`].
+#[inline(always)]
+pub fn log_basef_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    base: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    log2f_simd(x) / log2f_simd(base)
 }
 
 #[cfg(test)]
@@ -124,7 +583,10 @@ mod tests {
     use std::simd::Simd;
 
     use crate::{
-        tests::{accuracy_test, accuracy_test_simd, speed_test_simd_iterated},
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, speed_test_simd_iterated, ulp_diff,
+        },
         *,
     };
 
@@ -149,6 +611,202 @@ mod tests {
         accuracy_test_simd(X, |x| x.ln(), |x| x.ln());
     }
 
+    #[test]
+    fn test_ln_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let x = 2f64.powf(-1020.0 + 2040.0 * (i as f64 + 0.5) / N as f64);
+            let y_simd = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(ln(x), x.ln()) <= 1, "{x}");
+            assert!(ulp_diff(y_simd.ln()[0], x.ln()) <= 1, "{x}");
+            assert!(ulp_diff(log2(x), x.log2()) <= 2, "{x}");
+            assert!(ulp_diff(y_simd.log2()[0], x.log2()) <= 2, "{x}");
+            assert!(ulp_diff(log10(x), x.log10()) <= 2, "{x}");
+            assert!(ulp_diff(y_simd.log10()[0], x.log10()) <= 2, "{x}");
+        }
+    }
+
+    fn assert_ln_all(x: f64, y: f64) {
+        let simd = Simd::<f64, 4>::splat(x);
+
+        assert_eq!(ulp_diff(ln(x), y), 0, "{x}");
+        assert_eq!(ulp_diff(simd.ln()[0], y), 0, "{x}");
+        assert_eq!(ulp_diff(log2(x), y), 0, "{x}");
+        assert_eq!(ulp_diff(simd.log2()[0], y), 0, "{x}");
+        assert_eq!(ulp_diff(log10(x), y), 0, "{x}");
+        assert_eq!(ulp_diff(simd.log10()[0], y), 0, "{x}");
+    }
+
+    #[test]
+    fn test_ln_zero() {
+        assert_ln_all(0.0, f64::NEG_INFINITY);
+        assert_ln_all(-0.0, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ln_negative() {
+        for x in [-1.0, -0.5, -f64::MIN_POSITIVE, -f64::from_bits(1), -1e300] {
+            assert_ln_all(x, f64::NAN);
+        }
+
+        assert_ln_all(f64::NEG_INFINITY, f64::NAN);
+    }
+
+    #[test]
+    fn test_ln_inf_nan() {
+        assert_ln_all(f64::INFINITY, f64::INFINITY);
+        assert_ln_all(f64::NAN, f64::NAN);
+        assert_ln_all(-f64::NAN, f64::NAN);
+    }
+
+    #[test]
+    fn test_ln_subnormal() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let bits =
+                1 + (i as u64) * (f64::MIN_POSITIVE.to_bits() / N as u64);
+            let x = f64::from_bits(bits);
+            let simd = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(ln(x), x.ln()) <= 1, "{x}");
+            assert!(ulp_diff(simd.ln()[0], x.ln()) <= 1, "{x}");
+            assert!(ulp_diff(log2(x), x.log2()) <= 2, "{x}");
+            assert!(ulp_diff(simd.log2()[0], x.log2()) <= 2, "{x}");
+            assert!(ulp_diff(log10(x), x.log10()) <= 2, "{x}");
+            assert!(ulp_diff(simd.log10()[0], x.log10()) <= 2, "{x}");
+        }
+
+        let x = f64::from_bits(1);
+        assert_eq!(ln(x), x.ln());
+        assert_eq!(Simd::<f64, 4>::splat(x).ln()[0], x.ln());
+    }
+
+    #[test]
+    fn test_log1p_special_values() {
+        let x = [
+            -1.0,
+            -2.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -0.0,
+            0.0,
+            -1e-320,
+        ];
+        let y = [
+            f64::NEG_INFINITY,
+            f64::NAN,
+            f64::INFINITY,
+            f64::NAN,
+            f64::NAN,
+            -0.0,
+            0.0,
+            -1e-320,
+        ];
+
+        let y_simd = Simd::from(x).ln_1p();
+        let yf_simd = Simd::from(x.map(|x| x as f32)).ln_1p();
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(log1p(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y_simd[i], y[i]), 0, "{}", x[i]);
+
+            if y[i] == 0.0 {
+                assert_eq!(log1p(x[i]).to_bits(), y[i].to_bits());
+                assert_eq!(y_simd[i].to_bits(), y[i].to_bits());
+            }
+
+            let yf = log1pf(x[i] as f32);
+
+            assert_eq!(ulp_diff(yf as f64, y[i] as f32 as f64), 0, "{}", x[i]);
+            assert_eq!(yf_simd[i].to_bits(), yf.to_bits(), "{}", x[i]);
+        }
+    }
+
+    #[test]
+    fn test_log2_exact() {
+        for n in -1074..=1023 {
+            let x = if n < -1022 {
+                f64::from_bits(1 << (n + 1074))
+            } else {
+                f64::from_bits(((n + 1023) as u64) << 52)
+            };
+
+            assert_eq!(log2(x), n as f64);
+            assert_eq!(Simd::<f64, 4>::splat(x).log2()[0], n as f64);
+        }
+    }
+
+    #[test]
+    fn test_log10() {
+        accuracy_test(&X, |x| x.log10(), log10);
+    }
+
+    #[test]
+    fn test_log1p() {
+        accuracy_test(&X, |x| x.ln_1p(), log1p);
+    }
+
+    #[test]
+    fn test_log1p_ulp() {
+        const N: usize = 100000;
+
+        for (lo, hi) in [(-1e-300, 1e-300), (-1e-10, 1e-10), (-0.99, 3.0)] {
+            for i in 0..N {
+                let x = lo + (hi - lo) * (i as f64 + 0.5) / N as f64;
+                let y_std = x.ln_1p();
+
+                assert!(ulp_diff(log1p(x), y_std) <= 1, "{x}");
+                assert!(
+                    ulp_diff(Simd::<f64, 4>::splat(x).ln_1p()[0], y_std) <= 1,
+                    "{x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_base() {
+        let base = Simd::splat(3.0);
+
+        accuracy_test(&X, |x| x.log(3.0), |x| log_base(x, 3.0));
+        accuracy_test_simd(X, |x| x.log(3.0), |x| x.log(base));
+    }
+
+    const X_F32: [f32; 8] = [
+        5.1553884,
+        1963.5613,
+        18138.074,
+        0.005506141,
+        0.8485974,
+        3236.719,
+        0.58952355,
+        16.565388,
+    ];
+
+    #[test]
+    fn test_lnf() {
+        accuracy_testf(&X_F32, |x| x.ln(), lnf);
+    }
+
+    #[test]
+    fn test_lnf_simd() {
+        accuracy_test_simdf(X_F32, |x| x.ln(), |x| x.ln());
+    }
+
+    #[test]
+    fn test_logf() {
+        accuracy_testf(&X_F32, |x| x.log2(), log2f);
+        accuracy_testf(&X_F32, |x| x.log10(), log10f);
+        accuracy_testf(&X_F32, |x| x.ln_1p(), log1pf);
+        accuracy_test_simdf(X_F32, |x| x.log2(), |x| x.log2());
+        accuracy_test_simdf(X_F32, |x| x.log10(), |x| x.log10());
+        accuracy_test_simdf(X_F32, |x| x.ln_1p(), |x| x.ln_1p());
+    }
+
     #[test]
     fn test_ln_simd_speed() {
         const ITERS: usize = 1000000;
@@ -0,0 +1,1303 @@
+use std::{
+    f64::consts::LN_2,
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    exp, exp_simd, expf, expf_simd, ln, ln_simd, lnf, lnf_simd, polyval,
+    polyval_simd, polyvalf, polyvalf_simd,
+};
+
+// f(z) = I0(x), z = x^2
+// domain: 0 <= x <= 8
+const I0_MINIMAX: [f64; 17] = [
+    8.487698345602824e-37,
+    4.601357767863732e-34,
+    5.0327117729737235e-31,
+    3.829411247002327e-28,
+    2.5987841051325663e-25,
+    1.4962822964042478e-22,
+    7.242279005317181e-20,
+    2.896902788810415e-17,
+    9.385967122158306e-15,
+    2.4028075474081917e-12,
+    4.709502797312111e-10,
+    6.781684027758202e-8,
+    6.781684027778812e-6,
+    0.00043402777777777445,
+    0.015625000000000007,
+    0.25,
+    1.0,
+];
+
+// f(z) = I1(x) / x, z = x^2
+// domain: 0 <= x <= 8
+const I1_MINIMAX: [f64; 16] = [
+    2.716505589516919e-35,
+    1.3808181584914298e-32,
+    1.408926251589232e-29,
+    9.956969230500738e-27,
+    6.2370206832638684e-24,
+    3.2918259018287706e-21,
+    1.448455537254395e-18,
+    5.214425120919993e-16,
+    1.5017547367976848e-13,
+    3.3639305668982475e-11,
+    5.651403356704746e-9,
+    6.781684027764357e-7,
+    5.42534722222271e-5,
+    0.0026041666666666574,
+    0.06250000000000001,
+    0.5,
+];
+
+// f(z) = K0(x) + ln(x / 2) I0(x), z = x^2
+// domain: 0 <= x <= 1.5
+const K0_MINIMAX: [f64; 9] = [
+    2.0762739810042272e-14,
+    4.840354488304191e-12,
+    8.819947926911272e-10,
+    1.1570350080283141e-7,
+    1.021401414267146e-5,
+    0.000545189960253919,
+    0.014418505235914193,
+    0.10569608377461673,
+    -0.5772156649015329,
+];
+
+// f(z) = x (K1(x) - ln(x / 2) I1(x)), z = x^2
+// domain: 0 <= x <= 1.5
+const K1_MINIMAX: [f64; 10] = [
+    -1.1832777999384954e-15,
+    -3.119028541347273e-13,
+    -6.54024579651757e-11,
+    -1.0112908619636445e-8,
+    -1.0892182546444839e-6,
+    -7.493042905942012e-5,
+    -0.00283711198376353,
+    -0.04204902094365417,
+    0.038607832450766426,
+    1.0,
+];
+
+// f(u) = sqrt(x) exp(-x) I0(x), u = 16 / x - 1
+// domain: x >= 8
+const I0_ASYMPTOTIC: [f64; 26] = [
+    2.0820835852793106e-11,
+    -5.905024573995148e-11,
+    -1.5066113686101474e-10,
+    4.4789181847911815e-10,
+    5.122212147228388e-10,
+    -1.5930354799216344e-9,
+    -1.1448789558755036e-9,
+    3.5985362919394714e-9,
+    2.1053080134403423e-9,
+    -5.91556056510856e-9,
+    -3.905230623712341e-9,
+    7.406235049001522e-9,
+    7.737147033780934e-9,
+    -5.394456734259438e-9,
+    -1.346629294837396e-8,
+    -6.750552282740284e-9,
+    7.566352487698237e-9,
+    2.15488936175863e-8,
+    3.932437699533459e-8,
+    8.872214307451732e-8,
+    2.9735996224521226e-7,
+    1.4838475687894938e-6,
+    1.1143033787545386e-5,
+    0.00013621607439186705,
+    0.0033605519836678944,
+    0.4021765094450081,
+];
+
+// f(u) = sqrt(x) exp(-x) I1(x), u = 16 / x - 1
+// domain: x >= 8
+const I1_ASYMPTOTIC: [f64; 26] = [
+    -1.9677083272584384e-11,
+    6.135932778393812e-11,
+    1.417650842869536e-10,
+    -4.6561597954216946e-10,
+    -4.798751536934023e-10,
+    1.657764699456319e-9,
+    1.0715528639398075e-9,
+    -3.75344338735913e-9,
+    -1.992076551203188e-9,
+    6.202984113000443e-9,
+    3.803814960536081e-9,
+    -7.863004697555056e-9,
+    -7.816968266767898e-9,
+    5.963645798166707e-9,
+    1.408852075202545e-8,
+    6.737493433374833e-9,
+    -8.43880090582137e-9,
+    -2.337446787242448e-8,
+    -4.312778920167609e-8,
+    -1.0001282610189342e-7,
+    -3.4838567401229716e-7,
+    -1.8349101357991557e-6,
+    -1.5038547197358572e-5,
+    -0.00021923576383382282,
+    -0.009749577193235768,
+    0.38939845902587233,
+];
+
+// f(u) = sqrt(x) exp(x) K0(x), u = 3 / x - 1
+// domain: x >= 1.5
+const K0_ASYMPTOTIC: [f64; 28] = [
+    -3.9418891231648003e-10,
+    6.07009720758123e-10,
+    1.8171834874549238e-9,
+    -2.7744825326689206e-9,
+    -4.3564880275590874e-9,
+    6.668455310977711e-9,
+    5.5784790934637115e-9,
+    -8.427723308521632e-9,
+    -6.290580187834975e-9,
+    9.863490816277692e-9,
+    8.433192533274139e-11,
+    1.0697757540477278e-9,
+    -1.322323504207679e-8,
+    2.5500117864878175e-8,
+    -4.69363888088678e-8,
+    9.825962812499343e-8,
+    -2.1343496883169468e-7,
+    4.777474657347355e-7,
+    -1.115919518272044e-6,
+    2.7412303878426393e-6,
+    -7.1486661691903015e-6,
+    2.005451817771688e-5,
+    -6.167417798803907e-5,
+    0.0002139406781359939,
+    -0.0008773219933015101,
+    0.004642608579300114,
+    -0.03892810650052496,
+    1.2085585393824039,
+];
+
+// f(u) = sqrt(x) exp(x) K1(x), u = 3 / x - 1
+// domain: x >= 1.5
+const K1_ASYMPTOTIC: [f64; 28] = [
+    4.2822534212684183e-10,
+    -6.60560272137019e-10,
+    -1.970437442151348e-9,
+    3.0131384464772716e-9,
+    4.726460180052965e-9,
+    -7.247104673522837e-9,
+    -6.035282586056454e-9,
+    9.128123284405007e-9,
+    6.860229486488099e-9,
+    -1.078991317944568e-8,
+    8.933182178087702e-11,
+    -1.5285404946952892e-9,
+    1.5134911412449394e-8,
+    -2.9405024716244273e-8,
+    5.485771778670375e-8,
+    -1.1579935973955478e-7,
+    2.5386843230172416e-7,
+    -5.748437298325567e-7,
+    1.3614227862168212e-6,
+    -3.4013335458504685e-6,
+    9.060897251865321e-6,
+    -2.6134542655250922e-5,
+    8.348625004306203e-5,
+    -0.0003062266891241731,
+    0.0013759084082096268,
+    -0.008734853795315904,
+    0.13315330036563777,
+    1.3970089271126294,
+];
+
+// Above these the asymptotic polynomials are used. K has to switch early, as
+// the logarithmic term cancels against the polynomial for larger x, and the
+// polynomial itself changes sign just below 2.
+const I_BIG: f64 = 8.0;
+const K_BIG: f64 = 1.5;
+
+// ln(x / 2), without losing the last bit of subnormal x to the halving
+#[inline(always)]
+fn ln_half(x: f64) -> f64 {
+    if x < 1.0 {
+        ln(x) - LN_2
+    } else {
+        ln(0.5 * x)
+    }
+}
+
+// I0(x) for 0 <= x < I_BIG
+#[inline(always)]
+fn i0_small(x: f64) -> f64 {
+    polyval(&I0_MINIMAX, x * x)
+}
+
+// I1(x) for 0 <= x < I_BIG
+#[inline(always)]
+fn i1_small(x: f64) -> f64 {
+    x * polyval(&I1_MINIMAX, x * x)
+}
+
+// K0(x) for 0 < x < K_BIG
+#[inline(always)]
+fn k0_small(x: f64) -> f64 {
+    (-ln_half(x)).mul_add(i0_small(x), polyval(&K0_MINIMAX, x * x))
+}
+
+// K1(x) for 0 < x < K_BIG
+#[inline(always)]
+fn k1_small(x: f64) -> f64 {
+    ln_half(x).mul_add(i1_small(x), polyval(&K1_MINIMAX, x * x) / x)
+}
+
+// exp(-x) I0(x) and exp(-x) I1(x) for x >= I_BIG
+#[inline(always)]
+fn i_asymptotic(cs: &[f64; 26], x: f64) -> f64 {
+    polyval(cs, 2.0 * I_BIG / x - 1.0) / x.sqrt()
+}
+
+// exp(x) K0(x) and exp(x) K1(x) for x >= K_BIG
+#[inline(always)]
+fn k_asymptotic(cs: &[f64; 28], x: f64) -> f64 {
+    polyval(cs, 2.0 * K_BIG / x - 1.0) / x.sqrt()
+}
+
+// exp(x) y, split in two halves so that it does not overflow before y brings
+// it back down
+#[inline(always)]
+fn exp_scale(x: f64, y: f64) -> f64 {
+    let e = exp(0.5 * x);
+    (e * y) * e
+}
+
+/// Computes the modified Bessel function of the first kind of order zero.
+/// Overflows for `|x|` above about 713.98.
+pub fn i0(x: f64) -> f64 {
+    let x = x.abs();
+
+    if x < I_BIG {
+        i0_small(x)
+    } else if x.is_infinite() {
+        f64::INFINITY
+    } else {
+        exp_scale(x, i_asymptotic(&I0_ASYMPTOTIC, x))
+    }
+}
+
+/// Computes the modified Bessel function of the first kind of order one.
+/// Overflows for `|x|` above about 713.98.
+pub fn i1(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if a < I_BIG {
+        i1_small(a)
+    } else if a.is_infinite() {
+        f64::INFINITY
+    } else {
+        exp_scale(a, i_asymptotic(&I1_ASYMPTOTIC, a))
+    };
+
+    y.copysign(x)
+}
+
+/// Computes the modified Bessel function of the second kind of order zero.
+/// Returns infinity at zero and NaN for negative `x`.
+pub fn k0(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x < K_BIG {
+        k0_small(x)
+    } else {
+        exp(-x) * k_asymptotic(&K0_ASYMPTOTIC, x)
+    }
+}
+
+/// Computes the modified Bessel function of the second kind of order one.
+/// Returns infinity at zero and NaN for negative `x`.
+pub fn k1(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x < K_BIG {
+        k1_small(x)
+    } else {
+        exp(-x) * k_asymptotic(&K1_ASYMPTOTIC, x)
+    }
+}
+
+/// Computes `exp(-|x|) i0(x)`, which stays finite for all `x`.
+pub fn i0e(x: f64) -> f64 {
+    let x = x.abs();
+
+    if x < I_BIG {
+        exp(-x) * i0_small(x)
+    } else {
+        i_asymptotic(&I0_ASYMPTOTIC, x)
+    }
+}
+
+/// Computes `exp(-|x|) i1(x)`, which stays finite for all `x`.
+pub fn i1e(x: f64) -> f64 {
+    let a = x.abs();
+
+    let y = if a < I_BIG {
+        exp(-a) * i1_small(a)
+    } else {
+        i_asymptotic(&I1_ASYMPTOTIC, a)
+    };
+
+    y.copysign(x)
+}
+
+/// Computes `exp(x) k0(x)`, which does not underflow for large `x`.
+pub fn k0e(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x < K_BIG {
+        exp(x) * k0_small(x)
+    } else {
+        k_asymptotic(&K0_ASYMPTOTIC, x)
+    }
+}
+
+/// Computes `exp(x) k1(x)`, which does not underflow for large `x`.
+pub fn k1e(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x < K_BIG {
+        exp(x) * k1_small(x)
+    } else {
+        k_asymptotic(&K1_ASYMPTOTIC, x)
+    }
+}
+
+#[inline(always)]
+fn ln_half_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let small = x.simd_lt(Simd::splat(1.0));
+
+    ln_simd(small.select(x, Simd::splat(0.5) * x))
+        - small.select(Simd::splat(LN_2), Simd::splat(0.0))
+}
+
+#[inline(always)]
+fn i0_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    polyval_simd(&I0_MINIMAX, x * x)
+}
+
+#[inline(always)]
+fn i1_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    x * polyval_simd(&I1_MINIMAX, x * x)
+}
+
+#[inline(always)]
+fn k0_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (-ln_half_simd(x))
+        .mul_add(i0_small_simd(x), polyval_simd(&K0_MINIMAX, x * x))
+}
+
+#[inline(always)]
+fn k1_small_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    ln_half_simd(x)
+        .mul_add(i1_small_simd(x), polyval_simd(&K1_MINIMAX, x * x) / x)
+}
+
+#[inline(always)]
+fn i_asymptotic_simd<const LANES: usize>(
+    cs: &[f64; 26],
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u = Simd::splat(2.0 * I_BIG) / x - Simd::splat(1.0);
+    polyval_simd(cs, u) / x.sqrt()
+}
+
+#[inline(always)]
+fn k_asymptotic_simd<const LANES: usize>(
+    cs: &[f64; 28],
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u = Simd::splat(2.0 * K_BIG) / x - Simd::splat(1.0);
+    polyval_simd(cs, u) / x.sqrt()
+}
+
+#[inline(always)]
+fn exp_scale_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    y: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let e = exp_simd(Simd::splat(0.5) * x);
+    (e * y) * e
+}
+
+// I0 and I1 for lanes with x >= 0, with their scaled variants if `scaled`
+#[inline(always)]
+fn i_pos_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    small: impl Fn(Simd<f64, LANES>) -> Simd<f64, LANES>,
+    cs: &[f64; 26],
+    scaled: bool,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let big = x.simd_ge(Simd::splat(I_BIG));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = small(x);
+
+        if scaled {
+            y *= exp_simd(-x);
+        }
+    }
+
+    if big.any() {
+        let y_big = i_asymptotic_simd(cs, x);
+        let y_big = if scaled {
+            y_big
+        } else {
+            exp_scale_simd(x, y_big)
+        };
+
+        y = big.select(y_big, y);
+    }
+
+    if scaled {
+        y
+    } else {
+        x.is_infinite().select(Simd::splat(f64::INFINITY), y)
+    }
+}
+
+// K0 and K1, with their scaled variants if `scaled`
+#[inline(always)]
+fn k_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    small: impl Fn(Simd<f64, LANES>) -> Simd<f64, LANES>,
+    cs: &[f64; 28],
+    scaled: bool,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(K_BIG));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = small(x);
+
+        if scaled {
+            y *= exp_simd(x);
+        }
+    }
+
+    if big.any() {
+        let y_big = k_asymptotic_simd(cs, x);
+        let y_big = if scaled { y_big } else { exp_simd(-x) * y_big };
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.simd_eq(zero).select(Simd::splat(f64::INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f64::NAN), y)
+}
+
+/// The SIMD version of [`i0`].
+#[inline(always)]
+pub fn i0_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_pos_simd(x.abs(), i0_small_simd, &I0_ASYMPTOTIC, false)
+}
+
+/// The SIMD version of [`i1`].
+#[inline(always)]
+pub fn i1_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_pos_simd(x.abs(), i1_small_simd, &I1_ASYMPTOTIC, false).copysign(x)
+}
+
+/// The SIMD version of [`k0`].
+#[inline(always)]
+pub fn k0_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    k_simd(x, k0_small_simd, &K0_ASYMPTOTIC, false)
+}
+
+/// The SIMD version of [`k1`].
+#[inline(always)]
+pub fn k1_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    k_simd(x, k1_small_simd, &K1_ASYMPTOTIC, false)
+}
+
+/// The SIMD version of [`i0e`].
+#[inline(always)]
+pub fn i0e_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_pos_simd(x.abs(), i0_small_simd, &I0_ASYMPTOTIC, true)
+}
+
+/// The SIMD version of [`i1e`].
+#[inline(always)]
+pub fn i1e_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_pos_simd(x.abs(), i1_small_simd, &I1_ASYMPTOTIC, true).copysign(x)
+}
+
+/// The SIMD version of [`k0e`].
+#[inline(always)]
+pub fn k0e_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    k_simd(x, k0_small_simd, &K0_ASYMPTOTIC, true)
+}
+
+/// The SIMD version of [`k1e`].
+#[inline(always)]
+pub fn k1e_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    k_simd(x, k1_small_simd, &K1_ASYMPTOTIC, true)
+}
+
+// f(z) = I0(x), z = x^2
+// domain: 0 <= x <= 8
+const I0_MINIMAX_F32: [f32; 11] = [
+    1.481_667_9e-19,
+    1.616_619_5e-17,
+    1.054_368_6e-14,
+    2.339_176_8e-12,
+    4.731_728_3e-10,
+    6.776_729_6e-8,
+    6.782_37e-6,
+    4.340_222_5e-4,
+    1.562_502_2e-2,
+    2.499_999_7e-1,
+    1.0,
+];
+
+// f(z) = I1(x) / x, z = x^2
+// domain: 0 <= x <= 8
+const I1_MINIMAX_F32: [f32; 11] = [
+    6.358_902_5e-21,
+    9.338_66e-19,
+    5.678_116_6e-16,
+    1.476_321_5e-13,
+    3.372_802_4e-11,
+    5.649_427_6e-9,
+    6.781_957e-7,
+    5.425_325e-5,
+    2.604_167_7e-3,
+    6.25e-2,
+    5.0e-1,
+];
+
+// f(z) = K0(x) + ln(x / 2) I0(x), z = x^2
+// domain: 0 <= x <= 1
+const K0_MINIMAX_F32: [f32; 5] = [
+    1.050_688_3e-5,
+    5.449_326_7e-4,
+    1.441_859_7e-2,
+    1.056_960_75e-1,
+    -5.772_157e-1,
+];
+
+// f(z) = x (K1(x) - ln(x / 2) I1(x)), z = x^2
+// domain: 0 <= x <= 1
+const K1_MINIMAX_F32: [f32; 5] = [
+    -7.769_492e-5,
+    -2.834_681_4e-3,
+    -4.204_989_2e-2,
+    3.860_794e-2,
+    1.0,
+];
+
+// f(u) = sqrt(x) exp(-x) I0(x), u = 16 / x - 1
+// domain: x >= 8
+const I0_ASYMPTOTIC_F32: [f32; 7] = [
+    1.082_989_84e-7,
+    3.631_710_4e-7,
+    1.476_792_1e-6,
+    1.111_152_5e-5,
+    1.362_168_4e-4,
+    3.360_555_7e-3,
+    4.021_765e-1,
+];
+
+// f(u) = sqrt(x) exp(-x) I1(x), u = 16 / x - 1
+// domain: x >= 8
+const I1_ASYMPTOTIC_F32: [f32; 7] = [
+    -1.221_249_9e-7,
+    -4.215_554_3e-7,
+    -1.826_714_5e-6,
+    -1.500_332_3e-5,
+    -2.192_366_7e-4,
+    -9.749_581e-3,
+    3.893_984_6e-1,
+];
+
+// f(u) = sqrt(x) exp(x) K0(x), u = 2 / x - 1
+// domain: x >= 1
+const K0_ASYMPTOTIC_F32: [f32; 11] = [
+    7.985_118e-6,
+    -1.529_375e-5,
+    8.496_127_5e-6,
+    -2.150_399_3e-5,
+    7.879_363e-5,
+    -2.021_766e-4,
+    5.687_345e-4,
+    -1.907_490_3e-3,
+    8.067_412e-3,
+    -5.227_906e-2,
+    1.190_157_2,
+];
+
+// f(u) = sqrt(x) exp(x) K1(x), u = 2 / x - 1
+// domain: x >= 1
+const K1_ASYMPTOTIC_F32: [f32; 12] = [
+    5.639_76e-6,
+    -1.050_719_4e-5,
+    3.347_210_2e-6,
+    -9.219_723e-6,
+    4.437_738_2e-5,
+    -1.073_206_4e-4,
+    2.753_345_5e-4,
+    -8.431_496_7e-4,
+    3.112_068_6e-3,
+    -1.585_296_4e-2,
+    1.879_789_1e-1,
+    1.461_557,
+];
+
+// K switches earlier than K_BIG, as the cancellation in the logarithmic form
+// already costs a few ulps of f32 between 1 and 1.5
+const I_BIG_F32: f32 = 8.0;
+const K_BIG_F32: f32 = 1.0;
+
+#[inline(always)]
+fn ln_halff(x: f32) -> f32 {
+    use std::f32::consts::LN_2;
+
+    if x < 1.0 {
+        lnf(x) - LN_2
+    } else {
+        lnf(0.5 * x)
+    }
+}
+
+#[inline(always)]
+fn i0f_small(x: f32) -> f32 {
+    polyvalf(&I0_MINIMAX_F32, x * x)
+}
+
+#[inline(always)]
+fn i1f_small(x: f32) -> f32 {
+    x * polyvalf(&I1_MINIMAX_F32, x * x)
+}
+
+#[inline(always)]
+fn k0f_small(x: f32) -> f32 {
+    (-ln_halff(x)).mul_add(i0f_small(x), polyvalf(&K0_MINIMAX_F32, x * x))
+}
+
+#[inline(always)]
+fn k1f_small(x: f32) -> f32 {
+    ln_halff(x).mul_add(i1f_small(x), polyvalf(&K1_MINIMAX_F32, x * x) / x)
+}
+
+#[inline(always)]
+fn i_asymptoticf(cs: &[f32; 7], x: f32) -> f32 {
+    polyvalf(cs, 2.0 * I_BIG_F32 / x - 1.0) / x.sqrt()
+}
+
+#[inline(always)]
+fn k_asymptoticf<const N: usize>(cs: &[f32; N], x: f32) -> f32 {
+    polyvalf(cs, 2.0 * K_BIG_F32 / x - 1.0) / x.sqrt()
+}
+
+#[inline(always)]
+fn exp_scalef(x: f32, y: f32) -> f32 {
+    let e = expf(0.5 * x);
+    (e * y) * e
+}
+
+/// The `f32` version of [`i0`].
+pub fn i0f(x: f32) -> f32 {
+    let x = x.abs();
+
+    if x < I_BIG_F32 {
+        i0f_small(x)
+    } else if x.is_infinite() {
+        f32::INFINITY
+    } else {
+        exp_scalef(x, i_asymptoticf(&I0_ASYMPTOTIC_F32, x))
+    }
+}
+
+/// The `f32` version of [`i1`].
+pub fn i1f(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if a < I_BIG_F32 {
+        i1f_small(a)
+    } else if a.is_infinite() {
+        f32::INFINITY
+    } else {
+        exp_scalef(a, i_asymptoticf(&I1_ASYMPTOTIC_F32, a))
+    };
+
+    y.copysign(x)
+}
+
+/// The `f32` version of [`k0`].
+pub fn k0f(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::INFINITY
+    } else if x < K_BIG_F32 {
+        k0f_small(x)
+    } else {
+        expf(-x) * k_asymptoticf(&K0_ASYMPTOTIC_F32, x)
+    }
+}
+
+/// The `f32` version of [`k1`].
+pub fn k1f(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::INFINITY
+    } else if x < K_BIG_F32 {
+        k1f_small(x)
+    } else {
+        expf(-x) * k_asymptoticf(&K1_ASYMPTOTIC_F32, x)
+    }
+}
+
+/// The `f32` version of [`i0e`].
+pub fn i0ef(x: f32) -> f32 {
+    let x = x.abs();
+
+    if x < I_BIG_F32 {
+        expf(-x) * i0f_small(x)
+    } else {
+        i_asymptoticf(&I0_ASYMPTOTIC_F32, x)
+    }
+}
+
+/// The `f32` version of [`i1e`].
+pub fn i1ef(x: f32) -> f32 {
+    let a = x.abs();
+
+    let y = if a < I_BIG_F32 {
+        expf(-a) * i1f_small(a)
+    } else {
+        i_asymptoticf(&I1_ASYMPTOTIC_F32, a)
+    };
+
+    y.copysign(x)
+}
+
+/// The `f32` version of [`k0e`].
+pub fn k0ef(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::INFINITY
+    } else if x < K_BIG_F32 {
+        expf(x) * k0f_small(x)
+    } else {
+        k_asymptoticf(&K0_ASYMPTOTIC_F32, x)
+    }
+}
+
+/// The `f32` version of [`k1e`].
+pub fn k1ef(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::INFINITY
+    } else if x < K_BIG_F32 {
+        expf(x) * k1f_small(x)
+    } else {
+        k_asymptoticf(&K1_ASYMPTOTIC_F32, x)
+    }
+}
+
+#[inline(always)]
+fn ln_halff_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::LN_2;
+
+    let small = x.simd_lt(Simd::splat(1.0));
+
+    lnf_simd(small.select(x, Simd::splat(0.5) * x))
+        - small.select(Simd::splat(LN_2), Simd::splat(0.0))
+}
+
+#[inline(always)]
+fn i0f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    polyvalf_simd(&I0_MINIMAX_F32, x * x)
+}
+
+#[inline(always)]
+fn i1f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    x * polyvalf_simd(&I1_MINIMAX_F32, x * x)
+}
+
+#[inline(always)]
+fn k0f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (-ln_halff_simd(x))
+        .mul_add(i0f_small_simd(x), polyvalf_simd(&K0_MINIMAX_F32, x * x))
+}
+
+#[inline(always)]
+fn k1f_small_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    ln_halff_simd(x)
+        .mul_add(i1f_small_simd(x), polyvalf_simd(&K1_MINIMAX_F32, x * x) / x)
+}
+
+#[inline(always)]
+fn i_asymptoticf_simd<const LANES: usize>(
+    cs: &[f32; 7],
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u = Simd::splat(2.0 * I_BIG_F32) / x - Simd::splat(1.0);
+    polyvalf_simd(cs, u) / x.sqrt()
+}
+
+#[inline(always)]
+fn k_asymptoticf_simd<const N: usize, const LANES: usize>(
+    cs: &[f32; N],
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u = Simd::splat(2.0 * K_BIG_F32) / x - Simd::splat(1.0);
+    polyvalf_simd(cs, u) / x.sqrt()
+}
+
+#[inline(always)]
+fn exp_scalef_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    y: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let e = expf_simd(Simd::splat(0.5) * x);
+    (e * y) * e
+}
+
+#[inline(always)]
+fn i_posf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    small: impl Fn(Simd<f32, LANES>) -> Simd<f32, LANES>,
+    cs: &[f32; 7],
+    scaled: bool,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let big = x.simd_ge(Simd::splat(I_BIG_F32));
+
+    let mut y = Simd::splat(0.0);
+
+    if !big.all() {
+        y = small(x);
+
+        if scaled {
+            y *= expf_simd(-x);
+        }
+    }
+
+    if big.any() {
+        let y_big = i_asymptoticf_simd(cs, x);
+        let y_big = if scaled {
+            y_big
+        } else {
+            exp_scalef_simd(x, y_big)
+        };
+
+        y = big.select(y_big, y);
+    }
+
+    if scaled {
+        y
+    } else {
+        x.is_infinite().select(Simd::splat(f32::INFINITY), y)
+    }
+}
+
+#[inline(always)]
+fn kf_simd<const N: usize, const LANES: usize>(
+    x: Simd<f32, LANES>,
+    small: impl Fn(Simd<f32, LANES>) -> Simd<f32, LANES>,
+    cs: &[f32; N],
+    scaled: bool,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let big = x.simd_ge(Simd::splat(K_BIG_F32));
+
+    let mut y = zero;
+
+    if !big.all() {
+        y = small(x);
+
+        if scaled {
+            y *= expf_simd(x);
+        }
+    }
+
+    if big.any() {
+        let y_big = k_asymptoticf_simd(cs, x);
+        let y_big = if scaled { y_big } else { expf_simd(-x) * y_big };
+
+        y = big.select(y_big, y);
+    }
+
+    let y = x.simd_eq(zero).select(Simd::splat(f32::INFINITY), y);
+
+    x.simd_lt(zero).select(Simd::splat(f32::NAN), y)
+}
+
+/// The SIMD version of [`i0f`].
+#[inline(always)]
+pub fn i0f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_posf_simd(x.abs(), i0f_small_simd, &I0_ASYMPTOTIC_F32, false)
+}
+
+/// The SIMD version of [`i1f`].
+#[inline(always)]
+pub fn i1f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_posf_simd(x.abs(), i1f_small_simd, &I1_ASYMPTOTIC_F32, false).copysign(x)
+}
+
+/// The SIMD version of [`k0f`].
+#[inline(always)]
+pub fn k0f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    kf_simd(x, k0f_small_simd, &K0_ASYMPTOTIC_F32, false)
+}
+
+/// The SIMD version of [`k1f`].
+#[inline(always)]
+pub fn k1f_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    kf_simd(x, k1f_small_simd, &K1_ASYMPTOTIC_F32, false)
+}
+
+/// The SIMD version of [`i0ef`].
+#[inline(always)]
+pub fn i0ef_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_posf_simd(x.abs(), i0f_small_simd, &I0_ASYMPTOTIC_F32, true)
+}
+
+/// The SIMD version of [`i1ef`].
+#[inline(always)]
+pub fn i1ef_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    i_posf_simd(x.abs(), i1f_small_simd, &I1_ASYMPTOTIC_F32, true).copysign(x)
+}
+
+/// The SIMD version of [`k0ef`].
+#[inline(always)]
+pub fn k0ef_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    kf_simd(x, k0f_small_simd, &K0_ASYMPTOTIC_F32, true)
+}
+
+/// The SIMD version of [`k1ef`].
+#[inline(always)]
+pub fn k1ef_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    kf_simd(x, k1f_small_simd, &K1_ASYMPTOTIC_F32, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{tests::ulp_diff, *};
+
+    // Distance in f32 ulps from the f64 result rounded to f32
+    fn ulp_difff(y: f32, y_ref: f64) -> u32 {
+        y.to_bits().abs_diff((y_ref as f32).to_bits())
+    }
+
+    #[test]
+    fn test_modified_bessel() {
+        // (x, i0(x), i1(x), k0(x), k1(x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (1e-300, 1.0, 5e-301, 690.8914594138721, 9.999999999999999e299),
+            (1e-5, 1.000000000025, 5.0000000000625004e-6, 11.628856980944363, 99999.99993935571),
+            (0.5, 1.0634833707413236, 0.2578943053908963, 0.9244190712276659, 1.656441120003301),
+            (1.0, 1.2660658777520084, 0.565159103992485, 0.42102443824070834, 0.6019072301972346),
+            (1.99, 2.2637529399411482, 1.5758562935167124, 0.1153017675517768, 0.14171756162240132),
+            (2.0, 2.2795853023360673, 1.590636854637329, 0.11389387274953344, 0.13986588181652243),
+            (5.0, 27.239871823604446, 24.335642142450528, 0.0036910983340425942, 0.004044613445452165),
+            (7.99, 423.5842036746737, 396.1152262074013, 0.00014803272177026783, 0.0001570370894612197),
+            (8.0, 427.5641157218048, 399.8731367825601, 0.0001464707052228154, 0.00015536921180500115),
+            (20.0, 43558282.559553534, 42454973.38512777, 5.741237815336525e-10, 5.883057969557038e-10),
+            (100.0, 1.0737517071310738e42, 1.0683693903381625e42, 4.656628229175902e-45, 4.6798537356369095e-45),
+            (700.0, 1.5295933476718737e302, 1.5285003902339006e302, 4.669776431685377e-306, 4.6731107967079664e-306),
+        ];
+
+        for (x, i0_ref, i1_ref, k0_ref, k1_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(i0(x), i0_ref) <= 4, "{x}");
+            assert!(ulp_diff(i1(x), i1_ref) <= 4, "{x}");
+            assert!(ulp_diff(k0(x), k0_ref) <= 4, "{x}");
+            assert!(ulp_diff(k1(x), k1_ref) <= 4, "{x}");
+
+            assert_eq!(i0(-x), i0(x), "{x}");
+            assert_eq!(i1(-x), -i1(x), "{x}");
+
+            assert_eq!(i0_simd(xs)[0], i0(x), "{x}");
+            assert_eq!(i1_simd(xs)[0], i1(x), "{x}");
+            assert_eq!(k0_simd(xs)[0], k0(x), "{x}");
+            assert_eq!(k1_simd(xs)[0], k1(x), "{x}");
+            assert_eq!(i1_simd(-xs)[0], i1(-x), "{x}");
+            assert_eq!(xs.i0()[0], i0(x), "{x}");
+            assert_eq!(xs.k1()[0], k1(x), "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let xd = xf as f64;
+            assert!(ulp_difff(i0f(xf), i0(xd)) <= 6, "{x}");
+            assert!(ulp_difff(i1f(xf), i1(xd)) <= 6, "{x}");
+            assert!(ulp_difff(k0f(xf), k0(xd)) <= 6, "{x}");
+            assert!(ulp_difff(k1f(xf), k1(xd)) <= 6, "{x}");
+            assert_eq!(i1f(-xf), -i1f(xf), "{x}");
+            assert_eq!(i0f_simd(xfs)[0], i0f(xf), "{x}");
+            assert_eq!(i1f_simd(xfs)[0], i1f(xf), "{x}");
+            assert_eq!(k0f_simd(xfs)[0], k0f(xf), "{x}");
+            assert_eq!(k1f_simd(xfs)[0], k1f(xf), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_modified_bessel_scaled() {
+        // (x, i0e(x), i1e(x), k0e(x), k1e(x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (1e-300, 1.0, 5e-301, 690.8914594138721, 9.999999999999999e299),
+            (1e-5, 0.9999900000749996, 4.999950000312499e-6, 11.628973270095617, 100000.99994435512),
+            (0.5, 0.6450352704491501, 0.1564208031848717, 1.5241093857739094, 2.731009708211786),
+            (1.0, 0.46575960759364043, 0.20791041534970844, 1.144463079806895, 1.6361534862632583),
+            (1.99, 0.3094446712288107, 0.21541234648327334, 0.8434939733789928, 1.0367396067608632),
+            (2.0, 0.30850832255367105, 0.21526928924893765, 0.8415682150707714, 1.0334768470686886),
+            (5.0, 0.18354081260932836, 0.16397226694454237, 0.547807564313519, 0.6002738587883126),
+            (7.99, 0.14352476537933484, 0.134217339578281, 0.4368885216647203, 0.46346308465310176),
+            (8.0, 0.14343178185685032, 0.13414249329269817, 0.4366230186015861, 0.4631490928704961),
+            (20.0, 0.08978031188482602, 0.08750622218328867, 0.27854487665718225, 0.28542549694072644),
+            (100.0, 0.03994437929909668, 0.03974415302513025, 0.12517562165912657, 0.12579995047957854),
+            (700.0, 0.015081295651531358, 0.015070519444716848, 0.04736236945461357, 0.04739618765349454),
+            (1e10, 3.9894228040641945e-6, 3.989422803864723e-6, 1.2533141372998339e-5, 1.2533141373624996e-5),
+            (1e300, 3.9894228040143264e-151, 3.9894228040143264e-151, 1.2533141373155002e-150, 1.2533141373155002e-150),
+        ];
+
+        for (x, i0e_ref, i1e_ref, k0e_ref, k1e_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            assert!(ulp_diff(i0e(x), i0e_ref) <= 4, "{x}");
+            assert!(ulp_diff(i1e(x), i1e_ref) <= 4, "{x}");
+            assert!(ulp_diff(k0e(x), k0e_ref) <= 4, "{x}");
+            assert!(ulp_diff(k1e(x), k1e_ref) <= 4, "{x}");
+
+            assert_eq!(i0e(-x), i0e(x), "{x}");
+            assert_eq!(i1e(-x), -i1e(x), "{x}");
+
+            assert_eq!(i0e_simd(xs)[0], i0e(x), "{x}");
+            assert_eq!(i1e_simd(xs)[0], i1e(x), "{x}");
+            assert_eq!(k0e_simd(xs)[0], k0e(x), "{x}");
+            assert_eq!(k1e_simd(xs)[0], k1e(x), "{x}");
+            assert_eq!(xs.i1e()[0], i1e(x), "{x}");
+            assert_eq!(xs.k0e()[0], k0e(x), "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let xd = xf as f64;
+            assert!(ulp_difff(i0ef(xf), i0e(xd)) <= 6, "{x}");
+            assert!(ulp_difff(i1ef(xf), i1e(xd)) <= 6, "{x}");
+            assert!(ulp_difff(k0ef(xf), k0e(xd)) <= 6, "{x}");
+            assert!(ulp_difff(k1ef(xf), k1e(xd)) <= 6, "{x}");
+            assert_eq!(i1ef(-xf), -i1ef(xf), "{x}");
+            assert_eq!(i0ef_simd(xfs)[0], i0ef(xf), "{x}");
+            assert_eq!(i1ef_simd(xfs)[0], i1ef(xf), "{x}");
+            assert_eq!(k0ef_simd(xfs)[0], k0ef(xf), "{x}");
+            assert_eq!(k1ef_simd(xfs)[0], k1ef(xf), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_modified_bessel_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        // (x, [i0, i1, k0, k1, i0e, i1e, k0e, k1e] at x)
+        #[rustfmt::skip]
+        let table = [
+            (0.0, [1.0, 0.0, INF, INF, 1.0, 0.0, INF, INF]),
+            (-0.0, [1.0, -0.0, INF, INF, 1.0, -0.0, INF, INF]),
+            (-1.0, [i0(1.0), -i1(1.0), NAN, NAN, i0e(1.0), -i1e(1.0), NAN, NAN]),
+            (714.0, [INF, INF, k0(714.0), k1(714.0), i0e(714.0), i1e(714.0), k0e(714.0), k1e(714.0)]),
+            (750.0, [INF, INF, 0.0, 0.0, i0e(750.0), i1e(750.0), k0e(750.0), k1e(750.0)]),
+            (INF, [INF, INF, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            (-INF, [INF, -INF, NAN, NAN, 0.0, -0.0, NAN, NAN]),
+            (NAN, [NAN, NAN, NAN, NAN, NAN, NAN, NAN, NAN]),
+        ];
+
+        for (x, y_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            let ys = [
+                (i0(x), i0_simd(xs)[0]),
+                (i1(x), i1_simd(xs)[0]),
+                (k0(x), k0_simd(xs)[0]),
+                (k1(x), k1_simd(xs)[0]),
+                (i0e(x), i0e_simd(xs)[0]),
+                (i1e(x), i1e_simd(xs)[0]),
+                (k0e(x), k0e_simd(xs)[0]),
+                (k1e(x), k1e_simd(xs)[0]),
+            ];
+
+            for ((y, y_simd), y_ref) in ys.into_iter().zip(y_ref) {
+                let same = |y: f64| {
+                    y.to_bits() == y_ref.to_bits()
+                        || y.is_nan() && y_ref.is_nan()
+                };
+
+                assert!(same(y), "{x} {y} {y_ref}");
+                assert!(same(y_simd), "{x} {y_simd} {y_ref}");
+            }
+        }
+
+        // The f32 versions have the same special values, and overflow and
+        // underflow at their own thresholds
+        for x in [0.0f32, -0.0, f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+            let xs = Simd::<f32, 4>::splat(x);
+            let xd = x as f64;
+
+            let ys = [
+                (i0f(x), i0f_simd(xs)[0], i0(xd)),
+                (i1f(x), i1f_simd(xs)[0], i1(xd)),
+                (k0f(x), k0f_simd(xs)[0], k0(xd)),
+                (k1f(x), k1f_simd(xs)[0], k1(xd)),
+                (i0ef(x), i0ef_simd(xs)[0], i0e(xd)),
+                (i1ef(x), i1ef_simd(xs)[0], i1e(xd)),
+                (k0ef(x), k0ef_simd(xs)[0], k0e(xd)),
+                (k1ef(x), k1ef_simd(xs)[0], k1e(xd)),
+            ];
+
+            for (y, y_simd, y_ref) in ys {
+                let same = |y: f32| {
+                    y.to_bits() == (y_ref as f32).to_bits()
+                        || y.is_nan() && y_ref.is_nan()
+                };
+
+                assert!(same(y), "{x} {y} {y_ref}");
+                assert!(same(y_simd), "{x} {y_simd} {y_ref}");
+            }
+        }
+
+        assert!(i0f(91.9).is_finite());
+        assert_eq!(i0f(92.0), f32::INFINITY);
+        assert_eq!(
+            i1f_simd(Simd::<f32, 4>::splat(-92.0))[0],
+            f32::NEG_INFINITY
+        );
+        assert_eq!(k0f(104.0), 0.0);
+        assert_eq!(k1f_simd(Simd::<f32, 4>::splat(104.0))[0], 0.0);
+        assert!(k0f(-1.0).is_nan());
+    }
+}
@@ -0,0 +1,1757 @@
+use std::{
+    f64::consts::{E, PI},
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    exp, exp_simd, expf, expf_simd,
+    gamma::{STIRLING_MINIMAX, STIRLING_MINIMAX_F32},
+    ln, ln_simd, lnf, lnf_simd, log1p, log1p_simd, log1pf, log1pf_simd,
+    polyval, polyval_simd, polyvalf, polyvalf_simd, powi, powi_simd, powif,
+    powif_simd, sincospi, sincospi_simd, sincospif, sincospif_simd, sinpi,
+    sinpi_simd, sinpif, sinpif_simd,
+};
+
+// f(u) = (1 + u) digamma(1 + u) / (u - r), with r = x0 - 1 where x0 is the
+// positive zero of digamma
+// domain: 0 <= u <= 1
+const DIGAMMA_MINIMAX: [f64; 17] = [
+    -8.600687405079547e-8,
+    8.9667472835228e-7,
+    -4.576482476985037e-6,
+    1.5608188877946832e-5,
+    -4.133325887613278e-5,
+    9.411305190564162e-5,
+    -0.00019837228106979433,
+    0.0004057858715428533,
+    -0.0008253662515907108,
+    0.0016876261815253625,
+    -0.00349278970271321,
+    0.007381389744646895,
+    -0.01617720517013437,
+    0.037927560555407344,
+    -0.10222508831170986,
+    0.39568677690161586,
+    1.2503801375034054,
+];
+
+// x0 - 1 = DIGAMMA_ROOT_HI + DIGAMMA_ROOT_LO
+const DIGAMMA_ROOT_HI: f64 = 0.46163214496836236;
+const DIGAMMA_ROOT_LO: f64 = -1.5522348162858677e-17;
+
+// -B_2k / 2k for k = 8, ..., 1, the asymptotic series of digamma in 1 / x^2
+const DIGAMMA_ASYMPTOTIC: [f64; 8] = [
+    0.4432598039215686,
+    -0.08333333333333333,
+    0.021092796092796094,
+    -0.007575757575757576,
+    0.004166666666666667,
+    -0.003968253968253968,
+    0.008333333333333333,
+    -0.08333333333333333,
+];
+
+// B_2k for k = 10, ..., 1, the asymptotic series of trigamma in 1 / x^2
+const TRIGAMMA_ASYMPTOTIC: [f64; 10] = [
+    -529.1242424242424,
+    54.971177944862156,
+    -7.092156862745098,
+    1.1666666666666667,
+    -0.2531135531135531,
+    0.07575757575757576,
+    -0.03333333333333333,
+    0.023809523809523808,
+    -0.03333333333333333,
+    0.16666666666666666,
+];
+
+// B_2k for k = 1, ..., 12
+const BERNOULLI: [f64; 12] = [
+    0.16666666666666666,
+    -0.03333333333333333,
+    0.023809523809523808,
+    -0.03333333333333333,
+    0.07575757575757576,
+    -0.2531135531135531,
+    1.1666666666666667,
+    -7.092156862745098,
+    54.971177944862156,
+    -529.1242424242424,
+    6192.123188405797,
+    -86580.25311355312,
+];
+
+// From here on the asymptotic series are used. Smaller arguments are shifted
+// up to it, except for digamma, which is shifted down to DIGAMMA_MINIMAX.
+// polygamma of order n uses POLYGAMMA_BIG + n, as its series converges slower.
+const POLYGAMMA_BIG: f64 = 10.0;
+
+// Beyond this order (n - 1)! overflows, and the terms are taken in log space
+const POLYGAMMA_MAX_ORDER: i32 = 171;
+
+// Past POLYGAMMA_MAX_ORDER, terms this many e-folds below the first one no
+// longer count
+const POLYGAMMA_NEGLIGIBLE: f64 = 40.0;
+
+// e - E
+const E_LO: f64 = 1.4456468917292502e-16;
+
+// ln(2 pi) / 2
+const HALF_LN_2PI: f64 = 0.9189385332046728;
+
+// Length of the coefficients of D_n for n <= POLYGAMMA_MAX_ORDER
+const COT_DERIVATIVE_LEN: usize = POLYGAMMA_MAX_ORDER as usize + 2;
+
+// digamma(1 + u) for 0 <= u <= 1, with the zero factored out so that the
+// relative error stays small around it
+#[inline(always)]
+fn digamma_root(u: f64) -> f64 {
+    ((u - DIGAMMA_ROOT_HI) - DIGAMMA_ROOT_LO) * polyval(&DIGAMMA_MINIMAX, u)
+        / (1.0 + u)
+}
+
+// digamma(x) for x > 0
+fn digamma_pos(x: f64) -> f64 {
+    if x >= POLYGAMMA_BIG {
+        let r = x.recip();
+        let t = r * r;
+
+        ln(x) - 0.5 * r + t * polyval(&DIGAMMA_ASYMPTOTIC, t)
+    } else if x < 1.0 {
+        digamma_root(x) - x.recip()
+    } else {
+        // digamma(k + 1 + u) = digamma(1 + u) + 1 / (1 + u) + ... + 1 / (k + u)
+        let u = x - x.floor();
+
+        let mut s = 0.0;
+        for k in 1..x as usize {
+            s += (k as f64 + u).recip();
+        }
+
+        digamma_root(u) + s
+    }
+}
+
+// trigamma(x) for x >= 0
+fn trigamma_pos(x: f64) -> f64 {
+    // trigamma(x) = trigamma(x + 1) + 1 / x^2
+    let mut x = x;
+    let mut s = 0.0;
+    while x < POLYGAMMA_BIG {
+        s += (x * x).recip();
+        x += 1.0;
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    s + r * (1.0 + r.mul_add(0.5, t * polyval(&TRIGAMMA_ASYMPTOTIC, t)))
+}
+
+// The parts of polygamma of order n >= 2 that only depend on n
+struct PolygammaOrder {
+    n: i32,
+    // (-1)^(n + 1) (n - 1)!
+    scale: f64,
+    // The asymptotic series relative to its leading term (n - 1)! / x^n, in
+    // terms of 1 / x^2, B_2k binomial(2k + n - 1, 2k) for k = 12, ..., 1
+    series: [f64; 12],
+    // Where the series takes over from the recurrence
+    big: f64,
+}
+
+impl PolygammaOrder {
+    fn new(n: i32) -> Self {
+        let mut scale = 1.0;
+        for k in 1..n {
+            scale *= k as f64;
+        }
+
+        let mut series = [0.0; 12];
+        let mut binomial = 1.0;
+        for (k, b) in (1..=12).zip(BERNOULLI) {
+            let m = 2.0 * k as f64;
+            binomial *=
+                (m + n as f64 - 2.0) * (m + n as f64 - 1.0) / ((m - 1.0) * m);
+            series[12 - k] = b * binomial;
+        }
+
+        Self {
+            n,
+            scale: if n % 2 == 0 { -scale } else { scale },
+            series,
+            big: POLYGAMMA_BIG + n as f64,
+        }
+    }
+
+    // (-1)^(n + 1) (n - 1)! / x^m, with x^m split in two halves so that it
+    // does not overflow while the quotient is still in range
+    fn scaled_recip_pow(&self, x: f64, m: i32) -> f64 {
+        self.scale / powi(x, m / 2) / powi(x, m - m / 2)
+    }
+
+    #[inline(always)]
+    fn scaled_recip_pow_simd<const LANES: usize>(
+        &self,
+        x: Simd<f64, LANES>,
+        m: i32,
+    ) -> Simd<f64, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        Simd::splat(self.scale)
+            / powi_simd(x, Simd::splat(m / 2))
+            / powi_simd(x, Simd::splat(m - m / 2))
+    }
+
+    // Coefficients of D_n, lowest order first and padded with zeros, such that
+    // d^n/dz^n cot(z) = D_n(cot(z)). They follow from
+    // D_(k + 1)(c) = -(1 + c^2) D_k'(c), starting at D_0(c) = c.
+    fn cot_derivative(&self) -> [f64; COT_DERIVATIVE_LEN] {
+        let mut d = [0.0; COT_DERIVATIVE_LEN];
+        d[1] = 1.0;
+
+        // D_k has degree k + 1. The update is done in place, with prev
+        // holding the old coefficient below the current one.
+        for k in 0..self.n as usize {
+            let mut prev = 0.0;
+            for j in 0..k + 3 {
+                let up = if j < k + 2 {
+                    (j + 1) as f64 * d[j + 1]
+                } else {
+                    0.0
+                };
+                let down = (j as f64 - 1.0) * prev;
+
+                prev = d[j];
+                d[j] = -(up + down);
+            }
+        }
+
+        d
+    }
+}
+
+// ln(gamma(m) / t^m) for m > POLYGAMMA_MAX_ORDER and t = t_hi + t_lo > 0,
+// with ln(gamma(m)) from Stirling's series. That makes it
+// m ln(q / e) - ln(m) / 2 + ln(2 pi) / 2 + f(1 / m^2) / m with q = m / t.
+// ln(q / e) is found from the distance of q to e, which keeps the result
+// accurate where it is in range, however large m is.
+#[inline(always)]
+fn ln_gamma_ratio(m: f64, t_hi: f64, t_lo: f64) -> f64 {
+    let q = m / t_hi;
+
+    // Where q underflows or overflows, so does the ratio
+    if q == 0.0 || q.is_infinite() {
+        return if q == 0.0 { -f64::INFINITY } else { q };
+    }
+
+    let q_lo = (q.mul_add(-t_hi, m) - q * t_lo) / t_hi;
+    let w = ((q - E) - E_LO + q_lo) / E;
+
+    let r = m.recip();
+    let c = 0.5f64.mul_add(-ln(m), HALF_LN_2PI)
+        + polyval(&STIRLING_MINIMAX, r * r) * r;
+
+    m.mul_add(log1p(w), c)
+}
+
+// t + 1 with its rounding error
+#[inline(always)]
+fn add_one(t: f64) -> (f64, f64) {
+    let u = t + 1.0;
+    let v = u - t;
+
+    (u, (t - (u - v)) + (1.0 - v))
+}
+
+// The sum of n! / (x + k)^(n + 1) over k >= 0 for x > 0 and
+// n > POLYGAMMA_MAX_ORDER. Below n + POLYGAMMA_BIG each term is at most about
+// 1 / e of the one before, so the recurrence stops after a few dozen terms
+// once they no longer count, or else hands over to the asymptotic series as
+// in polygamma_pos.
+fn polygamma_large_pos(n: i32, x: f64) -> f64 {
+    let m = n as f64 + 1.0;
+    let big = POLYGAMMA_BIG + n as f64;
+    let cutoff = ln_gamma_ratio(m, x, 0.0) - POLYGAMMA_NEGLIGIBLE;
+
+    let mut t = x;
+    let mut t_lo = 0.0;
+    let mut s = 0.0;
+    while t < big {
+        let l = ln_gamma_ratio(m, t, t_lo);
+        if l < cutoff {
+            return s;
+        }
+
+        s += exp(l);
+
+        let (u, u_lo) = add_one(t);
+        t = u;
+        t_lo += u_lo;
+    }
+
+    // The series in terms of 1 / x, as its coefficients in terms of 1 / x^2
+    // overflow at these orders
+    let r = t.recip();
+
+    let mut p = 1.0;
+    let mut series = 0.0;
+    for (k, b) in (1..=12).zip(BERNOULLI) {
+        let j = 2.0 * k as f64 + n as f64 - 2.0;
+        p *= (j * r) * ((j + 1.0) * r) / ((2 * k - 1) * 2 * k) as f64;
+        series += b * p;
+    }
+
+    let y = exp(ln_gamma_ratio(n as f64, t, t_lo))
+        * (1.0 + (0.5 * n as f64).mul_add(r, series));
+
+    s + y
+}
+
+// polygamma(n, x) for n > POLYGAMMA_MAX_ORDER, away from the poles. For
+// negative x the terms next to the closest pole are so large that the result
+// overflows, unless x is halfway between two poles and n is even. Then the
+// terms up to -x cancel in pairs, and the rest is the sum from 1 - x on.
+fn polygamma_large(n: i32, x: f64) -> f64 {
+    let y = if x.is_nan() || x > 0.0 {
+        polygamma_large_pos(n, x)
+    } else if n % 2 == 0 && x.ceil() - x == 0.5 {
+        polygamma_large_pos(n, 1.0 - x)
+    } else if n % 2 == 1 {
+        f64::INFINITY
+    } else {
+        f64::INFINITY.copysign(x - x.round())
+    };
+
+    if n % 2 == 0 {
+        -y
+    } else {
+        y
+    }
+}
+
+// polygamma(n, x) for x >= 0 and n >= 2
+fn polygamma_pos(order: &PolygammaOrder, x: f64) -> f64 {
+    let n = order.n;
+
+    // polygamma(n, x) = polygamma(n, x + 1) + (-1)^(n + 1) n! / x^(n + 1).
+    // All terms have the sign of order.scale, and so does the zero s starts
+    // at.
+    let mut x = x;
+    let mut s = 0.0 * order.scale;
+    while x < order.big {
+        s += order.scaled_recip_pow(x, n + 1);
+        x += 1.0;
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    let y = order.scaled_recip_pow(x, n)
+        * (1.0 + (0.5 * n as f64).mul_add(r, t * polyval(&order.series, t)));
+
+    (n as f64).mul_add(s, y)
+}
+
+/// Computes the digamma function, the derivative of `lgamma`. Returns NaN at
+/// the poles at the negative integers, and infinity with the opposite sign of
+/// `x` at zero.
+pub fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x > 0.0 {
+        digamma_pos(x)
+    } else if x == 0.0 {
+        -f64::INFINITY.copysign(x)
+    } else if x == x.floor() {
+        f64::NAN
+    } else {
+        // Reflection, digamma(x) = digamma(1 - x) - pi cot(pi x)
+        let (s, c) = sincospi(x);
+        digamma_pos(1.0 - x) - PI * c / s
+    }
+}
+
+/// Computes the trigamma function, the second derivative of `lgamma`.
+/// Returns infinity at zero and the negative integers.
+pub fn trigamma(x: f64) -> f64 {
+    if x.is_nan() || x >= 0.0 {
+        trigamma_pos(x)
+    } else if x.is_infinite() {
+        f64::NAN
+    } else if x == x.floor() {
+        f64::INFINITY
+    } else {
+        // Reflection, trigamma(x) = pi^2 / sin(pi x)^2 - trigamma(1 - x)
+        let p = PI / sinpi(x);
+        p * p - trigamma_pos(1.0 - x)
+    }
+}
+
+/// Computes the polygamma function of order `n`, the `n + 1`th derivative of
+/// `lgamma`, so that `polygamma(0, x) = digamma(x)` and
+/// `polygamma(1, x) = trigamma(x)`. Returns NaN for negative `n`. At zero
+/// and the negative integers the result is infinity for odd `n`, and NaN for
+/// even `n` except at zero, where it is infinity with the opposite sign of
+/// `x`. Above order 171 the terms are taken in log space, as `n!` overflows.
+/// The result is then only infinite or zero where it overflows or underflows,
+/// and its relative error grows to about `2e-13` towards either end of the
+/// range.
+pub fn polygamma(n: i32, x: f64) -> f64 {
+    match n {
+        0 => return digamma(x),
+        1 => return trigamma(x),
+        _ if n < 0 => return f64::NAN,
+        _ => {}
+    }
+
+    if x <= 0.0 && x == x.floor() {
+        if x.is_infinite() {
+            f64::NAN
+        } else if n % 2 == 1 {
+            f64::INFINITY
+        } else if x == 0.0 {
+            -f64::INFINITY.copysign(x)
+        } else {
+            f64::NAN
+        }
+    } else if n > POLYGAMMA_MAX_ORDER {
+        polygamma_large(n, x)
+    } else if x.is_nan() || x > 0.0 {
+        polygamma_pos(&PolygammaOrder::new(n), x)
+    } else {
+        // Differentiating the reflection of digamma n times gives
+        // polygamma(n, x) = (-1)^n polygamma(n, 1 - x) - pi^(n + 1) D_n(c)
+        // with c = cot(pi x)
+        let order = PolygammaOrder::new(n);
+
+        let (s, c) = sincospi(x);
+        let c = c / s;
+
+        let d = order.cot_derivative()[..n as usize + 2]
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &d| c.mul_add(acc, d));
+
+        let y = polygamma_pos(&order, 1.0 - x);
+        let y = if n % 2 == 0 { y } else { -y };
+
+        y - powi(PI, n + 1) * d
+    }
+}
+
+#[inline(always)]
+fn digamma_root_simd<const LANES: usize>(
+    u: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    ((u - Simd::splat(DIGAMMA_ROOT_HI)) - Simd::splat(DIGAMMA_ROOT_LO))
+        * polyval_simd(&DIGAMMA_MINIMAX, u)
+        / (Simd::splat(1.0) + u)
+}
+
+// digamma(x) for lanes with x > 0; other lanes are garbage except NaN
+#[inline(always)]
+fn digamma_pos_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+
+    let small = x.simd_lt(Simd::splat(1.0));
+    let big = x.simd_ge(Simd::splat(POLYGAMMA_BIG));
+
+    let u = small.select(x, x - x.floor());
+
+    let n = x.simd_min(Simd::splat(POLYGAMMA_BIG)).floor();
+
+    let mut s = zero;
+    for k in 1..POLYGAMMA_BIG as usize {
+        let k = Simd::splat(k as f64);
+        let mask = k.simd_lt(n);
+
+        if !mask.any() {
+            break;
+        }
+
+        s += mask.select((k + u).recip(), zero);
+    }
+
+    let mut y = digamma_root_simd(u) + small.select(-x.recip(), s);
+
+    if big.any() {
+        let xb = x.simd_max(Simd::splat(POLYGAMMA_BIG));
+
+        let r = xb.recip();
+        let t = r * r;
+
+        let y_big = ln_simd(xb) - Simd::splat(0.5) * r
+            + t * polyval_simd(&DIGAMMA_ASYMPTOTIC, t);
+
+        y = big.select(y_big, y);
+    }
+
+    y
+}
+
+// trigamma(x) for lanes with x >= 0; other lanes are garbage except NaN
+#[inline(always)]
+fn trigamma_pos_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let mut x = x;
+    let mut s = Simd::splat(0.0);
+    for _ in 0..POLYGAMMA_BIG as usize {
+        let mask = x.simd_lt(Simd::splat(POLYGAMMA_BIG));
+
+        if !mask.any() {
+            break;
+        }
+
+        s = mask.select(s + (x * x).recip(), s);
+        x = mask.select(x + one, x);
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    s + r
+        * (one
+            + r.mul_add(
+                Simd::splat(0.5),
+                t * polyval_simd(&TRIGAMMA_ASYMPTOTIC, t),
+            ))
+}
+
+// polygamma(n, x) for lanes with x >= 0 and n >= 2; other lanes are garbage
+// except NaN
+#[inline(always)]
+fn polygamma_pos_simd<const LANES: usize>(
+    order: &PolygammaOrder,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let n = order.n;
+    let big = Simd::splat(order.big);
+    let one = Simd::splat(1.0);
+
+    let mut x = x;
+    let mut s = Simd::splat(0.0 * order.scale);
+    loop {
+        let mask = x.simd_lt(big);
+
+        if !mask.any() {
+            break;
+        }
+
+        s = mask.select(s + order.scaled_recip_pow_simd(x, n + 1), s);
+        x = mask.select(x + one, x);
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    let y = order.scaled_recip_pow_simd(x, n)
+        * (one
+            + Simd::splat(0.5 * n as f64)
+                .mul_add(r, t * polyval_simd(&order.series, t)));
+
+    Simd::splat(n as f64).mul_add(s, y)
+}
+
+#[inline(always)]
+fn ln_gamma_ratio_simd<const LANES: usize>(
+    m: Simd<f64, LANES>,
+    t_hi: Simd<f64, LANES>,
+    t_lo: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let q = m / t_hi;
+    let q_lo = (q.mul_add(-t_hi, m) - q * t_lo) / t_hi;
+    let w = ((q - Simd::splat(E)) - Simd::splat(E_LO) + q_lo) / Simd::splat(E);
+
+    let r = m.recip();
+    let c = Simd::splat(0.5).mul_add(-ln_simd(m), Simd::splat(HALF_LN_2PI))
+        + polyval_simd(&STIRLING_MINIMAX, r * r) * r;
+
+    let y = m.mul_add(log1p_simd(w), c);
+
+    let zero = q.simd_eq(Simd::splat(0.0));
+    (zero | q.is_infinite())
+        .select(zero.select(Simd::splat(-f64::INFINITY), q), y)
+}
+
+#[inline(always)]
+fn add_one_simd<const LANES: usize>(
+    t: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let u = t + one;
+    let v = u - t;
+
+    (u, (t - (u - v)) + (one - v))
+}
+
+// polygamma_large_pos for lanes with x > 0; other lanes are garbage except
+// NaN
+#[inline(always)]
+fn polygamma_large_pos_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let m = Simd::splat(n as f64 + 1.0);
+    let big = Simd::splat(POLYGAMMA_BIG + n as f64);
+    let cutoff =
+        ln_gamma_ratio_simd(m, x, zero) - Simd::splat(POLYGAMMA_NEGLIGIBLE);
+
+    let mut t = x;
+    let mut t_lo = zero;
+    let mut s = zero;
+    let mut done = Mask::splat(false);
+    loop {
+        let mask = t.simd_lt(big) & !done;
+
+        if !mask.any() {
+            break;
+        }
+
+        let l = ln_gamma_ratio_simd(m, t, t_lo);
+        done |= mask & l.simd_lt(cutoff);
+
+        let mask = mask & !done;
+        s = mask.select(s + exp_simd(l), s);
+
+        let (u, u_lo) = add_one_simd(t);
+        t = mask.select(u, t);
+        t_lo = mask.select(t_lo + u_lo, t_lo);
+    }
+
+    let r = t.recip();
+
+    let mut p = Simd::splat(1.0);
+    let mut series = zero;
+    for (k, b) in (1..=12).zip(BERNOULLI) {
+        let j = Simd::splat(2.0 * k as f64 + n as f64 - 2.0);
+        p *= (j * r) * ((j + Simd::splat(1.0)) * r)
+            / Simd::splat(((2 * k - 1) * 2 * k) as f64);
+        series += Simd::splat(b) * p;
+    }
+
+    let y = exp_simd(ln_gamma_ratio_simd(Simd::splat(n as f64), t, t_lo))
+        * (Simd::splat(1.0) + Simd::splat(0.5 * n as f64).mul_add(r, series));
+
+    done.select(s, s + y)
+}
+
+#[inline(always)]
+fn polygamma_large_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let neg = x.simd_le(Simd::splat(0.0));
+    let half = (x.ceil() - x).simd_eq(Simd::splat(0.5));
+
+    let y = polygamma_large_pos_simd(n, neg.select(Simd::splat(1.0) - x, x));
+
+    if n % 2 == 1 {
+        neg.select(Simd::splat(f64::INFINITY), y)
+    } else {
+        let y_neg =
+            half.select(y, Simd::splat(f64::INFINITY).copysign(x - x.round()));
+        -neg.select(y_neg, y)
+    }
+}
+
+/// The SIMD version of [`digamma`].
+#[inline(always)]
+pub fn digamma_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+
+    let neg = x.simd_lt(zero);
+
+    let mut y = digamma_pos_simd(neg.select(Simd::splat(1.0) - x, x));
+
+    if neg.any() {
+        let (s, c) = sincospi_simd(x);
+        y = neg.select(y - Simd::splat(PI) * c / s, y);
+    }
+
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+    let y_pole = x.simd_eq(zero).select(
+        -Simd::splat(f64::INFINITY).copysign(x),
+        Simd::splat(f64::NAN),
+    );
+
+    pole.select(y_pole, y)
+}
+
+/// The SIMD version of [`trigamma`].
+#[inline(always)]
+pub fn trigamma_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let neg = x.simd_lt(Simd::splat(0.0));
+
+    let mut y = trigamma_pos_simd(neg.select(Simd::splat(1.0) - x, x));
+
+    if neg.any() {
+        let p = Simd::splat(PI) / sinpi_simd(x);
+        y = neg.select(p * p - y, y);
+    }
+
+    let pole = neg & x.simd_eq(x.floor());
+    let y_pole = x
+        .is_infinite()
+        .select(Simd::splat(f64::NAN), Simd::splat(f64::INFINITY));
+
+    pole.select(y_pole, y)
+}
+
+/// The SIMD version of [`polygamma`].
+#[inline(always)]
+pub fn polygamma_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    match n {
+        0 => return digamma_simd(x),
+        1 => return trigamma_simd(x),
+        _ if n < 0 => return Simd::splat(f64::NAN),
+        _ => {}
+    }
+
+    let zero = Simd::splat(0.0);
+
+    let y = if n > POLYGAMMA_MAX_ORDER {
+        polygamma_large_simd(n, x)
+    } else {
+        let order = PolygammaOrder::new(n);
+        let neg = x.simd_lt(zero);
+
+        let mut y =
+            polygamma_pos_simd(&order, neg.select(Simd::splat(1.0) - x, x));
+
+        if neg.any() {
+            let (s, c) = sincospi_simd(x);
+            let c = c / s;
+
+            let d = order.cot_derivative()[..n as usize + 2]
+                .iter()
+                .rev()
+                .fold(zero, |acc, &d| c.mul_add(acc, Simd::splat(d)));
+
+            let y_neg = if n % 2 == 0 { y } else { -y };
+            let y_neg = y_neg - Simd::splat(powi(PI, n + 1)) * d;
+
+            y = neg.select(y_neg, y);
+        }
+
+        y
+    };
+
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+    let y_pole = if n % 2 == 1 {
+        Simd::splat(f64::INFINITY)
+    } else {
+        x.simd_eq(zero).select(
+            -Simd::splat(f64::INFINITY).copysign(x),
+            Simd::splat(f64::NAN),
+        )
+    };
+    let y_pole = x.is_infinite().select(Simd::splat(f64::NAN), y_pole);
+
+    pole.select(y_pole, y)
+}
+
+// f(u) = (1 + u) digamma(1 + u) / (u - r), as for DIGAMMA_MINIMAX
+// domain: 0 <= u <= 1
+const DIGAMMA_MINIMAX_F32: [f32; 8] = [
+    3.208_627_2e-4,
+    -1.964_551_4e-3,
+    6.310_88e-3,
+    -1.572_611e-2,
+    3.781_98e-2,
+    -1.022_121_16e-1,
+    3.956_861_8e-1,
+    1.250_380_2,
+];
+
+// x0 - 1 = DIGAMMA_ROOT_HI_F32 + DIGAMMA_ROOT_LO_F32
+const DIGAMMA_ROOT_HI_F32: f32 = 4.616_321_3e-1;
+const DIGAMMA_ROOT_LO_F32: f32 = 1.243_815e-8;
+
+// -B_2k / 2k for k = 4, ..., 1
+const DIGAMMA_ASYMPTOTIC_F32: [f32; 4] =
+    [4.166_667e-3, -3.968_254e-3, 8.333_334e-3, -8.333_334e-2];
+
+// B_2k for k = 4, ..., 1
+const TRIGAMMA_ASYMPTOTIC_F32: [f32; 4] = [
+    -3.333_333_4e-2,
+    2.380_952_4e-2,
+    -3.333_333_4e-2,
+    1.666_666_7e-1,
+];
+
+const POLYGAMMA_BIG_F32: f32 = 10.0;
+
+// Beyond this order (n - 1)! overflows in f32
+const POLYGAMMA_MAX_ORDER_F32: i32 = 35;
+
+const POLYGAMMA_NEGLIGIBLE_F32: f32 = 20.0;
+
+// e - E in f32
+const E_LO_F32: f32 = 8.254_840_4e-8;
+
+const HALF_LN_2PI_F32: f32 = 9.189_385e-1;
+
+// B_2k for k = 1, ..., 6
+const BERNOULLI_F32: [f32; 6] = [
+    1.666_666_7e-1,
+    -3.333_333_5e-2,
+    2.380_952_4e-2,
+    -3.333_333_5e-2,
+    7.575_758e-2,
+    -2.531_135_7e-1,
+];
+
+// PolygammaOrder for the f32 versions. Six terms of the series are enough
+// from POLYGAMMA_BIG_F32 + n on.
+struct PolygammaOrderF32 {
+    order: PolygammaOrder,
+    scale: f32,
+    series: [f32; 6],
+    big: f32,
+}
+
+impl PolygammaOrderF32 {
+    fn new(n: i32) -> Self {
+        let order = PolygammaOrder::new(n);
+
+        let mut series = [0.0; 6];
+        for (s, &c) in series.iter_mut().zip(&order.series[6..]) {
+            *s = c as f32;
+        }
+
+        Self {
+            scale: order.scale as f32,
+            series,
+            big: POLYGAMMA_BIG_F32 + n as f32,
+            order,
+        }
+    }
+
+    // As PolygammaOrder::scaled_recip_pow, but with x^m split in three, as
+    // in f32 a half can overflow while the quotient is still in range
+    fn scaled_recip_pow(&self, x: f32, m: i32) -> f32 {
+        let a = m / 3;
+        let b = (m - a) / 2;
+
+        self.scale / powif(x, a) / powif(x, b) / powif(x, m - a - b)
+    }
+
+    #[inline(always)]
+    fn scaled_recip_pow_simd<const LANES: usize>(
+        &self,
+        x: Simd<f32, LANES>,
+        m: i32,
+    ) -> Simd<f32, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        let a = m / 3;
+        let b = (m - a) / 2;
+
+        Simd::splat(self.scale)
+            / powif_simd(x, Simd::splat(a))
+            / powif_simd(x, Simd::splat(b))
+            / powif_simd(x, Simd::splat(m - a - b))
+    }
+
+    // The coefficients of D_n divided by scale, which keeps them in range for
+    // every order up to POLYGAMMA_MAX_ORDER_F32
+    fn cot_derivative(&self) -> [f32; COT_DERIVATIVE_LEN] {
+        self.order
+            .cot_derivative()
+            .map(|d| (d / self.order.scale) as f32)
+    }
+}
+
+#[inline(always)]
+fn digammaf_root(u: f32) -> f32 {
+    ((u - DIGAMMA_ROOT_HI_F32) - DIGAMMA_ROOT_LO_F32)
+        * polyvalf(&DIGAMMA_MINIMAX_F32, u)
+        / (1.0 + u)
+}
+
+fn digammaf_pos(x: f32) -> f32 {
+    if x >= POLYGAMMA_BIG_F32 {
+        let r = x.recip();
+        let t = r * r;
+
+        lnf(x) - 0.5 * r + t * polyvalf(&DIGAMMA_ASYMPTOTIC_F32, t)
+    } else if x < 1.0 {
+        digammaf_root(x) - x.recip()
+    } else {
+        let u = x - x.floor();
+
+        let mut s = 0.0;
+        for k in 1..x as usize {
+            s += (k as f32 + u).recip();
+        }
+
+        digammaf_root(u) + s
+    }
+}
+
+fn trigammaf_pos(x: f32) -> f32 {
+    let mut x = x;
+    let mut s = 0.0;
+    while x < POLYGAMMA_BIG_F32 {
+        s += (x * x).recip();
+        x += 1.0;
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    s + r * (1.0 + r.mul_add(0.5, t * polyvalf(&TRIGAMMA_ASYMPTOTIC_F32, t)))
+}
+
+// m as m_hi + m_lo, as it need not fit in an f32
+fn order_splitf(m: i64) -> (f32, f32) {
+    let m_hi = m as f32;
+
+    (m_hi, (m - m_hi as i64) as f32)
+}
+
+// As ln_gamma_ratio, with m = m_hi + m_lo. m_lo keeps q = m / t exact
+// enough however large m is.
+#[inline(always)]
+fn ln_gamma_ratiof(m_hi: f32, m_lo: f32, t_hi: f32, t_lo: f32) -> f32 {
+    use std::f32::consts::E;
+
+    let q = m_hi / t_hi;
+
+    if q == 0.0 || q.is_infinite() {
+        return if q == 0.0 { -f32::INFINITY } else { q };
+    }
+
+    let q_lo = (q.mul_add(-t_hi, m_hi) + m_lo - q * t_lo) / t_hi;
+    let w = ((q - E) - E_LO_F32 + q_lo) / E;
+
+    let r = m_hi.recip();
+    let c = 0.5f32.mul_add(-lnf(m_hi), HALF_LN_2PI_F32)
+        + polyvalf(&STIRLING_MINIMAX_F32, r * r) * r;
+
+    // Rounding m costs no more here than rounding the result
+    m_hi.mul_add(log1pf(w), c)
+}
+
+#[inline(always)]
+fn add_onef(t: f32) -> (f32, f32) {
+    let u = t + 1.0;
+    let v = u - t;
+
+    (u, (t - (u - v)) + (1.0 - v))
+}
+
+fn polygammaf_large_pos(n: i32, x: f32) -> f32 {
+    let (m_hi, m_lo) = order_splitf(n as i64 + 1);
+    let big = POLYGAMMA_BIG_F32 + n as f32;
+    let cutoff = ln_gamma_ratiof(m_hi, m_lo, x, 0.0) - POLYGAMMA_NEGLIGIBLE_F32;
+
+    let mut t = x;
+    let mut t_lo = 0.0;
+    let mut s = 0.0;
+    while t < big {
+        let l = ln_gamma_ratiof(m_hi, m_lo, t, t_lo);
+        if l < cutoff {
+            return s;
+        }
+
+        s += expf(l);
+
+        let (u, u_lo) = add_onef(t);
+        t = u;
+        t_lo += u_lo;
+    }
+
+    let r = t.recip();
+
+    let mut p = 1.0;
+    let mut series = 0.0;
+    for (k, b) in (1..=6).zip(BERNOULLI_F32) {
+        let j = 2.0 * k as f32 + n as f32 - 2.0;
+        p *= (j * r) * ((j + 1.0) * r) / ((2 * k - 1) * 2 * k) as f32;
+        series += b * p;
+    }
+
+    let (n_hi, n_lo) = order_splitf(n as i64);
+    let y = expf(ln_gamma_ratiof(n_hi, n_lo, t, t_lo))
+        * (1.0 + (0.5 * n as f32).mul_add(r, series));
+
+    s + y
+}
+
+fn polygammaf_large(n: i32, x: f32) -> f32 {
+    let y = if x.is_nan() || x > 0.0 {
+        polygammaf_large_pos(n, x)
+    } else if n % 2 == 0 && x.ceil() - x == 0.5 {
+        polygammaf_large_pos(n, 1.0 - x)
+    } else if n % 2 == 1 {
+        f32::INFINITY
+    } else {
+        f32::INFINITY.copysign(x - x.round())
+    };
+
+    if n % 2 == 0 {
+        -y
+    } else {
+        y
+    }
+}
+
+fn polygammaf_pos(order: &PolygammaOrderF32, x: f32) -> f32 {
+    let n = order.order.n;
+
+    let mut x = x;
+    let mut s = 0.0 * order.scale;
+    while x < order.big {
+        s += order.scaled_recip_pow(x, n + 1);
+        x += 1.0;
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    let y = order.scaled_recip_pow(x, n)
+        * (1.0 + (0.5 * n as f32).mul_add(r, t * polyvalf(&order.series, t)));
+
+    (n as f32).mul_add(s, y)
+}
+
+/// The `f32` version of [`digamma`].
+pub fn digammaf(x: f32) -> f32 {
+    use std::f32::consts::PI;
+
+    if x.is_nan() || x > 0.0 {
+        digammaf_pos(x)
+    } else if x == 0.0 {
+        -f32::INFINITY.copysign(x)
+    } else if x == x.floor() {
+        f32::NAN
+    } else {
+        let (s, c) = sincospif(x);
+        digammaf_pos(1.0 - x) - PI * c / s
+    }
+}
+
+/// The `f32` version of [`trigamma`].
+pub fn trigammaf(x: f32) -> f32 {
+    use std::f32::consts::PI;
+
+    if x.is_nan() || x >= 0.0 {
+        trigammaf_pos(x)
+    } else if x.is_infinite() {
+        f32::NAN
+    } else if x == x.floor() {
+        f32::INFINITY
+    } else {
+        let p = PI / sinpif(x);
+        p * p - trigammaf_pos(1.0 - x)
+    }
+}
+
+/// The `f32` version of [`polygamma`].
+pub fn polygammaf(n: i32, x: f32) -> f32 {
+    use std::f32::consts::PI;
+
+    match n {
+        0 => return digammaf(x),
+        1 => return trigammaf(x),
+        _ if n < 0 => return f32::NAN,
+        _ => {}
+    }
+
+    if x <= 0.0 && x == x.floor() {
+        if x.is_infinite() {
+            f32::NAN
+        } else if n % 2 == 1 {
+            f32::INFINITY
+        } else if x == 0.0 {
+            -f32::INFINITY.copysign(x)
+        } else {
+            f32::NAN
+        }
+    } else if n > POLYGAMMA_MAX_ORDER_F32 {
+        polygammaf_large(n, x)
+    } else if x.is_nan() || x > 0.0 {
+        polygammaf_pos(&PolygammaOrderF32::new(n), x)
+    } else {
+        let order = PolygammaOrderF32::new(n);
+
+        let (s, c) = sincospif(x);
+        let c = c / s;
+
+        let d = order.cot_derivative()[..n as usize + 2]
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &d| c.mul_add(acc, d));
+
+        let y = polygammaf_pos(&order, 1.0 - x);
+        let y = if n % 2 == 0 { y } else { -y };
+
+        y - powif(PI, n + 1) * d * order.scale
+    }
+}
+
+#[inline(always)]
+fn digammaf_root_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    ((u - Simd::splat(DIGAMMA_ROOT_HI_F32)) - Simd::splat(DIGAMMA_ROOT_LO_F32))
+        * polyvalf_simd(&DIGAMMA_MINIMAX_F32, u)
+        / (Simd::splat(1.0) + u)
+}
+
+#[inline(always)]
+fn digammaf_pos_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+
+    let small = x.simd_lt(Simd::splat(1.0));
+    let big = x.simd_ge(Simd::splat(POLYGAMMA_BIG_F32));
+
+    let u = small.select(x, x - x.floor());
+
+    let n = x.simd_min(Simd::splat(POLYGAMMA_BIG_F32)).floor();
+
+    let mut s = zero;
+    for k in 1..POLYGAMMA_BIG_F32 as usize {
+        let k = Simd::splat(k as f32);
+        let mask = k.simd_lt(n);
+
+        if !mask.any() {
+            break;
+        }
+
+        s += mask.select((k + u).recip(), zero);
+    }
+
+    let mut y = digammaf_root_simd(u) + small.select(-x.recip(), s);
+
+    if big.any() {
+        let xb = x.simd_max(Simd::splat(POLYGAMMA_BIG_F32));
+
+        let r = xb.recip();
+        let t = r * r;
+
+        let y_big = lnf_simd(xb) - Simd::splat(0.5) * r
+            + t * polyvalf_simd(&DIGAMMA_ASYMPTOTIC_F32, t);
+
+        y = big.select(y_big, y);
+    }
+
+    y
+}
+
+#[inline(always)]
+fn trigammaf_pos_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let mut x = x;
+    let mut s = Simd::splat(0.0);
+    for _ in 0..POLYGAMMA_BIG_F32 as usize {
+        let mask = x.simd_lt(Simd::splat(POLYGAMMA_BIG_F32));
+
+        if !mask.any() {
+            break;
+        }
+
+        s = mask.select(s + (x * x).recip(), s);
+        x = mask.select(x + one, x);
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    s + r
+        * (one
+            + r.mul_add(
+                Simd::splat(0.5),
+                t * polyvalf_simd(&TRIGAMMA_ASYMPTOTIC_F32, t),
+            ))
+}
+
+#[inline(always)]
+fn polygammaf_pos_simd<const LANES: usize>(
+    order: &PolygammaOrderF32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let n = order.order.n;
+    let big = Simd::splat(order.big);
+    let one = Simd::splat(1.0);
+
+    let mut x = x;
+    let mut s = Simd::splat(0.0 * order.scale);
+    loop {
+        let mask = x.simd_lt(big);
+
+        if !mask.any() {
+            break;
+        }
+
+        s = mask.select(s + order.scaled_recip_pow_simd(x, n + 1), s);
+        x = mask.select(x + one, x);
+    }
+
+    let r = x.recip();
+    let t = r * r;
+
+    let y = order.scaled_recip_pow_simd(x, n)
+        * (one
+            + Simd::splat(0.5 * n as f32)
+                .mul_add(r, t * polyvalf_simd(&order.series, t)));
+
+    Simd::splat(n as f32).mul_add(s, y)
+}
+
+#[inline(always)]
+fn ln_gamma_ratiof_simd<const LANES: usize>(
+    m_hi: Simd<f32, LANES>,
+    m_lo: Simd<f32, LANES>,
+    t_hi: Simd<f32, LANES>,
+    t_lo: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::E;
+
+    let q = m_hi / t_hi;
+    let q_lo = (q.mul_add(-t_hi, m_hi) + m_lo - q * t_lo) / t_hi;
+    let w =
+        ((q - Simd::splat(E)) - Simd::splat(E_LO_F32) + q_lo) / Simd::splat(E);
+
+    let r = m_hi.recip();
+    let c = Simd::splat(0.5)
+        .mul_add(-lnf_simd(m_hi), Simd::splat(HALF_LN_2PI_F32))
+        + polyvalf_simd(&STIRLING_MINIMAX_F32, r * r) * r;
+
+    let y = m_hi.mul_add(log1pf_simd(w), c);
+
+    let zero = q.simd_eq(Simd::splat(0.0));
+    (zero | q.is_infinite())
+        .select(zero.select(Simd::splat(-f32::INFINITY), q), y)
+}
+
+#[inline(always)]
+fn add_onef_simd<const LANES: usize>(
+    t: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1.0);
+
+    let u = t + one;
+    let v = u - t;
+
+    (u, (t - (u - v)) + (one - v))
+}
+
+#[inline(always)]
+fn polygammaf_large_pos_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let (m_hi, m_lo) = order_splitf(n as i64 + 1);
+    let (m_hi, m_lo) = (Simd::splat(m_hi), Simd::splat(m_lo));
+    let big = Simd::splat(POLYGAMMA_BIG_F32 + n as f32);
+    let cutoff = ln_gamma_ratiof_simd(m_hi, m_lo, x, zero)
+        - Simd::splat(POLYGAMMA_NEGLIGIBLE_F32);
+
+    let mut t = x;
+    let mut t_lo = zero;
+    let mut s = zero;
+    let mut done = Mask::splat(false);
+    loop {
+        let mask = t.simd_lt(big) & !done;
+
+        if !mask.any() {
+            break;
+        }
+
+        let l = ln_gamma_ratiof_simd(m_hi, m_lo, t, t_lo);
+        done |= mask & l.simd_lt(cutoff);
+
+        let mask = mask & !done;
+        s = mask.select(s + expf_simd(l), s);
+
+        let (u, u_lo) = add_onef_simd(t);
+        t = mask.select(u, t);
+        t_lo = mask.select(t_lo + u_lo, t_lo);
+    }
+
+    let r = t.recip();
+
+    let mut p = Simd::splat(1.0);
+    let mut series = zero;
+    for (k, b) in (1..=6).zip(BERNOULLI_F32) {
+        let j = Simd::splat(2.0 * k as f32 + n as f32 - 2.0);
+        p *= (j * r) * ((j + Simd::splat(1.0)) * r)
+            / Simd::splat(((2 * k - 1) * 2 * k) as f32);
+        series += Simd::splat(b) * p;
+    }
+
+    let (n_hi, n_lo) = order_splitf(n as i64);
+    let y = expf_simd(ln_gamma_ratiof_simd(
+        Simd::splat(n_hi),
+        Simd::splat(n_lo),
+        t,
+        t_lo,
+    )) * (Simd::splat(1.0)
+        + Simd::splat(0.5 * n as f32).mul_add(r, series));
+
+    done.select(s, s + y)
+}
+
+#[inline(always)]
+fn polygammaf_large_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let neg = x.simd_le(Simd::splat(0.0));
+    let half = (x.ceil() - x).simd_eq(Simd::splat(0.5));
+
+    let y = polygammaf_large_pos_simd(n, neg.select(Simd::splat(1.0) - x, x));
+
+    if n % 2 == 1 {
+        neg.select(Simd::splat(f32::INFINITY), y)
+    } else {
+        let y_neg =
+            half.select(y, Simd::splat(f32::INFINITY).copysign(x - x.round()));
+        -neg.select(y_neg, y)
+    }
+}
+
+/// The SIMD version of [`digammaf`].
+#[inline(always)]
+pub fn digammaf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::PI;
+
+    let zero = Simd::splat(0.0);
+
+    let neg = x.simd_lt(zero);
+
+    let mut y = digammaf_pos_simd(neg.select(Simd::splat(1.0) - x, x));
+
+    if neg.any() {
+        let (s, c) = sincospif_simd(x);
+        y = neg.select(y - Simd::splat(PI) * c / s, y);
+    }
+
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+    let y_pole = x.simd_eq(zero).select(
+        -Simd::splat(f32::INFINITY).copysign(x),
+        Simd::splat(f32::NAN),
+    );
+
+    pole.select(y_pole, y)
+}
+
+/// The SIMD version of [`trigammaf`].
+#[inline(always)]
+pub fn trigammaf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::PI;
+
+    let neg = x.simd_lt(Simd::splat(0.0));
+
+    let mut y = trigammaf_pos_simd(neg.select(Simd::splat(1.0) - x, x));
+
+    if neg.any() {
+        let p = Simd::splat(PI) / sinpif_simd(x);
+        y = neg.select(p * p - y, y);
+    }
+
+    let pole = neg & x.simd_eq(x.floor());
+    let y_pole = x
+        .is_infinite()
+        .select(Simd::splat(f32::NAN), Simd::splat(f32::INFINITY));
+
+    pole.select(y_pole, y)
+}
+
+/// The SIMD version of [`polygammaf`].
+#[inline(always)]
+pub fn polygammaf_simd<const LANES: usize>(
+    n: i32,
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    use std::f32::consts::PI;
+
+    match n {
+        0 => return digammaf_simd(x),
+        1 => return trigammaf_simd(x),
+        _ if n < 0 => return Simd::splat(f32::NAN),
+        _ => {}
+    }
+
+    let zero = Simd::splat(0.0);
+
+    let y = if n > POLYGAMMA_MAX_ORDER_F32 {
+        polygammaf_large_simd(n, x)
+    } else {
+        let order = PolygammaOrderF32::new(n);
+        let neg = x.simd_lt(zero);
+
+        let mut y =
+            polygammaf_pos_simd(&order, neg.select(Simd::splat(1.0) - x, x));
+
+        if neg.any() {
+            let (s, c) = sincospif_simd(x);
+            let c = c / s;
+
+            let d = order.cot_derivative()[..n as usize + 2]
+                .iter()
+                .rev()
+                .fold(zero, |acc, &d| c.mul_add(acc, Simd::splat(d)));
+
+            let y_neg = if n % 2 == 0 { y } else { -y };
+            let y_neg = y_neg
+                - Simd::splat(powif(PI, n + 1)) * d * Simd::splat(order.scale);
+
+            y = neg.select(y_neg, y);
+        }
+
+        y
+    };
+
+    let pole = x.simd_le(zero) & x.simd_eq(x.floor());
+    let y_pole = if n % 2 == 1 {
+        Simd::splat(f32::INFINITY)
+    } else {
+        x.simd_eq(zero).select(
+            -Simd::splat(f32::INFINITY).copysign(x),
+            Simd::splat(f32::NAN),
+        )
+    };
+    let y_pole = x.is_infinite().select(Simd::splat(f32::NAN), y_pole);
+
+    pole.select(y_pole, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{tests::ulp_diff, *};
+
+    // Error bound for negative arguments, where the reflection makes the error
+    // absolute rather than relative around the zeros
+    fn reflected_error(y: f64, y_ref: f64) -> bool {
+        (y - y_ref).abs() <= 2e-15 * y_ref.abs().max(1.0)
+    }
+
+    // The same for f32, against the f64 version at the same argument
+    fn reflected_errorf(y: f32, y_ref: f64) -> bool {
+        (y as f64 - y_ref).abs() <= 1e-6 * y_ref.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_digamma_trigamma() {
+        // (x, digamma(x), trigamma(x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (1e-300, -9.999999999999999e299, f64::INFINITY),
+            (1e-5, -100000.57719921567, 10000000001.644909),
+            (0.25, -4.2274535333762655, 17.19732915450711),
+            (1.0, -0.5772156649015329, 1.6449340668482264),
+            (1.4616321449683622, -9.241265521729427e-17, 0.9676722454476212),
+            (1.5, 0.03648997397857652, 0.9348022005446793),
+            (2.0, 0.42278433509846713, 0.6449340668482264),
+            (3.7, 1.1671535393615113, 0.3100378576700383),
+            (9.99, 2.250700372831201, 0.10527695014824179),
+            (10.0, 2.251752589066721, 0.10516633568168575),
+            (123.4, 4.8113737751162775, 0.008136651610865263),
+            (1e10, 23.025850929890456, 1.00000000005e-10),
+            (1e300, 690.7755278982137, 1e-300),
+            (-0.5, 0.03648997397857652, 8.934802200544679),
+            (-2.25, 4.158583564657972, 19.37941051186914),
+            (-7.9, -7.540007224419479, 103.23693140003948),
+        ];
+
+        for (x, digamma_ref, trigamma_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            if x > 0.0 {
+                assert!(ulp_diff(digamma(x), digamma_ref) <= 3, "{x}");
+                assert!(ulp_diff(trigamma(x), trigamma_ref) <= 3, "{x}");
+            } else {
+                assert!(reflected_error(digamma(x), digamma_ref), "{x}");
+                assert!(reflected_error(trigamma(x), trigamma_ref), "{x}");
+            }
+
+            assert_eq!(digamma_simd(xs)[0], digamma(x), "{x}");
+            assert_eq!(trigamma_simd(xs)[0], trigamma(x), "{x}");
+            assert_eq!(xs.digamma()[0], digamma(x), "{x}");
+            assert_eq!(xs.trigamma()[0], trigamma(x), "{x}");
+            assert_eq!(polygamma(0, x), digamma(x), "{x}");
+            assert_eq!(polygamma(1, x), trigamma(x), "{x}");
+
+            let xf = x as f32;
+            let xfs = Simd::<f32, 4>::splat(xf);
+            let digammaf_ref = digamma(xf as f64);
+            let trigammaf_ref = trigamma(xf as f64);
+
+            if x > 0.0 {
+                let d = digammaf(xf).to_bits() as i64
+                    - (digammaf_ref as f32).to_bits() as i64;
+                let t = trigammaf(xf).to_bits() as i64
+                    - (trigammaf_ref as f32).to_bits() as i64;
+                assert!(d.abs() <= 4, "{x}");
+                assert!(t.abs() <= 4, "{x}");
+            } else {
+                assert!(reflected_errorf(digammaf(xf), digammaf_ref), "{x}");
+                assert!(reflected_errorf(trigammaf(xf), trigammaf_ref), "{x}");
+            }
+
+            assert_eq!(digammaf_simd(xfs)[0], digammaf(xf), "{x}");
+            assert_eq!(trigammaf_simd(xfs)[0], trigammaf(xf), "{x}");
+            assert_eq!(polygammaf(0, xf), digammaf(xf), "{x}");
+            assert_eq!(polygammaf(1, xf), trigammaf(xf), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_polygamma() {
+        // (n, x, polygamma(n, x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (2, 0.5, -16.82879664423432),
+            (2, 3.0, -0.15411380631918858),
+            (3, 1.0, 6.493939402266829),
+            (3, 25.0, 0.0001358846365082737),
+            (4, -1.5, -0.3137559995067314),
+            (5, 0.1, 120000069.30751093),
+            (6, 12.5, -3.970634713243468e-5),
+            (10, 4.0, -0.9519244156357158),
+            (2, -0.3, 67.63908119988317),
+        ];
+
+        for (n, x, y_ref) in table {
+            let xs = Simd::<f64, 4>::splat(x);
+
+            if x > 0.0 {
+                assert!(ulp_diff(polygamma(n, x), y_ref) <= 8, "{n} {x}");
+            } else {
+                assert!(reflected_error(polygamma(n, x), y_ref), "{n} {x}");
+            }
+
+            assert_eq!(polygamma_simd(n, xs)[0], polygamma(n, x), "{n} {x}");
+            assert_eq!(xs.polygamma(n)[0], polygamma(n, x), "{n} {x}");
+
+            let xf = x as f32;
+            let yf = polygammaf(n, xf);
+            let yf_ref = polygamma(n, xf as f64);
+
+            if x > 0.0 {
+                let d = yf.to_bits() as i64 - (yf_ref as f32).to_bits() as i64;
+                assert!(d.abs() <= 8, "{n} {x}");
+            } else {
+                assert!(reflected_errorf(yf, yf_ref), "{n} {x}");
+            }
+
+            assert_eq!(
+                polygammaf_simd(n, Simd::<f32, 4>::splat(xf))[0],
+                yf,
+                "{n} {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_polygamma_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        // (x, polygamma(n, x) for n = 0, ..., 3)
+        #[rustfmt::skip]
+        let table = [
+            (0.0, [-INF, INF, -INF, INF]),
+            (-0.0, [INF, INF, INF, INF]),
+            (-1.0, [NAN, INF, NAN, INF]),
+            (-4.0, [NAN, INF, NAN, INF]),
+            (INF, [INF, 0.0, -0.0, 0.0]),
+            (-INF, [NAN, NAN, NAN, NAN]),
+            (NAN, [NAN, NAN, NAN, NAN]),
+        ];
+
+        for (x, y_ref) in table {
+            for (n, y_ref) in (0..).zip(y_ref) {
+                let y_simd = polygamma_simd(n, Simd::<f64, 4>::splat(x))[0];
+
+                assert_eq!(ulp_diff(polygamma(n, x), y_ref), 0, "{n} {x}");
+                assert_eq!(ulp_diff(y_simd, y_ref), 0, "{n} {x}");
+
+                let xf = x as f32;
+                let yf_simd = polygammaf_simd(n, Simd::<f32, 4>::splat(xf))[0];
+
+                assert_eq!(ulp_diff(polygammaf(n, xf) as f64, y_ref), 0);
+                assert_eq!(ulp_diff(yf_simd as f64, y_ref), 0, "{n} {x}");
+            }
+        }
+
+        assert!(polygamma(-1, 2.0).is_nan());
+        assert!(polygamma_simd(-1, Simd::<f64, 4>::splat(2.0))[0].is_nan());
+    }
+
+    #[test]
+    fn test_polygamma_large_order() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        // (n, x, polygamma(n, x)), correctly rounded
+        #[rustfmt::skip]
+        let table = [
+            (37, -2.7, 1.0188944434949939e63),
+            (100, 1e4, -9.379363201543512e-245),
+            (171, 20.0, 2.073563430060801e85),
+            (171, 200.0, 3.607958931214698e-87),
+            (150, -0.25, INF),
+            // Past POLYGAMMA_MAX_ORDER
+            (172, 200.0, -3.0914370658031885e-87),
+            (200, 2.5, -8.146046727420046e294),
+            (1000, 713.0, -6.090749713156254e-289),
+            (1000000, 367880.0, -0.0015970388032333072),
+            (i32::MAX, 790015084.0, 0.00015038333665628138),
+            (172, -0.5, -7.33700365622151e280),
+            (172, -2.5, -1.6052211731871423e217),
+            (172, 1.0, -INF),
+            (172, -0.3, INF),
+            (172, -0.7, -INF),
+            (172, 5e-324, -INF),
+            (1000, 800.0, -0.0),
+            (1000, 1e300, -0.0),
+            (172, INF, -0.0),
+            (173, 1e300, 0.0),
+            (i32::MAX, 790000000.0, INF),
+            (i32::MAX, -2.5, INF),
+            (i32::MAX, INF, 0.0),
+            (i32::MAX, -3.0, INF),
+            (i32::MAX - 1, -2.5, -INF),
+            (i32::MAX - 1, 0.0, -INF),
+            (i32::MAX - 1, -3.0, NAN),
+            (i32::MAX, NAN, NAN),
+        ];
+
+        for (n, x, y_ref) in table {
+            let y_simd = polygamma_simd(n, Simd::<f64, 4>::splat(x))[0];
+
+            // powi, or exp in log space, loses some accuracy at these orders
+            if y_ref.is_finite() && y_ref != 0.0 {
+                let err = (polygamma(n, x) - y_ref).abs();
+                assert!(err <= 2e-13 * y_ref.abs(), "{n} {x}");
+            } else {
+                assert_eq!(ulp_diff(polygamma(n, x), y_ref), 0, "{n} {x}");
+            }
+            assert_eq!(y_simd.to_bits(), polygamma(n, x).to_bits(), "{n} {x}");
+        }
+
+        // The same in f32, past order 35
+        #[rustfmt::skip]
+        let table = [
+            (35, 20.0, 1.824_522e-7),
+            (35, 139.0, 3.297_88e-37),
+            (36, 20.0, -3.250_739_8e-7),
+            (100, 40.0, -1.583_429_1e-4),
+            (1000, 368.0, -1.662_258_5e-1),
+            (i32::MAX, 790015104.0, 3.685_564_7e-28),
+            (36, -0.5, -1.135_425_3e35),
+            (36, -2.5, -2.754_592e21),
+            (36, 1.0, -f32::INFINITY),
+            (36, -0.3, f32::INFINITY),
+            (1000, 420.0, -0.0),
+            (36, f32::INFINITY, -0.0),
+            (i32::MAX, 1e30, 0.0),
+            (i32::MAX, f32::NAN, f32::NAN),
+        ];
+
+        for (n, x, y_ref) in table {
+            let y = polygammaf(n, x);
+            let y_simd = polygammaf_simd(n, Simd::<f32, 4>::splat(x))[0];
+
+            if y_ref.is_finite() && y_ref != 0.0 {
+                assert!((y - y_ref).abs() <= 8e-6 * y_ref.abs(), "{n} {x}");
+            } else {
+                assert_eq!(ulp_diff(y as f64, y_ref as f64), 0, "{n} {x}");
+            }
+            assert_eq!(y_simd.to_bits(), y.to_bits(), "{n} {x}");
+        }
+    }
+}
@@ -0,0 +1,696 @@
+use std::{
+    f64::consts::LOG2_E,
+    simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount},
+};
+
+use crate::{
+    exp::{
+        exp_saturate_simd, exp_scaled_extended, exp_scaled_extended_simd,
+        expf_extended, expf_extended_simd, EXPF_MAX, EXPF_MIN, EXP_MAX,
+        EXP_MIN, LN2_HI, LN2_HI_F32, LN2_LO, LN2_LO_F32,
+    },
+    log::{ln_reduce, ln_reduce_simd, lnf_reduce, lnf_reduce_simd},
+    polyval, polyval_simd, polyvalf, polyvalf_simd,
+};
+
+// f(z) = (2 atanh(s) - 2 s - 2 s^3 / 3) / s^5, z = s^2, s = x / (2 + x)
+// domain: x in [sqrt(1/2) - 1, sqrt(2) - 1], i.e. z in [0, 0.0295]
+const MINIMAX: [f64; 8] = [
+    0.11724564479969542,
+    0.11706101997413179,
+    0.13334809789246505,
+    0.1538459486712996,
+    0.18181818336304575,
+    0.2222222222165176,
+    0.28571428571429375,
+    0.4,
+];
+
+// 2 / 3 as a double-double
+const TWO_THIRDS_HI: f64 = 0.6666666666666666;
+const TWO_THIRDS_LO: f64 = 3.700743415417188e-17;
+
+// ln(x) as an unevaluated sum hi + lo, accurate to about 2^-64 relative, for
+// positive finite x. The extra bits are needed because pow multiplies the
+// error in ln(x) by y.
+fn ln_extended(x: f64) -> (f64, f64) {
+    let (n, f) = ln_reduce(x);
+
+    // s = f / (2 + f) as a double-double
+    let d = 2.0 + f;
+    let d_lo = f - (d - 2.0);
+    let s = f / d;
+    let s_lo = (s.mul_add(-d, f) - s * d_lo) / d;
+
+    // 2 s^3 / 3 as a double-double
+    let z = s * s;
+    let z_lo = s.mul_add(s, -z);
+    let s3 = z * s;
+    let s3_lo = z.mul_add(s, -s3) + z_lo * s;
+    let t = s3 * TWO_THIRDS_HI;
+    let t_lo = s3.mul_add(TWO_THIRDS_HI, -t)
+        + s3_lo.mul_add(TWO_THIRDS_HI, s3 * TWO_THIRDS_LO);
+
+    // ln(1 + f) = 2 s + 2 s^3 / 3 + s^5 P(s^2), where s^5 needs its own low
+    // part. The low part of s enters through the derivative
+    // 2 / (1 - s^2) = 2 (1 + z + z^2 + ...).
+    let s5 = s3 * z;
+    let s5_lo = s3.mul_add(z, -s5) + s3_lo.mul_add(z, s3 * z_lo);
+    let poly = polyval(&MINIMAX, z);
+    let tail = s5.mul_add(
+        poly,
+        s5_lo.mul_add(poly, 2.0 * s_lo.mul_add(z.mul_add(z, z), s_lo)),
+    );
+
+    let r = 2.0 * s + t;
+    let r_lo = (2.0 * s - r) + t + (t_lo + tail);
+
+    // n ln(2), where n * LN2_HI is exact
+    let a = n * LN2_HI;
+    let b = n * LN2_LO;
+    let b_lo = n.mul_add(LN2_LO, -b);
+
+    let h = a + r;
+    let e = (a - (h - (h - a))) + (r - (h - a));
+    let lo = e + (b + (r_lo + b_lo));
+
+    let hi = h + lo;
+    (hi, lo - (hi - h))
+}
+
+// x^y for positive finite x and finite y. Both halves are carried in
+// double-double, so the result is within about 0.57 ulp and correctly rounded
+// in all but a few percent of cases.
+fn pow_positive(x: f64, y: f64) -> f64 {
+    let (l, l_lo) = ln_extended(x);
+
+    let p = y * l;
+    let p_lo = y.mul_add(l, -p) + y * l_lo;
+
+    if p > EXP_MAX {
+        return f64::INFINITY;
+    } else if p < EXP_MIN {
+        return 0.0;
+    }
+
+    // Same reduction as exp, but u = p + p_lo - k ln(2) is kept as hi + lo.
+    // p - k * LN2_HI is exact.
+    let k = (p * LOG2_E).round();
+    let a = k.mul_add(-LN2_HI, p);
+    let b = k.mul_add(-LN2_LO, p_lo);
+    let u = a + b;
+    let u_lo = (a - u) + b;
+
+    exp_scaled_extended(u, u_lo, k)
+}
+
+/// Computes `x^y`, with the special cases of C99 Annex F.
+pub fn pow(x: f64, y: f64) -> f64 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    } else if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    let ax = x.abs();
+
+    if y.is_infinite() {
+        return if ax == 1.0 {
+            1.0
+        } else if (ax < 1.0) == (y < 0.0) {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+    }
+
+    let y_int = y.round() == y;
+    let y_odd = y_int && (0.5 * y).round() != 0.5 * y;
+
+    if x < 0.0 && x.is_finite() && !y_int {
+        return f64::NAN;
+    }
+
+    let r = if ax == 0.0 || ax == f64::INFINITY {
+        if (ax == 0.0) == (y < 0.0) {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    } else {
+        pow_positive(ax, y)
+    };
+
+    if y_odd && x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+#[inline(always)]
+fn ln_extended_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let two = Simd::splat(2.0);
+
+    let (n, f) = ln_reduce_simd(x);
+
+    let d = two + f;
+    let d_lo = f - (d - two);
+    let s = f / d;
+    let s_lo = (s.mul_add(-d, f) - s * d_lo) / d;
+
+    let z = s * s;
+    let z_lo = s.mul_add(s, -z);
+    let s3 = z * s;
+    let s3_lo = z.mul_add(s, -s3) + z_lo * s;
+    let t = s3 * Simd::splat(TWO_THIRDS_HI);
+    let t_lo = s3.mul_add(Simd::splat(TWO_THIRDS_HI), -t)
+        + s3_lo.mul_add(
+            Simd::splat(TWO_THIRDS_HI),
+            s3 * Simd::splat(TWO_THIRDS_LO),
+        );
+
+    let s5 = s3 * z;
+    let s5_lo = s3.mul_add(z, -s5) + s3_lo.mul_add(z, s3 * z_lo);
+    let poly = polyval_simd(&MINIMAX, z);
+    let tail = s5.mul_add(
+        poly,
+        s5_lo.mul_add(poly, two * s_lo.mul_add(z.mul_add(z, z), s_lo)),
+    );
+
+    let r = two * s + t;
+    let r_lo = (two * s - r) + t + (t_lo + tail);
+
+    let a = n * Simd::splat(LN2_HI);
+    let b = n * Simd::splat(LN2_LO);
+    let b_lo = n.mul_add(Simd::splat(LN2_LO), -b);
+
+    let h = a + r;
+    let e = (a - (h - (h - a))) + (r - (h - a));
+    let lo = e + (b + (r_lo + b_lo));
+
+    let hi = h + lo;
+    (hi, lo - (hi - h))
+}
+
+/// The SIMD version of [`pow`].
+#[inline(always)]
+pub fn pow_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    y: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let one = Simd::splat(1.0);
+    let inf = Simd::splat(f64::INFINITY);
+    let nan = Simd::splat(f64::NAN);
+
+    let ax = x.abs();
+
+    let (l, l_lo) = ln_extended_simd(ax);
+
+    let p = y * l;
+    let p_lo = y.mul_add(l, -p) + y * l_lo;
+
+    // Clamping keeps k small enough for the exponent arithmetic in
+    // exp_scaled_extended_simd. Lanes outside the range are patched up below.
+    let pc = p.simd_clamp(Simd::splat(EXP_MIN), Simd::splat(EXP_MAX));
+
+    let k = (pc * Simd::splat(LOG2_E)).round();
+    let a = k.mul_add(Simd::splat(-LN2_HI), pc);
+    let b = k.mul_add(Simd::splat(-LN2_LO), p_lo);
+    let u = a + b;
+    let u_lo = (a - u) + b;
+
+    let r = exp_saturate_simd(
+        p,
+        exp_scaled_extended_simd(u, u_lo, k),
+        EXP_MIN,
+        EXP_MAX,
+    );
+
+    // x = 0 and x = inf
+    let ax_zero = ax.simd_eq(zero);
+    let r = (ax_zero | ax.simd_eq(inf))
+        .select((ax_zero ^ y.simd_lt(zero)).select(zero, inf), r);
+
+    let y_int = y.round().simd_eq(y);
+    let y_half = Simd::splat(0.5) * y;
+    let y_odd = y_int & y_half.round().simd_ne(y_half);
+
+    let r = (y_odd & x.is_sign_negative()).select(-r, r);
+    let r = (x.simd_lt(zero) & x.is_finite() & !y_int).select(nan, r);
+
+    // y = +-inf
+    let r = y.is_infinite().select(
+        ax.simd_eq(one)
+            .select(one, (ax.simd_lt(one) ^ y.simd_lt(zero)).select(zero, inf)),
+        r,
+    );
+
+    let r = (x.is_nan() | y.is_nan()).select(nan, r);
+
+    (y.simd_eq(zero) | x.simd_eq(one)).select(one, r)
+}
+
+// f(z) = (2 atanh(s) - 2 s - 2 s^3 / 3) / s^5, z = s^2, s = x / (2 + x)
+// domain: x in [sqrt(1/2) - 1, sqrt(2) - 1], i.e. z in [0, 0.0295]
+const MINIMAX_F32: [f32; 4] =
+    [1.912_035_8e-1, 2.220_476_6e-1, 2.857_153e-1, 4.0e-1];
+
+// 2 / 3 as a sum of two f32
+const TWO_THIRDS_HI_F32: f32 = 6.666_667e-1;
+const TWO_THIRDS_LO_F32: f32 = -1.986_821_6e-8;
+
+// ln(x) as an unevaluated sum hi + lo, accurate to about 2^-36 relative, for
+// positive finite x. Same steps as ln_extended.
+pub(crate) fn lnf_extended(x: f32) -> (f32, f32) {
+    let (n, f) = lnf_reduce(x);
+
+    let d = 2.0 + f;
+    let d_lo = f - (d - 2.0);
+    let s = f / d;
+    let s_lo = (s.mul_add(-d, f) - s * d_lo) / d;
+
+    let z = s * s;
+    let z_lo = s.mul_add(s, -z);
+    let s3 = z * s;
+    let s3_lo = z.mul_add(s, -s3) + z_lo * s;
+    let t = s3 * TWO_THIRDS_HI_F32;
+    let t_lo = s3.mul_add(TWO_THIRDS_HI_F32, -t)
+        + s3_lo.mul_add(TWO_THIRDS_HI_F32, s3 * TWO_THIRDS_LO_F32);
+
+    let s5 = s3 * z;
+    let tail =
+        s5.mul_add(polyvalf(&MINIMAX_F32, z), 2.0 * s_lo.mul_add(z, s_lo));
+
+    let r = 2.0 * s + t;
+    let r_lo = (2.0 * s - r) + t + (t_lo + tail);
+
+    let a = n * LN2_HI_F32;
+    let b = n * LN2_LO_F32;
+    let b_lo = n.mul_add(LN2_LO_F32, -b);
+
+    let h = a + r;
+    let e = (a - (h - (h - a))) + (r - (h - a));
+    let lo = e + (b + (r_lo + b_lo));
+
+    let hi = h + lo;
+    (hi, lo - (hi - h))
+}
+
+// x^y for positive finite x and finite y, the same way as pow_positive
+fn powf_positive(x: f32, y: f32) -> f32 {
+    let (l, l_lo) = lnf_extended(x);
+
+    let p = y * l;
+    let p_lo = y.mul_add(l, -p) + y * l_lo;
+
+    // Past these bounds the scaling in expf_extended overflows to inf
+    // or underflows to 0 by itself
+    if p > EXPF_MAX {
+        return f32::INFINITY;
+    } else if p < EXPF_MIN {
+        return 0.0;
+    }
+
+    expf_extended(p, p_lo)
+}
+
+/// The `f32` version of [`pow`].
+pub fn powf(x: f32, y: f32) -> f32 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    } else if x.is_nan() || y.is_nan() {
+        return f32::NAN;
+    }
+
+    let ax = x.abs();
+
+    if y.is_infinite() {
+        return if ax == 1.0 {
+            1.0
+        } else if (ax < 1.0) == (y < 0.0) {
+            f32::INFINITY
+        } else {
+            0.0
+        };
+    }
+
+    let y_int = y.round() == y;
+    let y_odd = y_int && (0.5 * y).round() != 0.5 * y;
+
+    if x < 0.0 && x.is_finite() && !y_int {
+        return f32::NAN;
+    }
+
+    let r = if ax == 0.0 || ax == f32::INFINITY {
+        if (ax == 0.0) == (y < 0.0) {
+            f32::INFINITY
+        } else {
+            0.0
+        }
+    } else {
+        powf_positive(ax, y)
+    };
+
+    if y_odd && x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+#[inline(always)]
+pub(crate) fn lnf_extended_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let two = Simd::splat(2.0);
+
+    let (n, f) = lnf_reduce_simd(x);
+
+    let d = two + f;
+    let d_lo = f - (d - two);
+    let s = f / d;
+    let s_lo = (s.mul_add(-d, f) - s * d_lo) / d;
+
+    let z = s * s;
+    let z_lo = s.mul_add(s, -z);
+    let s3 = z * s;
+    let s3_lo = z.mul_add(s, -s3) + z_lo * s;
+    let t = s3 * Simd::splat(TWO_THIRDS_HI_F32);
+    let t_lo = s3.mul_add(Simd::splat(TWO_THIRDS_HI_F32), -t)
+        + s3_lo.mul_add(
+            Simd::splat(TWO_THIRDS_HI_F32),
+            s3 * Simd::splat(TWO_THIRDS_LO_F32),
+        );
+
+    let s5 = s3 * z;
+    let tail =
+        s5.mul_add(polyvalf_simd(&MINIMAX_F32, z), two * s_lo.mul_add(z, s_lo));
+
+    let r = two * s + t;
+    let r_lo = (two * s - r) + t + (t_lo + tail);
+
+    let a = n * Simd::splat(LN2_HI_F32);
+    let b = n * Simd::splat(LN2_LO_F32);
+    let b_lo = n.mul_add(Simd::splat(LN2_LO_F32), -b);
+
+    let h = a + r;
+    let e = (a - (h - (h - a))) + (r - (h - a));
+    let lo = e + (b + (r_lo + b_lo));
+
+    let hi = h + lo;
+    (hi, lo - (hi - h))
+}
+
+/// The SIMD version of [`powf`].
+#[inline(always)]
+pub fn powf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    y: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0.0);
+    let one = Simd::splat(1.0);
+    let inf = Simd::splat(f32::INFINITY);
+    let nan = Simd::splat(f32::NAN);
+
+    let ax = x.abs();
+
+    let (l, l_lo) = lnf_extended_simd(ax);
+
+    let p = y * l;
+    let p_lo = y.mul_add(l, -p) + y * l_lo;
+
+    // Clamped lanes overflow or underflow in expf_extended_simd, as
+    // long as their low part does not turn into NaN
+    let pc = p.simd_clamp(Simd::splat(EXPF_MIN), Simd::splat(EXPF_MAX));
+    let p_lo = pc.simd_eq(p).select(p_lo, zero);
+
+    let r = expf_extended_simd(pc, p_lo);
+
+    // x = 0 and x = inf
+    let ax_zero = ax.simd_eq(zero);
+    let r = (ax_zero | ax.simd_eq(inf))
+        .select((ax_zero ^ y.simd_lt(zero)).select(zero, inf), r);
+
+    let y_int = y.round().simd_eq(y);
+    let y_half = Simd::splat(0.5) * y;
+    let y_odd = y_int & y_half.round().simd_ne(y_half);
+
+    let r = (y_odd & x.is_sign_negative()).select(-r, r);
+    let r = (x.simd_lt(zero) & x.is_finite() & !y_int).select(nan, r);
+
+    // y = +-inf
+    let r = y.is_infinite().select(
+        ax.simd_eq(one)
+            .select(one, (ax.simd_lt(one) ^ y.simd_lt(zero)).select(zero, inf)),
+        r,
+    );
+
+    let r = (x.is_nan() | y.is_nan()).select(nan, r);
+
+    (y.simd_eq(zero) | x.simd_eq(one)).select(one, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, speed_test_simd_iterated, ulp_diff,
+        },
+        *,
+    };
+
+    const X: [f64; 8] = [
+        5.155388558913315,
+        1963.561314768797,
+        18138.072812963892,
+        0.005506141006060214,
+        0.8485974262673789,
+        3236.7191093391725,
+        0.5895235440367635,
+        16.565388066382837,
+    ];
+
+    const Y: f64 = -2.6;
+
+    #[test]
+    fn test_pow() {
+        accuracy_test(&X, |x| x.powf(Y), |x| pow(x, Y));
+    }
+
+    #[test]
+    fn test_pow_simd() {
+        let y = Simd::splat(Y);
+
+        accuracy_test_simd(X, |x| x.powf(Y), |x| x.pow(y));
+    }
+
+    const X_F32: [f32; 8] = [
+        5.1553884,
+        1963.5613,
+        18138.074,
+        0.005506141,
+        0.8485974,
+        3236.719,
+        0.58952355,
+        16.565388,
+    ];
+
+    #[test]
+    fn test_powf() {
+        let y = Y as f32;
+
+        accuracy_testf(&X_F32, |x| x.powf(y), |x| powf(x, y));
+        accuracy_test_simdf(X_F32, |x| x.powf(y), |x| x.pow(Simd::splat(y)));
+    }
+
+    #[test]
+    fn test_pow_ulp() {
+        const N: usize = 1000;
+
+        // Includes |y ln(x)| close to the overflow threshold, where a plain
+        // exp(y * ln(x)) is off by hundreds of ulps
+        for i in 0..N {
+            let x = 2f64.powf(-20.0 + 40.0 * (i as f64 + 0.5) / N as f64);
+
+            for j in 0..N {
+                let y = -700.0 + 1400.0 * (j as f64 + 0.5) / N as f64;
+                let y = y / x.ln().abs().max(1.0);
+                let y_std = x.powf(y);
+
+                assert!(ulp_diff(pow(x, y), y_std) <= 1, "{x} {y}");
+                assert!(
+                    ulp_diff(
+                        Simd::<f64, 4>::splat(x).pow(Simd::splat(y))[0],
+                        y_std
+                    ) <= 1,
+                    "{x} {y}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_powf_ulp() {
+        const N: usize = 1000;
+
+        for i in 0..N {
+            let x = 2f32.powf(-20.0 + 40.0 * (i as f32 + 0.5) / N as f32);
+
+            for j in 0..N {
+                let y = -100.0 + 200.0 * (j as f32 + 0.5) / N as f32;
+                let y = y / x.ln().abs().max(1.0);
+                let y_std = (x as f64).powf(y as f64) as f32;
+                let y_simd = Simd::<f32, 4>::splat(x).pow(Simd::splat(y))[0];
+
+                // Both are positive, so their bits are ordered like them
+                let diff = powf(x, y).to_bits().abs_diff(y_std.to_bits());
+                assert!(diff <= 1, "{x} {y}");
+                assert_eq!(y_simd, powf(x, y), "{x} {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_integer_exponent() {
+        for x in [-3.0f64, -2.0, -1.5, -0.5, 0.5, 1.5, 2.0, 3.0] {
+            for n in -30..=30 {
+                let y_std = x.powi(n);
+                let y = n as f64;
+
+                assert!(ulp_diff(pow(x, y), y_std) <= 1, "{x} {y}");
+                assert!(
+                    ulp_diff(
+                        Simd::<f64, 4>::splat(x).pow(Simd::splat(y))[0],
+                        y_std
+                    ) <= 1,
+                    "{x} {y}"
+                );
+            }
+        }
+
+        assert_eq!(pow(-2.0, 3.0), -8.0);
+        assert_eq!(pow(-2.0, 4.0), 16.0);
+        assert_eq!(pow(2.0, -1074.0), f64::from_bits(1));
+        assert_eq!(pow(2.0, 1023.0), 2f64.powi(1023));
+    }
+
+    #[test]
+    fn test_pow_correctly_rounded() {
+        let table = [
+            (2.0, 0.5, std::f64::consts::SQRT_2),
+            (7.0, 0.5, 7f64.sqrt()),
+            (3.0, -1.0, 1.0 / 3.0),
+            (10.0, -1.0, 0.1),
+            (1.0 / 3.0, -2.0, 9.000000000000002),
+            (1.0 / 7.0, -1.0, 7.0),
+            (2.0, -0.5, std::f64::consts::FRAC_1_SQRT_2),
+            (1e10, 0.5, 1e5),
+        ];
+
+        for (x, y, z) in table {
+            let z_simd = Simd::<f64, 4>::splat(x).pow(Simd::splat(y))[0];
+
+            assert_eq!(pow(x, y), z, "pow({x}, {y})");
+            assert_eq!(z_simd, z, "pow({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn test_pow_special_values() {
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        #[rustfmt::skip]
+        let table: [(f64, f64, f64); 40] = [
+            // pow(x, +-0) = 1 and pow(1, y) = 1, even for NaN
+            (NAN, 0.0, 1.0),
+            (NAN, -0.0, 1.0),
+            (-3.0, 0.0, 1.0),
+            (1.0, NAN, 1.0),
+            (1.0, -INF, 1.0),
+            (-1.0, INF, 1.0),
+            (-1.0, -INF, 1.0),
+            // NaN otherwise
+            (NAN, 1.0, NAN),
+            (2.0, NAN, NAN),
+            // Negative base with non-integer exponent
+            (-8.0, 1.0 / 3.0, NAN),
+            (-2.0, 0.5, NAN),
+            (-INF, 0.5, INF),
+            // Negative base with integer exponent
+            (-2.0, 3.0, -8.0),
+            (-2.0, -3.0, -0.125),
+            (-2.0, 2.0, 4.0),
+            (-1.0, 1e300, 1.0),
+            (-2.0, 1e300, INF),
+            // Zero base
+            (0.0, -3.0, INF),
+            (-0.0, -3.0, -INF),
+            (-0.0, -2.0, INF),
+            (-0.0, -0.5, INF),
+            (0.0, 3.0, 0.0),
+            (-0.0, 3.0, -0.0),
+            (-0.0, 2.0, 0.0),
+            (-0.0, -INF, INF),
+            (-0.0, INF, 0.0),
+            // Infinite exponent
+            (0.5, -INF, INF),
+            (-0.5, INF, 0.0),
+            (2.0, -INF, 0.0),
+            (-2.0, INF, INF),
+            // Infinite base
+            (-INF, -3.0, -0.0),
+            (-INF, -2.0, 0.0),
+            (-INF, 3.0, -INF),
+            (-INF, 2.0, INF),
+            (INF, -0.5, 0.0),
+            (INF, 0.5, INF),
+            // Overflow and underflow
+            (10.0, 400.0, INF),
+            (-10.0, 401.0, -INF),
+            (10.0, -400.0, 0.0),
+            (-10.0, -401.0, -0.0),
+        ];
+
+        for (x, y, z) in table {
+            let z_simd = Simd::<f64, 4>::splat(x).pow(Simd::splat(y))[0];
+
+            assert_eq!(ulp_diff(pow(x, y), z), 0, "pow({x}, {y})");
+            assert_eq!(ulp_diff(z_simd, z), 0, "pow({x}, {y})");
+
+            let (x, y, z) = (x as f32, y as f32, z as f32);
+            let z_simd = Simd::<f32, 4>::splat(x).pow(Simd::splat(y))[0];
+
+            assert_eq!(ulp_diff(powf(x, y) as f64, z as f64), 0, "{x} {y}");
+            assert_eq!(ulp_diff(z_simd as f64, z as f64), 0, "{x} {y}");
+        }
+    }
+
+    #[test]
+    fn test_pow_simd_speed() {
+        const ITERS: usize = 1000000;
+
+        let y = Simd::splat(1.0001);
+
+        speed_test_simd_iterated(X, |x| x.powf(1.0001), |x| x.pow(y), ITERS);
+    }
+}
@@ -0,0 +1,636 @@
+use std::simd::{prelude::*, LaneCount, Simd, StdFloat, SupportedLaneCount};
+
+use crate::{
+    exp::{expf_extended, expf_extended_simd, pow2_split, pow2_split_simd},
+    exp2, exp2_simd, log2, log2_simd,
+    pow::{lnf_extended, lnf_extended_simd},
+    powi_pos, powi_simd_pos,
+};
+
+// Dividing the bits of x by 3 divides the exponent by 3. Adding this puts the
+// bias back, and is tuned so that the initial guess is within 1/32 of cbrt(x).
+const CBRT_BIAS: u64 = 0x2a9f789300000000;
+
+// 2^54 and its cube root, used to renormalise subnormal inputs
+const SUBNORMAL_SCALE: f64 = 18_014_398_509_481_984.0;
+const SUBNORMAL_SCALE_CBRT_INV: f64 = 3.814697265625e-6;
+
+// cbrt(x) for positive normal x
+fn cbrt_positive(x: f64) -> f64 {
+    let t = f64::from_bits(x.to_bits() / 3 + CBRT_BIAS);
+
+    // Each Newton step squares the relative error:
+    // 2^-5 -> 2^-10 -> 2^-20 -> 2^-40
+    let t = (2.0 * t + x / (t * t)) * (1.0 / 3.0);
+    let t = (2.0 * t + x / (t * t)) * (1.0 / 3.0);
+    let t = (2.0 * t + x / (t * t)) * (1.0 / 3.0);
+
+    // Last step with the residual x - t^3 evaluated exactly
+    let t2 = t * t;
+    let t2_lo = t.mul_add(t, -t2);
+    let r = (-t2).mul_add(t, x) - t2_lo * t;
+
+    t + r / (3.0 * t2)
+}
+
+/// Computes the cube root, negative for negative `x`.
+pub fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || !x.is_finite() {
+        return x + x;
+    }
+
+    let ax = x.abs();
+
+    let y = if ax < f64::MIN_POSITIVE {
+        cbrt_positive(ax * SUBNORMAL_SCALE) * SUBNORMAL_SCALE_CBRT_INV
+    } else {
+        cbrt_positive(ax)
+    };
+
+    y.copysign(x)
+}
+
+// x^(1/n) for positive finite x and n > 0
+fn root_n_positive(x: f64, n: u32) -> f64 {
+    let nf = n as f64;
+
+    // x = 2^(n k) m with m^(1/n) in (1/2, 2) and m between 1 and x, so that
+    // m stays in range for any n. For large n the reduction does nothing.
+    let k = (log2(x) / nf).trunc();
+    let (fac1, fac2) = pow2_split(-nf * k);
+    let m = x * fac1 * fac2;
+
+    let y = exp2(log2(m) / nf);
+
+    // One Newton step removes the error from log2 and exp2. y^n ~ m is taken
+    // in two halves of about sqrt(m) each, so neither overflows for m near
+    // f64::MAX nor underflows for subnormal m.
+    let r = m / powi_pos(y, n - n / 2) / powi_pos(y, n / 2);
+    let y = y.mul_add((r - 1.0) / nf, y);
+
+    let (fac1, fac2) = pow2_split(k);
+    y * fac1 * fac2
+}
+
+/// Computes `x^(1 / n)`, defined for negative `x` when `n` is odd.
+/// Returns NaN for `n = 0` and for negative `x` with even `n`.
+pub fn root_n(x: f64, n: i32) -> f64 {
+    if n == 0 || (x < 0.0 && n % 2 == 0) {
+        return f64::NAN;
+    }
+
+    let y = if x == 0.0 || !x.is_finite() {
+        x
+    } else {
+        root_n_positive(x.abs(), n.unsigned_abs()).copysign(x)
+    };
+
+    if n < 0 {
+        y.recip()
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+fn cbrt_positive_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let two = Simd::splat(2.0);
+    let third = Simd::splat(1.0 / 3.0);
+
+    let t = Simd::<f64, LANES>::from_bits(
+        x.to_bits() / Simd::splat(3) + Simd::splat(CBRT_BIAS),
+    );
+
+    let t = (two * t + x / (t * t)) * third;
+    let t = (two * t + x / (t * t)) * third;
+    let t = (two * t + x / (t * t)) * third;
+
+    let t2 = t * t;
+    let t2_lo = t.mul_add(t, -t2);
+    let r = (-t2).mul_add(t, x) - t2_lo * t;
+
+    t + r / (Simd::splat(3.0) * t2)
+}
+
+/// The SIMD version of [`cbrt`].
+#[inline(always)]
+pub fn cbrt_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let ax = x.abs();
+
+    let subnormal = ax.simd_lt(Simd::splat(f64::MIN_POSITIVE));
+    let axs = subnormal.select(ax * Simd::splat(SUBNORMAL_SCALE), ax);
+
+    let y = cbrt_positive_simd(axs);
+    let y = subnormal.select(y * Simd::splat(SUBNORMAL_SCALE_CBRT_INV), y);
+
+    // Zero, infinity and NaN are their own cube roots
+    (x.simd_eq(Simd::splat(0.0)) | !x.is_finite()).select(x + x, y.copysign(x))
+}
+
+#[inline(always)]
+fn root_n_positive_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    n: u32,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let nf = Simd::splat(n as f64);
+
+    let k = (log2_simd(x) / nf).trunc();
+    let (fac1, fac2) = pow2_split_simd(-nf * k);
+    let m = x * fac1 * fac2;
+
+    let y = exp2_simd(log2_simd(m) / nf);
+
+    let r = m
+        / powi_simd_pos(y, Simd::splat(n - n / 2))
+        / powi_simd_pos(y, Simd::splat(n / 2));
+    let y = y.mul_add((r - Simd::splat(1.0)) / nf, y);
+
+    let (fac1, fac2) = pow2_split_simd(k);
+    y * fac1 * fac2
+}
+
+/// The SIMD version of [`root_n`].
+#[inline(always)]
+pub fn root_n_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    n: i32,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if n == 0 {
+        return Simd::splat(f64::NAN);
+    }
+
+    let y = root_n_positive_simd(x.abs(), n.unsigned_abs()).copysign(x);
+
+    let y = (x.simd_eq(Simd::splat(0.0)) | !x.is_finite()).select(x, y);
+
+    let y = if n % 2 == 0 {
+        x.simd_lt(Simd::splat(0.0)).select(Simd::splat(f64::NAN), y)
+    } else {
+        y
+    };
+
+    if n < 0 {
+        y.recip()
+    } else {
+        y
+    }
+}
+
+// Same as CBRT_BIAS, for the bits of an f32
+const CBRT_BIAS_F32: u32 = 0x2a5119f2;
+
+// 2^24 and its cube root, used to renormalise subnormal inputs
+const SUBNORMAL_SCALE_F32: f32 = 16_777_216.0;
+const SUBNORMAL_SCALE_CBRT_INV_F32: f32 = 3.906_25e-3;
+
+// cbrt(x) for positive normal x
+fn cbrtf_positive(x: f32) -> f32 {
+    let t = f32::from_bits(x.to_bits() / 3 + CBRT_BIAS_F32);
+
+    // 2^-5 -> 2^-10 -> 2^-20, and the last step to 2^-40
+    let t = (2.0 * t + x / (t * t)) * (1.0 / 3.0);
+    let t = (2.0 * t + x / (t * t)) * (1.0 / 3.0);
+
+    let t2 = t * t;
+    let t2_lo = t.mul_add(t, -t2);
+    let r = (-t2).mul_add(t, x) - t2_lo * t;
+
+    t + r / (3.0 * t2)
+}
+
+/// The `f32` version of [`cbrt`].
+pub fn cbrtf(x: f32) -> f32 {
+    if x == 0.0 || !x.is_finite() {
+        return x + x;
+    }
+
+    let ax = x.abs();
+
+    let y = if ax < f32::MIN_POSITIVE {
+        cbrtf_positive(ax * SUBNORMAL_SCALE_F32) * SUBNORMAL_SCALE_CBRT_INV_F32
+    } else {
+        cbrtf_positive(ax)
+    };
+
+    y.copysign(x)
+}
+
+// x^(1/n) for positive finite x and n > 0, as exp(ln(x) / n) with both
+// halves carried in two parts as in powf
+fn root_nf_positive(x: f32, n: u32) -> f32 {
+    let nf = n as f32;
+
+    let (l, l_lo) = lnf_extended(x);
+
+    let p = l / nf;
+    let p_lo = (p.mul_add(-nf, l) + l_lo) / nf;
+
+    expf_extended(p, p_lo)
+}
+
+/// The `f32` version of [`root_n`].
+pub fn root_nf(x: f32, n: i32) -> f32 {
+    if n == 0 || (x < 0.0 && n % 2 == 0) {
+        return f32::NAN;
+    }
+
+    let y = if x == 0.0 || !x.is_finite() {
+        x
+    } else {
+        root_nf_positive(x.abs(), n.unsigned_abs()).copysign(x)
+    };
+
+    if n < 0 {
+        y.recip()
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+fn cbrtf_positive_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let two = Simd::splat(2.0);
+    let third = Simd::splat(1.0 / 3.0);
+
+    let t = Simd::<f32, LANES>::from_bits(
+        x.to_bits() / Simd::splat(3) + Simd::splat(CBRT_BIAS_F32),
+    );
+
+    let t = (two * t + x / (t * t)) * third;
+    let t = (two * t + x / (t * t)) * third;
+
+    let t2 = t * t;
+    let t2_lo = t.mul_add(t, -t2);
+    let r = (-t2).mul_add(t, x) - t2_lo * t;
+
+    t + r / (Simd::splat(3.0) * t2)
+}
+
+/// The SIMD version of [`cbrtf`].
+#[inline(always)]
+pub fn cbrtf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let ax = x.abs();
+
+    let subnormal = ax.simd_lt(Simd::splat(f32::MIN_POSITIVE));
+    let axs = subnormal.select(ax * Simd::splat(SUBNORMAL_SCALE_F32), ax);
+
+    let y = cbrtf_positive_simd(axs);
+    let y = subnormal.select(y * Simd::splat(SUBNORMAL_SCALE_CBRT_INV_F32), y);
+
+    (x.simd_eq(Simd::splat(0.0)) | !x.is_finite()).select(x + x, y.copysign(x))
+}
+
+#[inline(always)]
+fn root_nf_positive_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    n: u32,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let nf = Simd::splat(n as f32);
+
+    let (l, l_lo) = lnf_extended_simd(x);
+
+    let p = l / nf;
+    let p_lo = (p.mul_add(-nf, l) + l_lo) / nf;
+
+    expf_extended_simd(p, p_lo)
+}
+
+/// The SIMD version of [`root_nf`].
+#[inline(always)]
+pub fn root_nf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    n: i32,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if n == 0 {
+        return Simd::splat(f32::NAN);
+    }
+
+    let y = root_nf_positive_simd(x.abs(), n.unsigned_abs()).copysign(x);
+
+    let y = (x.simd_eq(Simd::splat(0.0)) | !x.is_finite()).select(x, y);
+
+    let y = if n % 2 == 0 {
+        x.simd_lt(Simd::splat(0.0)).select(Simd::splat(f32::NAN), y)
+    } else {
+        y
+    };
+
+    if n < 0 {
+        y.recip()
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::simd::Simd;
+
+    use crate::{
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, speed_test_simd_iterated, ulp_diff,
+        },
+        *,
+    };
+
+    const X: [f64; 8] = [
+        -6.470329170669899,
+        7.608185328297425,
+        3.03226005318477,
+        1.6990497408119154,
+        -5.422265238742455,
+        -3.9968940734442704,
+        5.683523314814294,
+        -4.318069330898973,
+    ];
+
+    #[test]
+    fn test_cbrt() {
+        accuracy_test(&X, |x| x.cbrt(), cbrt);
+    }
+
+    #[test]
+    fn test_cbrt_simd() {
+        accuracy_test_simd(X, |x| x.cbrt(), |x| x.cbrt());
+    }
+
+    const X_F32: [f32; 8] = [
+        -6.470329, 7.6081853, 3.03226, 1.6990497, -5.4222655, -3.996894,
+        5.683523, -4.3180695,
+    ];
+
+    #[test]
+    fn test_cbrtf() {
+        accuracy_testf(&X_F32, |x| x.cbrt(), cbrtf);
+        accuracy_test_simdf(X_F32, |x| x.cbrt(), |x| x.cbrt());
+    }
+
+    #[test]
+    fn test_cbrt_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let t = (i as f64 + 0.5) / N as f64;
+
+            for x in [
+                2f64.powf(-1074.0 + 2098.0 * t),
+                -(2f64.powf(-3.0 + 6.0 * t)),
+            ] {
+                let y_std = x.cbrt();
+
+                assert!(ulp_diff(cbrt(x), y_std) <= 1, "{x}");
+                assert!(
+                    ulp_diff(Simd::<f64, 4>::splat(x).cbrt()[0], y_std) <= 1,
+                    "{x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cbrt_special_values() {
+        let x = [
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -27.0,
+            f64::from_bits(1),
+            -8.0,
+        ];
+        let y = [
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -3.0,
+            f64::from_bits(1).cbrt(),
+            -2.0,
+        ];
+
+        let y_simd = Simd::from(x).cbrt();
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(cbrt(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y_simd[i], y[i]), 0, "{}", x[i]);
+        }
+    }
+
+    #[test]
+    fn test_root_n() {
+        for n in [2, 4, 8, 16] {
+            let e = 1.0 / n as f64;
+
+            accuracy_test(&X.map(f64::abs), |x| x.powf(e), |x| root_n(x, n));
+            accuracy_test_simd(X.map(f64::abs), |x| x.powf(e), |x| x.root_n(n));
+        }
+    }
+
+    #[test]
+    fn test_root_n_ulp() {
+        const N: usize = 10000;
+
+        for i in 0..N {
+            let x = 2f64.powf(-1070.0 + 2090.0 * (i as f64 + 0.5) / N as f64);
+            let x_simd = Simd::<f64, 4>::splat(x);
+
+            for n in [2, 4, 8, 16, 64] {
+                let y_std = x.powf(1.0 / n as f64);
+
+                assert!(ulp_diff(root_n(x, n), y_std) <= 1, "{x} {n}");
+                assert!(ulp_diff(x_simd.root_n(n)[0], y_std) <= 1, "{x} {n}");
+                assert!(ulp_diff(root_n(x, -n), y_std.recip()) <= 2, "{x} {n}");
+            }
+
+            assert!(ulp_diff(root_n(x, 3), x.cbrt()) <= 1, "{x}");
+            assert!(ulp_diff(x_simd.root_n(3)[0], x.cbrt()) <= 1, "{x}");
+            assert!(ulp_diff(root_n(-x, 3), -x.cbrt()) <= 1, "{x}");
+            assert!(ulp_diff(root_n(x, 1), x) <= 1, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_root_n_special_values() {
+        let x = Simd::from([
+            -243.0,
+            -32.0,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            0.0,
+            1.0,
+            32.0,
+        ]);
+
+        for (n, y) in [
+            (5, [-3.0, -2.0, 0.0, -0.0, f64::INFINITY, 0.0, 1.0, 2.0]),
+            (
+                2,
+                [
+                    f64::NAN,
+                    f64::NAN,
+                    0.0,
+                    -0.0,
+                    f64::INFINITY,
+                    0.0,
+                    1.0,
+                    5.656854249492381,
+                ],
+            ),
+            (
+                -1,
+                [
+                    -1.0 / 243.0,
+                    -0.03125,
+                    f64::INFINITY,
+                    f64::NEG_INFINITY,
+                    0.0,
+                    f64::INFINITY,
+                    1.0,
+                    0.03125,
+                ],
+            ),
+            (0, [f64::NAN; 8]),
+        ] {
+            let y_simd = x.root_n(n);
+
+            for i in 0..y.len() {
+                assert_eq!(ulp_diff(root_n(x[i], n), y[i]), 0, "{} {n}", x[i]);
+                assert_eq!(ulp_diff(y_simd[i], y[i]), 0, "{} {n}", x[i]);
+            }
+        }
+
+        assert_eq!(root_n(2f64.powi(60), 5), 4096.0);
+        assert_eq!(root_n(-(3f64.powi(35)), 7), -243.0);
+    }
+
+    #[test]
+    fn test_root_n_extreme_order() {
+        let x = [
+            f64::from_bits(1),
+            1e-300,
+            3.0,
+            1e300,
+            -7.0,
+            f64::MAX,
+            0.0,
+            f64::INFINITY,
+        ];
+        let x_simd = Simd::from(x);
+
+        // Orders around twice the exponent range, where x^(1/n) is close to
+        // 2^(+-1/2) for the extreme x
+        for n in [i32::MIN, i32::MAX, 2047, 2048, 2147, 2148] {
+            let y_simd = x_simd.root_n(n);
+            let yf_simd = Simd::from(x.map(|x| x as f32)).root_n(n);
+
+            for i in 0..x.len() {
+                let y = if x[i] < 0.0 && n % 2 == 0 {
+                    f64::NAN
+                } else {
+                    x[i].abs().powf((n as f64).recip()).copysign(x[i])
+                };
+
+                assert!(ulp_diff(root_n(x[i], n), y) <= 1, "{} {n}", x[i]);
+                assert!(ulp_diff(y_simd[i], y) <= 1, "{} {n}", x[i]);
+
+                let xf = x[i] as f32;
+                let yf = if xf < 0.0 && n % 2 == 0 {
+                    f32::NAN
+                } else {
+                    (xf.abs() as f64).powf((n as f64).recip()).copysign(x[i])
+                        as f32
+                };
+
+                assert_eq!(ulp_diff(root_nf(xf, n) as f64, yf as f64), 0);
+                assert_eq!(yf_simd[i].to_bits(), root_nf(xf, n).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cbrtf_ulp() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let t = (i as f32 + 0.5) / N as f32;
+
+            for x in
+                [2f32.powf(-149.0 + 277.0 * t), -(2f32.powf(-3.0 + 6.0 * t))]
+            {
+                let y_std = (x as f64).cbrt() as f32;
+                let y = cbrtf(x);
+
+                // Same sign, so the bits are ordered like the values
+                assert!(y.to_bits().abs_diff(y_std.to_bits()) <= 1, "{x}");
+                assert_eq!(Simd::<f32, 4>::splat(x).cbrt()[0], y, "{x}");
+            }
+        }
+
+        assert_eq!(cbrtf(-27.0), -3.0);
+        assert_eq!(cbrtf(-0.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(cbrtf(f32::NEG_INFINITY), f32::NEG_INFINITY);
+        assert!(cbrtf(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_root_nf_ulp() {
+        const N: usize = 10000;
+
+        for i in 0..N {
+            let x = 2f32.powf(-148.0 + 275.0 * (i as f32 + 0.5) / N as f32);
+            let x_simd = Simd::<f32, 4>::splat(x);
+
+            for n in [2, 3, 5, 8, 64, -3] {
+                let y_std = (x as f64).powf(1.0 / n as f64) as f32;
+                let y = root_nf(x, n);
+
+                assert!(y.to_bits().abs_diff(y_std.to_bits()) <= 1, "{x} {n}");
+                assert_eq!(x_simd.root_n(n)[0], y, "{x} {n}");
+            }
+        }
+
+        assert_eq!(root_nf(32.0, 5), 2.0);
+        assert_eq!(root_nf(-243.0, 5), -3.0);
+        assert!(root_nf(-2.0, 4).is_nan());
+        assert_eq!(root_nf(0.0, -3), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_cbrt_simd_speed() {
+        const ITERS: usize = 1000000;
+
+        speed_test_simd_iterated(X, |x| x.cbrt(), |x| x.cbrt(), ITERS);
+    }
+}
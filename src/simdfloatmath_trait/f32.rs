@@ -0,0 +1,292 @@
+use std::simd::{LaneCount, Simd, SupportedLaneCount};
+
+use crate::{
+    acosf_simd, acoshf_simd, asinf_simd, asinhf_simd, atan2df_simd,
+    atan2f_simd, atandf_simd, atanf_simd, atanhf_simd, cbrtf_simd, cosdf_simd,
+    cosf_simd, coshf_simd, cospif_simd, digammaf_simd, erfcf_simd, erff_simd,
+    erfinvf_simd, exp10f_simd, exp2f_simd, expf_simd, expm1f_simd, i0ef_simd,
+    i0f_simd, i1ef_simd, i1f_simd, j0f_simd, j1f_simd, jnf_simd, k0ef_simd,
+    k0f_simd, k1ef_simd, k1f_simd, lgammaf_simd, lnf_simd, log10f_simd,
+    log1pf_simd, log2f_simd, log_basef_simd, polygammaf_simd, powf_simd,
+    root_nf_simd, sincosdf_simd, sincosf_simd, sincospif_simd, sindf_simd,
+    sinf_simd, sinhf_simd, sinpif_simd, tandf_simd, tanf_simd, tanhf_simd,
+    tanpif_simd, tgammaf_simd, trigammaf_simd, y0f_simd, y1f_simd,
+};
+
+use super::SimdFloatMath;
+
+impl<const LANES: usize> SimdFloatMath for Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline(always)]
+    fn exp(self) -> Self {
+        expf_simd(self)
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        exp2f_simd(self)
+    }
+
+    #[inline(always)]
+    fn exp10(self) -> Self {
+        exp10f_simd(self)
+    }
+
+    #[inline(always)]
+    fn exp_m1(self) -> Self {
+        expm1f_simd(self)
+    }
+
+    fn sin(self) -> Self {
+        sinf_simd(self)
+    }
+
+    fn cos(self) -> Self {
+        cosf_simd(self)
+    }
+
+    fn tan(self) -> Self {
+        tanf_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (Self, Self) {
+        sincosf_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_pi(self) -> Self {
+        sinpif_simd(self)
+    }
+
+    #[inline(always)]
+    fn cos_pi(self) -> Self {
+        cospif_simd(self)
+    }
+
+    #[inline(always)]
+    fn tan_pi(self) -> Self {
+        tanpif_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_cos_pi(self) -> (Self, Self) {
+        sincospif_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_deg(self) -> Self {
+        sindf_simd(self)
+    }
+
+    #[inline(always)]
+    fn cos_deg(self) -> Self {
+        cosdf_simd(self)
+    }
+
+    #[inline(always)]
+    fn tan_deg(self) -> Self {
+        tandf_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_cos_deg(self) -> (Self, Self) {
+        sincosdf_simd(self)
+    }
+
+    fn atan(self) -> Self {
+        atanf_simd(self)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        atan2f_simd(self, x)
+    }
+
+    #[inline(always)]
+    fn atan_deg(self) -> Self {
+        atandf_simd(self)
+    }
+
+    #[inline(always)]
+    fn atan2_deg(self, x: Self) -> Self {
+        atan2df_simd(self, x)
+    }
+
+    fn asin(self) -> Self {
+        asinf_simd(self)
+    }
+
+    fn acos(self) -> Self {
+        acosf_simd(self)
+    }
+
+    fn sinh(self) -> Self {
+        sinhf_simd(self)
+    }
+
+    fn cosh(self) -> Self {
+        coshf_simd(self)
+    }
+
+    fn tanh(self) -> Self {
+        tanhf_simd(self)
+    }
+
+    fn asinh(self) -> Self {
+        asinhf_simd(self)
+    }
+
+    fn acosh(self) -> Self {
+        acoshf_simd(self)
+    }
+
+    fn atanh(self) -> Self {
+        atanhf_simd(self)
+    }
+
+    fn ln(self) -> Self {
+        lnf_simd(self)
+    }
+
+    #[inline(always)]
+    fn log2(self) -> Self {
+        log2f_simd(self)
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Self {
+        log10f_simd(self)
+    }
+
+    #[inline(always)]
+    fn ln_1p(self) -> Self {
+        log1pf_simd(self)
+    }
+
+    #[inline(always)]
+    fn log(self, base: Self) -> Self {
+        log_basef_simd(self, base)
+    }
+
+    #[inline(always)]
+    fn pow(self, y: Self) -> Self {
+        powf_simd(self, y)
+    }
+
+    #[inline(always)]
+    fn cbrt(self) -> Self {
+        cbrtf_simd(self)
+    }
+
+    #[inline(always)]
+    fn root_n(self, n: i32) -> Self {
+        root_nf_simd(self, n)
+    }
+
+    #[inline(always)]
+    fn erf(self) -> Self {
+        erff_simd(self)
+    }
+
+    #[inline(always)]
+    fn erfc(self) -> Self {
+        erfcf_simd(self)
+    }
+
+    #[inline(always)]
+    fn erf_inv(self) -> Self {
+        erfinvf_simd(self)
+    }
+
+    #[inline(always)]
+    fn gamma(self) -> Self {
+        tgammaf_simd(self)
+    }
+
+    #[inline(always)]
+    fn ln_gamma(self) -> (Self, Simd<i32, LANES>) {
+        lgammaf_simd(self)
+    }
+
+    #[inline(always)]
+    fn digamma(self) -> Self {
+        digammaf_simd(self)
+    }
+
+    #[inline(always)]
+    fn trigamma(self) -> Self {
+        trigammaf_simd(self)
+    }
+
+    #[inline(always)]
+    fn polygamma(self, n: i32) -> Self {
+        polygammaf_simd(n, self)
+    }
+
+    #[inline(always)]
+    fn j0(self) -> Self {
+        j0f_simd(self)
+    }
+
+    #[inline(always)]
+    fn j1(self) -> Self {
+        j1f_simd(self)
+    }
+
+    #[inline(always)]
+    fn y0(self) -> Self {
+        y0f_simd(self)
+    }
+
+    #[inline(always)]
+    fn y1(self) -> Self {
+        y1f_simd(self)
+    }
+
+    #[inline(always)]
+    fn jn(self, n: i32) -> Self {
+        jnf_simd(n, self)
+    }
+
+    #[inline(always)]
+    fn i0(self) -> Self {
+        i0f_simd(self)
+    }
+
+    #[inline(always)]
+    fn i1(self) -> Self {
+        i1f_simd(self)
+    }
+
+    #[inline(always)]
+    fn k0(self) -> Self {
+        k0f_simd(self)
+    }
+
+    #[inline(always)]
+    fn k1(self) -> Self {
+        k1f_simd(self)
+    }
+
+    #[inline(always)]
+    fn i0e(self) -> Self {
+        i0ef_simd(self)
+    }
+
+    #[inline(always)]
+    fn i1e(self) -> Self {
+        i1ef_simd(self)
+    }
+
+    #[inline(always)]
+    fn k0e(self) -> Self {
+        k0ef_simd(self)
+    }
+
+    #[inline(always)]
+    fn k1e(self) -> Self {
+        k1ef_simd(self)
+    }
+}
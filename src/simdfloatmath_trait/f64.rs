@@ -1,6 +1,16 @@
 use std::simd::{LaneCount, Simd, SupportedLaneCount};
 
-use crate::{exp_simd, sin_simd, cos_simd, tan_simd, atan_simd, ln_simd, atan2_simd};
+use crate::{
+    acos_simd, acosh_simd, asin_simd, asinh_simd, atan2_simd, atan2d_simd,
+    atan_simd, atand_simd, atanh_simd, cbrt_simd, cos_simd, cosd_simd,
+    cosh_simd, cospi_simd, digamma_simd, erf_simd, erfc_simd, erfinv_simd,
+    exp10_simd, exp2_simd, exp_simd, expm1_simd, i0_simd, i0e_simd, i1_simd,
+    i1e_simd, j0_simd, j1_simd, jn_simd, k0_simd, k0e_simd, k1_simd, k1e_simd,
+    lgamma_simd, ln_simd, log10_simd, log1p_simd, log2_simd, log_base_simd,
+    polygamma_simd, pow_simd, root_n_simd, sin_simd, sincos_simd, sincosd_simd,
+    sincospi_simd, sind_simd, sinh_simd, sinpi_simd, tan_simd, tand_simd,
+    tanh_simd, tanpi_simd, tgamma_simd, trigamma_simd, y0_simd, y1_simd,
+};
 
 use super::SimdFloatMath;
 
@@ -13,6 +23,21 @@ where
         exp_simd(self)
     }
 
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        exp2_simd(self)
+    }
+
+    #[inline(always)]
+    fn exp10(self) -> Self {
+        exp10_simd(self)
+    }
+
+    #[inline(always)]
+    fn exp_m1(self) -> Self {
+        expm1_simd(self)
+    }
+
     fn sin(self) -> Self {
         sin_simd(self)
     }
@@ -25,6 +50,51 @@ where
         tan_simd(self)
     }
 
+    #[inline(always)]
+    fn sin_cos(self) -> (Self, Self) {
+        sincos_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_pi(self) -> Self {
+        sinpi_simd(self)
+    }
+
+    #[inline(always)]
+    fn cos_pi(self) -> Self {
+        cospi_simd(self)
+    }
+
+    #[inline(always)]
+    fn tan_pi(self) -> Self {
+        tanpi_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_cos_pi(self) -> (Self, Self) {
+        sincospi_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_deg(self) -> Self {
+        sind_simd(self)
+    }
+
+    #[inline(always)]
+    fn cos_deg(self) -> Self {
+        cosd_simd(self)
+    }
+
+    #[inline(always)]
+    fn tan_deg(self) -> Self {
+        tand_simd(self)
+    }
+
+    #[inline(always)]
+    fn sin_cos_deg(self) -> (Self, Self) {
+        sincosd_simd(self)
+    }
+
     fn atan(self) -> Self {
         atan_simd(self)
     }
@@ -33,7 +103,189 @@ where
         atan2_simd(self, x)
     }
 
+    #[inline(always)]
+    fn atan_deg(self) -> Self {
+        atand_simd(self)
+    }
+
+    #[inline(always)]
+    fn atan2_deg(self, x: Self) -> Self {
+        atan2d_simd(self, x)
+    }
+
+    fn asin(self) -> Self {
+        asin_simd(self)
+    }
+
+    fn acos(self) -> Self {
+        acos_simd(self)
+    }
+
+    fn sinh(self) -> Self {
+        sinh_simd(self)
+    }
+
+    fn cosh(self) -> Self {
+        cosh_simd(self)
+    }
+
+    fn tanh(self) -> Self {
+        tanh_simd(self)
+    }
+
+    fn asinh(self) -> Self {
+        asinh_simd(self)
+    }
+
+    fn acosh(self) -> Self {
+        acosh_simd(self)
+    }
+
+    fn atanh(self) -> Self {
+        atanh_simd(self)
+    }
+
     fn ln(self) -> Self {
         ln_simd(self)
     }
+
+    #[inline(always)]
+    fn log2(self) -> Self {
+        log2_simd(self)
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Self {
+        log10_simd(self)
+    }
+
+    #[inline(always)]
+    fn ln_1p(self) -> Self {
+        log1p_simd(self)
+    }
+
+    #[inline(always)]
+    fn log(self, base: Self) -> Self {
+        log_base_simd(self, base)
+    }
+
+    #[inline(always)]
+    fn pow(self, y: Self) -> Self {
+        pow_simd(self, y)
+    }
+
+    #[inline(always)]
+    fn cbrt(self) -> Self {
+        cbrt_simd(self)
+    }
+
+    #[inline(always)]
+    fn root_n(self, n: i32) -> Self {
+        root_n_simd(self, n)
+    }
+
+    #[inline(always)]
+    fn erf(self) -> Self {
+        erf_simd(self)
+    }
+
+    #[inline(always)]
+    fn erfc(self) -> Self {
+        erfc_simd(self)
+    }
+
+    #[inline(always)]
+    fn erf_inv(self) -> Self {
+        erfinv_simd(self)
+    }
+
+    #[inline(always)]
+    fn gamma(self) -> Self {
+        tgamma_simd(self)
+    }
+
+    #[inline(always)]
+    fn ln_gamma(self) -> (Self, Simd<i32, LANES>) {
+        lgamma_simd(self)
+    }
+
+    #[inline(always)]
+    fn digamma(self) -> Self {
+        digamma_simd(self)
+    }
+
+    #[inline(always)]
+    fn trigamma(self) -> Self {
+        trigamma_simd(self)
+    }
+
+    #[inline(always)]
+    fn polygamma(self, n: i32) -> Self {
+        polygamma_simd(n, self)
+    }
+
+    #[inline(always)]
+    fn j0(self) -> Self {
+        j0_simd(self)
+    }
+
+    #[inline(always)]
+    fn j1(self) -> Self {
+        j1_simd(self)
+    }
+
+    #[inline(always)]
+    fn y0(self) -> Self {
+        y0_simd(self)
+    }
+
+    #[inline(always)]
+    fn y1(self) -> Self {
+        y1_simd(self)
+    }
+
+    #[inline(always)]
+    fn jn(self, n: i32) -> Self {
+        jn_simd(n, self)
+    }
+
+    #[inline(always)]
+    fn i0(self) -> Self {
+        i0_simd(self)
+    }
+
+    #[inline(always)]
+    fn i1(self) -> Self {
+        i1_simd(self)
+    }
+
+    #[inline(always)]
+    fn k0(self) -> Self {
+        k0_simd(self)
+    }
+
+    #[inline(always)]
+    fn k1(self) -> Self {
+        k1_simd(self)
+    }
+
+    #[inline(always)]
+    fn i0e(self) -> Self {
+        i0e_simd(self)
+    }
+
+    #[inline(always)]
+    fn i1e(self) -> Self {
+        i1e_simd(self)
+    }
+
+    #[inline(always)]
+    fn k0e(self) -> Self {
+        k0e_simd(self)
+    }
+
+    #[inline(always)]
+    fn k1e(self) -> Self {
+        k1e_simd(self)
+    }
 }
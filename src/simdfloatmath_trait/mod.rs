@@ -1,16 +1,73 @@
 use std::simd::prelude::*;
 
+mod f32;
 mod f64;
 
 pub trait SimdFloatMath: SimdFloat {
     fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn exp10(self) -> Self;
+    fn exp_m1(self) -> Self;
 
     fn sin(self) -> Self;
     fn cos(self) -> Self;
     fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn sin_pi(self) -> Self;
+    fn cos_pi(self) -> Self;
+    fn tan_pi(self) -> Self;
+    fn sin_cos_pi(self) -> (Self, Self);
+    fn sin_deg(self) -> Self;
+    fn cos_deg(self) -> Self;
+    fn tan_deg(self) -> Self;
+    fn sin_cos_deg(self) -> (Self, Self);
 
     fn atan(self) -> Self;
     fn atan2(self, x: Self) -> Self;
+    fn atan_deg(self) -> Self;
+    fn atan2_deg(self, x: Self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn atanh(self) -> Self;
 
     fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn log(self, base: Self) -> Self;
+
+    fn pow(self, y: Self) -> Self;
+    fn cbrt(self) -> Self;
+    fn root_n(self, n: i32) -> Self;
+
+    fn erf(self) -> Self;
+    fn erfc(self) -> Self;
+    fn erf_inv(self) -> Self;
+
+    fn gamma(self) -> Self;
+    fn ln_gamma(self) -> (Self, Self::Cast<i32>);
+    fn digamma(self) -> Self;
+    fn trigamma(self) -> Self;
+    fn polygamma(self, n: i32) -> Self;
+
+    fn j0(self) -> Self;
+    fn j1(self) -> Self;
+    fn y0(self) -> Self;
+    fn y1(self) -> Self;
+    fn jn(self, n: i32) -> Self;
+
+    fn i0(self) -> Self;
+    fn i1(self) -> Self;
+    fn k0(self) -> Self;
+    fn k1(self) -> Self;
+    fn i0e(self) -> Self;
+    fn i1e(self) -> Self;
+    fn k0e(self) -> Self;
+    fn k1e(self) -> Self;
 }
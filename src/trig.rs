@@ -1,101 +1,1461 @@
 use std::{
-    f64::consts::{FRAC_1_SQRT_2, PI},
-    simd::{prelude::*, LaneCount, SupportedLaneCount},
+    f64::consts::{FRAC_2_PI, FRAC_PI_2, PI},
+    simd::{prelude::*, LaneCount, StdFloat, SupportedLaneCount},
 };
 
-use crate::{periodic_clamp, periodic_clamp_simd, polyval, polyval_simd};
-
-const TAYLOR_COEFFS: [f64; 16] = [
-    -5.407361331613617e-13,
-    -8.111041997420426e-12,
-    1.1355458796388596e-10,
-    1.4762096435305176e-9,
-    -1.771451572236621e-8,
-    -1.948596729460283e-7,
-    1.948596729460283e-6,
-    1.7537370565142548e-5,
-    -0.00014029896452114038,
-    -0.0009820927516479827,
-    0.005892556509887896,
-    0.02946278254943948,
-    -0.11785113019775792,
-    -0.3535533905932738,
-    FRAC_1_SQRT_2,
-    FRAC_1_SQRT_2,
+use crate::{polyval, polyval_simd, polyvalf, polyvalf_simd};
+
+// f(x) = (sin(x) - x) / x^3 in terms of x^2
+// domain: [-pi/4, pi/4]
+const SIN_MINIMAX: [f64; 7] = [
+    -7.586701001931799e-13,
+    1.6058531690867902e-10,
+    -2.5052106232946424e-8,
+    2.7557319219340764e-6,
+    -0.00019841269841265068,
+    0.008333333333333331,
+    -0.16666666666666666,
+];
+
+// f(x) = (cos(x) - 1 + x^2 / 2) / x^4 in terms of x^2
+// domain: [-pi/4, pi/4]
+const COS_MINIMAX: [f64; 6] = [
+    -1.1382639806406403e-11,
+    2.0876146382230336e-9,
+    -2.75573172723442e-7,
+    2.480158729876704e-5,
+    -0.0013888888888887398,
+    0.041666666666666664,
+];
+
+// f(x) = (tan(x) - x) / x^3 in terms of x^2, as TAN_NUM / TAN_DEN with the
+// constant term of TAN_DEN being 1
+// domain: [-pi/4, pi/4]
+const TAN_NUM: [f64; 4] = [
+    -4.981325530589814e-7,
+    0.0002939421271113269,
+    -0.02223873470202876,
+    0.3333333333333333,
+];
+const TAN_DEN: [f64; 4] = [
+    4.981325530589814e-7,
+    -0.0003118025508224405,
+    0.025663546119004465,
+    -0.46671620410608616,
+];
+
+// Below this sin(x) and tan(x) round to x
+const TRIG_TINY: f64 = 7.450580596923828e-9;
+
+// pi/2 = PIO2_1 + PIO2_2 + PIO2_3 to about 160 bits
+const PIO2_1: f64 = FRAC_PI_2;
+const PIO2_2: f64 = 6.123233995736766e-17;
+const PIO2_3: f64 = -1.4973849048591698e-33;
+
+// Past this the three parts of pi/2 no longer give a correctly rounded
+// remainder, and reduce_pio2_big takes over
+const REDUCE_BIG: f64 = 1_073_741_824.0;
+
+// The binary expansion of 2/pi, enough for the exponent of f64::MAX plus the
+// 192 bits used by reduce_pio2_big
+const TWO_OVER_PI: [u64; 19] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+];
+
+// pi = PI + PI_LO to about 107 bits
+const PI_LO: f64 = 1.2246467991473532e-16;
+
+// 2^53, past which every double is an even integer
+const HALF_TURNS_BIG: f64 = 9_007_199_254_740_992.0;
+
+// pi / 180 = DEG + DEG_LO to about 107 bits
+const DEG: f64 = 0.017453292519943295;
+const DEG_LO: f64 = 2.9486522708701687e-19;
+
+// 2^52, past which x / 90 may round to the wrong quadrant
+const DEGREES_BIG: f64 = 4_503_599_627_370_496.0;
+
+const MANTISSA_MASK: u64 = 0x000fffffffffffff;
+
+// 2^-126
+const FRAC_SCALE: f64 = 1.1754943508222875e-38;
+
+// The 64 bits of 2/pi starting at bit p, where bit 1 is worth 1/2
+fn two_over_pi_bits(p: i32) -> u64 {
+    let word = |i: i32| if i < 0 { 0 } else { TWO_OVER_PI[i as usize] };
+
+    let i = (p - 1).div_euclid(64);
+    let s = (p - 1).rem_euclid(64);
+
+    if s == 0 {
+        word(i)
+    } else {
+        word(i) << s | word(i + 1) >> (64 - s)
+    }
+}
+
+// Payne-Hanek reduction for finite |x| >= REDUCE_BIG. Only the bits of 2/pi
+// that can change x * 2/pi mod 4 are multiplied out, in integer arithmetic.
+fn reduce_pio2_big(x: f64) -> (f64, f64, i64) {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let m = (bits & MANTISSA_MASK) | (1 << 52);
+
+    // |x| * 2/pi = m 2^e sum_i b_i 2^-i. The bits before i = e - 1 only add
+    // multiples of 4, and the next 192 bits leave plenty for the fraction.
+    let w0 = two_over_pi_bits(e - 1);
+    let w1 = two_over_pi_bits(e + 63);
+    let w2 = two_over_pi_bits(e + 127);
+
+    // The top 128 of the low 192 bits of m * (w0 w1 w2), with the binary
+    // point at bit 126
+    let lo = m as u128 * w2 as u128;
+    let mid = m as u128 * w1 as u128 + (lo >> 64);
+    let hi = m.wrapping_mul(w0).wrapping_add((mid >> 64) as u64);
+    let y = (hi as u128) << 64 | mid as u64 as u128;
+
+    // Round to the nearest quadrant, leaving f in [-2^125, 2^125)
+    let y = y.wrapping_add(1 << 125);
+    let n = (y >> 126) as i64;
+    let f = (y & ((1 << 126) - 1)) as i128 - (1 << 125);
+
+    let f_hi = f as f64;
+    let f_lo = (f - f_hi as i128) as f64 * FRAC_SCALE;
+    let f_hi = f_hi * FRAC_SCALE;
+
+    let r_hi = f_hi * PIO2_1;
+    let r_lo =
+        f_hi.mul_add(PIO2_1, -r_hi) + f_hi.mul_add(PIO2_2, f_lo * PIO2_1);
+
+    let r = r_hi + r_lo;
+    let r_lo = r_lo - (r - r_hi);
+
+    if x < 0.0 {
+        (-r, -r_lo, -n)
+    } else {
+        (r, r_lo, n)
+    }
+}
+
+// x - k pi/2 as r_hi + r_lo, for k within a half of x 2/pi. k may itself be
+// a half-integer.
+fn sub_pio2(x: f64, k: f64) -> (f64, f64) {
+    // Exact, since k PIO2_1 is within a few ulp of x
+    let a = k.mul_add(-PIO2_1, x);
+
+    let b = k * PIO2_2;
+    let b_lo = k.mul_add(PIO2_2, -b);
+
+    let r = a - b;
+    let t = r - a;
+    let r_lo = ((a - (r - t)) - (b + t)) - k.mul_add(PIO2_3, b_lo);
+
+    let r_hi = r + r_lo;
+
+    (r_hi, r_lo - (r_hi - r))
+}
+
+// x = r_hi + r_lo + n pi/2 with |r_hi| <= pi/4 (up to rounding of n)
+fn reduce_pio2(x: f64) -> (f64, f64, i64) {
+    if x.abs() >= REDUCE_BIG && x.is_finite() {
+        return reduce_pio2_big(x);
+    }
+
+    let k = (x * FRAC_2_PI).round();
+    let (r_hi, r_lo) = sub_pio2(x, k);
+
+    (r_hi, r_lo, k as i64)
+}
+
+// x + pi/4 = r_hi + r_lo + n pi/2 with |r_hi| <= pi/4. Taking off
+// (n - 1/2) pi/2 in one go means that the shift cancels exactly against x.
+fn reduce_pio2_shifted(x: f64) -> (f64, f64, i64) {
+    if x.abs() >= REDUCE_BIG && x.is_finite() {
+        let (r_hi, r_lo, n) = reduce_pio2_big(x);
+        return shift_pio4(r_hi, r_lo, n);
+    }
+
+    let k = x.mul_add(FRAC_2_PI, 0.5).round();
+    let (r_hi, r_lo) = sub_pio2(x, k - 0.5);
+
+    (r_hi, r_lo, k as i64)
+}
+
+// Moves a reduced argument on by pi/4, keeping |r_hi| <= pi/4. Only needed
+// after reduce_pio2_big, which has no room for the shift.
+fn shift_pio4(r_hi: f64, r_lo: f64, n: i64) -> (f64, f64, i64) {
+    let (c, c_lo, n) = if r_hi < 0.0 {
+        (0.5 * PIO2_1, 0.5 * PIO2_2, n)
+    } else {
+        (-0.5 * PIO2_1, -0.5 * PIO2_2, n.wrapping_add(1))
+    };
+
+    let s = r_hi + c;
+    let t = s - r_hi;
+    let lo = ((r_hi - (s - t)) + (c - t) + r_lo) + c_lo;
+
+    let hi = s + lo;
+    (hi, lo - (hi - s), n)
+}
+
+// sin(x + y) for |x| <= pi/4 and |y| <= ulp(x) / 2
+fn sin_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    x + (z * x).mul_add(polyval(&SIN_MINIMAX, z), (0.5 * z).mul_add(-y, y))
+}
+
+// cos(x + y) for |x| <= pi/4 and |y| <= ulp(x) / 2
+fn cos_kernel(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+
+    w + (((1.0 - w) - hz) + (z * z).mul_add(polyval(&COS_MINIMAX, z), -x * y))
+}
+
+// tan(x + y) for |x| <= pi/4 and |y| <= ulp(x) / 2, or -cot(x + y) if odd
+fn tan_kernel(x: f64, y: f64, odd: bool) -> f64 {
+    let z = x * x;
+    let den = z.mul_add(polyval(&TAN_DEN, z), 1.0);
+
+    // tan(x + y) = tan(x) + y (1 + tan(x)^2), and x^2 is close enough to
+    // tan(x)^2 for the correction
+    let w = (x * z).mul_add(polyval(&TAN_NUM, z) / den, y * (1.0 + z));
+    let t = x + w;
+
+    if odd {
+        // -1 / (t + t_lo) with one Newton step on the reciprocal, which keeps
+        // the low part of t that would be lost in -1 / t
+        let t_lo = w - (t - x);
+        let r = -t.recip();
+        r.mul_add(r.mul_add(t, 1.0) + r * t_lo, r)
+    } else {
+        t
+    }
+}
+
+fn sin_quadrant(r_hi: f64, r_lo: f64, n: i64) -> f64 {
+    let tl = if n & 1 != 0 {
+        cos_kernel(r_hi, r_lo)
+    } else {
+        sin_kernel(r_hi, r_lo)
+    };
+
+    if n & 2 != 0 {
+        -tl
+    } else {
+        tl
+    }
+}
+
+fn sincos_quadrant(r_hi: f64, r_lo: f64, n: i64) -> (f64, f64) {
+    let s = sin_kernel(r_hi, r_lo);
+    let c = cos_kernel(r_hi, r_lo);
+
+    let (s, c) = if n & 1 != 0 { (c, -s) } else { (s, c) };
+
+    if n & 2 != 0 {
+        (-s, -c)
+    } else {
+        (s, c)
+    }
+}
+
+pub fn sin(x: f64) -> f64 {
+    // Also keeps the sign of -0, which the reduction loses
+    if x.abs() < TRIG_TINY {
+        return x;
+    }
+
+    let (r_hi, r_lo, n) = reduce_pio2(x);
+    sin_quadrant(r_hi, r_lo, n)
+}
+
+pub fn cos(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_pio2(x);
+    sin_quadrant(r_hi, r_lo, n.wrapping_add(1))
+}
+
+pub fn tan(x: f64) -> f64 {
+    if x.abs() < TRIG_TINY {
+        return x;
+    }
+
+    let (r_hi, r_lo, n) = reduce_pio2(x);
+    tan_kernel(r_hi, r_lo, n & 1 != 0)
+}
+
+/// Computes `(sin(x), cos(x))` with a single range reduction.
+pub fn sincos(x: f64) -> (f64, f64) {
+    if x.abs() < TRIG_TINY {
+        return (x, 1.0);
+    }
+
+    let (r_hi, r_lo, n) = reduce_pio2(x);
+    sincos_quadrant(r_hi, r_lo, n)
+}
+
+/// Computes `sin(x + PI / 4)`, with the shift folded into the range reduction
+/// so that it is exact.
+pub fn sin_shift(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_pio2_shifted(x);
+    sin_quadrant(r_hi, r_lo, n)
+}
+
+#[inline(always)]
+fn sub_pio2_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    k: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = k.mul_add(Simd::splat(-PIO2_1), x);
+
+    let b = k * Simd::splat(PIO2_2);
+    let b_lo = k.mul_add(Simd::splat(PIO2_2), -b);
+
+    let r = a - b;
+    let t = r - a;
+    let r_lo = ((a - (r - t)) - (b + t)) - k.mul_add(Simd::splat(PIO2_3), b_lo);
+
+    let r_hi = r + r_lo;
+
+    (r_hi, r_lo - (r_hi - r))
+}
+
+#[inline(always)]
+fn reduce_pio2_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>, Simd<i64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k = (x * Simd::splat(FRAC_2_PI)).round();
+
+    let (mut r_hi, mut r_lo) = sub_pio2_simd(x, k);
+    let mut n = k.cast();
+
+    // Huge arguments are rare, so they are reduced one lane at a time
+    let big = x.abs().simd_ge(Simd::splat(REDUCE_BIG)) & x.is_finite();
+
+    if big.any() {
+        for i in 0..LANES {
+            if big.test(i) {
+                (r_hi[i], r_lo[i], n[i]) = reduce_pio2_big(x[i]);
+            }
+        }
+    }
+
+    (r_hi, r_lo, n)
+}
+
+#[inline(always)]
+fn sincos_kernel_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    y: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let z = x * x;
+
+    let s = x
+        + (z * x).mul_add(
+            polyval_simd(&SIN_MINIMAX, z),
+            (Simd::splat(0.5) * z).mul_add(-y, y),
+        );
+
+    let hz = Simd::splat(0.5) * z;
+    let w = Simd::splat(1.0) - hz;
+    let c = w
+        + (((Simd::splat(1.0) - w) - hz)
+            + (z * z).mul_add(polyval_simd(&COS_MINIMAX, z), -x * y));
+
+    (s, c)
+}
+
+#[inline(always)]
+fn sin_quadrant_simd<const LANES: usize>(
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincos_kernel_simd(r_hi, r_lo);
+
+    let tl = (n & Simd::splat(1)).simd_eq(Simd::splat(0)).select(s, c);
+
+    (n & Simd::splat(2)).simd_eq(Simd::splat(0)).select(tl, -tl)
+}
+
+#[inline(always)]
+fn sincos_quadrant_simd<const LANES: usize>(
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincos_kernel_simd(r_hi, r_lo);
+
+    let even = (n & Simd::splat(1)).simd_eq(Simd::splat(0));
+    let (s, c) = (even.select(s, c), even.select(c, -s));
+
+    let pos = (n & Simd::splat(2)).simd_eq(Simd::splat(0));
+    (pos.select(s, -s), pos.select(c, -c))
+}
+
+pub fn sin_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_pio2_simd(x);
+    let y = sin_quadrant_simd(r_hi, r_lo, n);
+
+    x.abs().simd_lt(Simd::splat(TRIG_TINY)).select(x, y)
+}
+
+pub fn cos_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_pio2_simd(x);
+    sin_quadrant_simd(r_hi, r_lo, n + Simd::splat(1))
+}
+
+#[inline(always)]
+fn tan_quadrant_simd<const LANES: usize>(
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let z = r_hi * r_hi;
+    let den = z.mul_add(polyval_simd(&TAN_DEN, z), Simd::splat(1.0));
+
+    let w = (r_hi * z).mul_add(
+        polyval_simd(&TAN_NUM, z) / den,
+        r_lo * (Simd::splat(1.0) + z),
+    );
+    let t = r_hi + w;
+
+    let t_lo = w - (t - r_hi);
+    let r = -t.recip();
+    let cot = r.mul_add(r.mul_add(t, Simd::splat(1.0)) + r * t_lo, r);
+
+    (n & Simd::splat(1)).simd_eq(Simd::splat(0)).select(t, cot)
+}
+
+pub fn tan_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_pio2_simd(x);
+    let y = tan_quadrant_simd(r_hi, r_lo, n);
+
+    x.abs().simd_lt(Simd::splat(TRIG_TINY)).select(x, y)
+}
+
+/// The SIMD version of [`sincos`].
+#[inline(always)]
+pub fn sincos_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_pio2_simd(x);
+    let (s, c) = sincos_quadrant_simd(r_hi, r_lo, n);
+
+    (x.abs().simd_lt(Simd::splat(TRIG_TINY)).select(x, s), c)
+}
+
+#[inline(always)]
+fn reduce_pio2_shifted_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>, Simd<i64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k = x.mul_add(Simd::splat(FRAC_2_PI), Simd::splat(0.5)).round();
+
+    let (mut r_hi, mut r_lo) = sub_pio2_simd(x, k - Simd::splat(0.5));
+    let mut n = k.cast();
+
+    let big = x.abs().simd_ge(Simd::splat(REDUCE_BIG)) & x.is_finite();
+
+    if big.any() {
+        for i in 0..LANES {
+            if big.test(i) {
+                let (r_hi_big, r_lo_big, n_big) = reduce_pio2_big(x[i]);
+                (r_hi[i], r_lo[i], n[i]) =
+                    shift_pio4(r_hi_big, r_lo_big, n_big);
+            }
+        }
+    }
+
+    (r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`sin_shift`].
+#[inline(always)]
+pub fn sin_shift_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_pio2_shifted_simd(x);
+    sin_quadrant_simd(r_hi, r_lo, n)
+}
+
+// x = n / 2 + r exactly with |r| <= 1/4. Returns r pi as r_hi + r_lo and
+// n mod 4.
+fn reduce_half_turns(x: f64) -> (f64, f64, i64) {
+    // Past 2^53 every double is an even integer, and x * 0 keeps the sign and
+    // turns infinities into NaN
+    let x = if x.abs() < HALF_TURNS_BIG { x } else { x * 0.0 };
+
+    let n = (2.0 * x).round();
+    let r = n.mul_add(-0.5, x);
+
+    let r_hi = r * PI;
+    let r_lo = r.mul_add(PI, -r_hi) + r * PI_LO;
+
+    (r_hi, r_lo, (n - 4.0 * (0.25 * n).floor()) as i64)
+}
+
+// The quadrant functions below for a reduction that lands exactly on the
+// zeros and poles. x is the unreduced argument, which decides the sign of
+// zero results.
+fn sin_exact(x: f64, r_hi: f64, r_lo: f64, n: i64) -> f64 {
+    let y = sin_quadrant(r_hi, r_lo, n);
+
+    // Zeros are +0 for positive x and -0 for negative x
+    if y == 0.0 {
+        0.0f64.copysign(x)
+    } else {
+        y
+    }
+}
+
+fn cos_exact(r_hi: f64, r_lo: f64, n: i64) -> f64 {
+    // Zeros are always +0
+    sin_quadrant(r_hi, r_lo, n + 1) + 0.0
+}
+
+fn tan_exact(x: f64, r_hi: f64, r_lo: f64, n: i64) -> f64 {
+    if r_hi == 0.0 {
+        // Zeros have the sign of x for even n and the opposite for odd n,
+        // while poles are +inf for even n and -inf for odd n
+        let y = if n & 1 != 0 {
+            f64::INFINITY
+        } else {
+            0.0f64.copysign(x)
+        };
+
+        return if n & 2 != 0 { -y } else { y };
+    }
+
+    tan_kernel(r_hi, r_lo, n & 1 != 0)
+}
+
+fn sincos_exact(x: f64, r_hi: f64, r_lo: f64, n: i64) -> (f64, f64) {
+    let (s, c) = sincos_quadrant(r_hi, r_lo, n);
+
+    let s = if s == 0.0 { 0.0f64.copysign(x) } else { s };
+
+    (s, c + 0.0)
+}
+
+/// Computes `sin(PI * x)`, exactly zero at the integers.
+pub fn sinpi(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_half_turns(x);
+    sin_exact(x, r_hi, r_lo, n)
+}
+
+/// Computes `cos(PI * x)`, exactly zero at the half-integers.
+pub fn cospi(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_half_turns(x);
+    cos_exact(r_hi, r_lo, n)
+}
+
+/// Computes `tan(PI * x)`, with exact zeros at the integers and exact poles
+/// at the half-integers.
+pub fn tanpi(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_half_turns(x);
+    tan_exact(x, r_hi, r_lo, n)
+}
+
+/// Computes `(sinpi(x), cospi(x))` with a single range reduction.
+pub fn sincospi(x: f64) -> (f64, f64) {
+    let (r_hi, r_lo, n) = reduce_half_turns(x);
+    sincos_exact(x, r_hi, r_lo, n)
+}
+
+#[inline(always)]
+fn reduce_half_turns_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>, Simd<i64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x
+        .abs()
+        .simd_lt(Simd::splat(HALF_TURNS_BIG))
+        .select(x, x * Simd::splat(0.0));
+
+    let n = (Simd::splat(2.0) * x).round();
+    let r = n.mul_add(Simd::splat(-0.5), x);
+
+    let r_hi = r * Simd::splat(PI);
+    let r_lo = r.mul_add(Simd::splat(PI), -r_hi) + r * Simd::splat(PI_LO);
+
+    let n = n - Simd::splat(4.0) * (Simd::splat(0.25) * n).floor();
+
+    (r_hi, r_lo, n.cast())
+}
+
+#[inline(always)]
+fn sin_exact_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = sin_quadrant_simd(r_hi, r_lo, n);
+
+    y.simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0).copysign(x), y)
+}
+
+#[inline(always)]
+fn cos_exact_simd<const LANES: usize>(
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    sin_quadrant_simd(r_hi, r_lo, n + Simd::splat(1)) + Simd::splat(0.0)
+}
+
+#[inline(always)]
+fn tan_exact_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = tan_quadrant_simd(r_hi, r_lo, n);
+
+    let exact = (n & Simd::splat(1))
+        .simd_eq(Simd::splat(0))
+        .select(Simd::splat(0.0).copysign(x), Simd::splat(f64::INFINITY));
+    let exact = (n & Simd::splat(2))
+        .simd_eq(Simd::splat(0))
+        .select(exact, -exact);
+
+    r_hi.simd_eq(Simd::splat(0.0)).select(exact, y)
+}
+
+#[inline(always)]
+fn sincos_exact_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+    r_hi: Simd<f64, LANES>,
+    r_lo: Simd<f64, LANES>,
+    n: Simd<i64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincos_quadrant_simd(r_hi, r_lo, n);
+
+    let s = s
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0).copysign(x), s);
+
+    (s, c + Simd::splat(0.0))
+}
+
+/// The SIMD version of [`sinpi`].
+#[inline(always)]
+pub fn sinpi_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_half_turns_simd(x);
+    sin_exact_simd(x, r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`cospi`].
+#[inline(always)]
+pub fn cospi_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_half_turns_simd(x);
+    cos_exact_simd(r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`tanpi`].
+#[inline(always)]
+pub fn tanpi_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_half_turns_simd(x);
+    tan_exact_simd(x, r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`sincospi`].
+#[inline(always)]
+pub fn sincospi_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_half_turns_simd(x);
+    sincos_exact_simd(x, r_hi, r_lo, n)
+}
+
+// x = 90 n + r exactly with |r| <= 45. Returns r in radians as r_hi + r_lo
+// and n mod 4.
+fn reduce_degrees(x: f64) -> (f64, f64, i64) {
+    // x % 360 is exact, and keeps n small enough for the quotient to round
+    // to the right quadrant
+    let x = if x.abs() < DEGREES_BIG { x } else { x % 360.0 };
+
+    let n = (x / 90.0).round();
+    let r = n.mul_add(-90.0, x);
+
+    let r_hi = r * DEG;
+    let r_lo = r.mul_add(DEG, -r_hi) + r * DEG_LO;
+
+    (r_hi, r_lo, (n - 4.0 * (0.25 * n).floor()) as i64)
+}
+
+/// Computes the sine of `x` degrees, exactly zero at multiples of 180.
+pub fn sind(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_degrees(x);
+    sin_exact(x, r_hi, r_lo, n)
+}
+
+/// Computes the cosine of `x` degrees, exactly zero at odd multiples of 90.
+pub fn cosd(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_degrees(x);
+    cos_exact(r_hi, r_lo, n)
+}
+
+/// Computes the tangent of `x` degrees, with exact zeros at multiples of 180
+/// and exact poles at odd multiples of 90.
+pub fn tand(x: f64) -> f64 {
+    let (r_hi, r_lo, n) = reduce_degrees(x);
+    tan_exact(x, r_hi, r_lo, n)
+}
+
+/// Computes `(sind(x), cosd(x))` with a single range reduction.
+pub fn sincosd(x: f64) -> (f64, f64) {
+    let (r_hi, r_lo, n) = reduce_degrees(x);
+    sincos_exact(x, r_hi, r_lo, n)
+}
+
+#[inline(always)]
+fn reduce_degrees_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>, Simd<i64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let big = x.abs().simd_ge(Simd::splat(DEGREES_BIG));
+    let x = if big.any() {
+        big.select(x % Simd::splat(360.0), x)
+    } else {
+        x
+    };
+
+    let n = (x / Simd::splat(90.0)).round();
+    let r = n.mul_add(Simd::splat(-90.0), x);
+
+    let r_hi = r * Simd::splat(DEG);
+    let r_lo = r.mul_add(Simd::splat(DEG), -r_hi) + r * Simd::splat(DEG_LO);
+
+    let n = n - Simd::splat(4.0) * (Simd::splat(0.25) * n).floor();
+
+    (r_hi, r_lo, n.cast())
+}
+
+/// The SIMD version of [`sind`].
+#[inline(always)]
+pub fn sind_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_degrees_simd(x);
+    sin_exact_simd(x, r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`cosd`].
+#[inline(always)]
+pub fn cosd_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_degrees_simd(x);
+    cos_exact_simd(r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`tand`].
+#[inline(always)]
+pub fn tand_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_degrees_simd(x);
+    tan_exact_simd(x, r_hi, r_lo, n)
+}
+
+/// The SIMD version of [`sincosd`].
+#[inline(always)]
+pub fn sincosd_simd<const LANES: usize>(
+    x: Simd<f64, LANES>,
+) -> (Simd<f64, LANES>, Simd<f64, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (r_hi, r_lo, n) = reduce_degrees_simd(x);
+    sincos_exact_simd(x, r_hi, r_lo, n)
+}
+
+// f(x) = (sin(x) - x) / x^3 in terms of x^2
+// domain: [-pi/4, pi/4]
+const SIN_MINIMAX_F32: [f32; 3] =
+    [-1.950_396_3e-4, 8.332_101e-3, -1.666_665_5e-1];
+
+// f(x) = (cos(x) - 1 + x^2 / 2) / x^4 in terms of x^2
+// domain: [-pi/4, pi/4]
+const COS_MINIMAX_F32: [f32; 3] =
+    [2.446_383_8e-5, -1.388_765_4e-3, 4.166_665_3e-2];
+
+// f(x) = (tan(x) - x) / x^3 in terms of x^2
+// domain: [-pi/4, pi/4]
+const TAN_MINIMAX_F32: [f32; 7] = [
+    4.437_675_7e-3,
+    7.475_816_6e-10,
+    1.087_008_6e-2,
+    2.128_777_1e-2,
+    5.405_384_7e-2,
+    1.333_277_8e-1,
+    3.333_334_3e-1,
 ];
 
-fn sin_shift(x: f64) -> f64 {
-    let (mut u, n) = periodic_clamp(x, PI / 2.0);
+// pi/2 split for Cody-Waite reduction. The first three parts are short enough
+// that n * PIO2_k_F32 is exact for |n| < 2^12.
+const PIO2_1_F32: f32 = 1.570_312_5;
+const PIO2_2_F32: f32 = 4.838_705e-4;
+const PIO2_3_F32: f32 = -4.371_395_3e-8;
+const PIO2_4_F32: f32 = 2.563_344e-12;
+
+// Below this sinf(x) and tanf(x) round to x
+const TRIG_TINY_F32: f32 = 2.441_406_2e-4;
+
+// pi = PI_F32 + PI_LO_F32 to about 48 bits
+const PI_LO_F32: f32 = -8.742_278e-8;
+
+// 2^24, past which every f32 is an even integer
+const HALF_TURNS_BIG_F32: f32 = 16_777_216.0;
+
+// pi / 180 = DEG_F32 + DEG_LO_F32 to about 48 bits
+const DEG_F32: f32 = 0.017_453_292;
+const DEG_LO_F32: f32 = 1.351_996e-10;
+
+// 2^22, past which x / 90 may round to the wrong quadrant
+const DEGREES_BIG_F32: f32 = 4_194_304.0;
+
+// Beyond this the reduction goes through f64
+const REDUCE_BIG_F32: f32 = 4096.0;
+
+fn reduce_pio2f(x: f32) -> (f32, i32) {
+    if x.abs() >= REDUCE_BIG_F32 && x.is_finite() {
+        let (r, _, n) = reduce_pio2(x as f64);
+        return (r as f32, n as i32);
+    }
+
+    let k = (x * std::f32::consts::FRAC_2_PI).round();
+
+    let u = k.mul_add(-PIO2_1_F32, x);
+    let u = k.mul_add(-PIO2_2_F32, u);
+    let u = k.mul_add(-PIO2_3_F32, u);
+    let u = k.mul_add(-PIO2_4_F32, u);
+
+    (u, k as i32)
+}
+
+fn sinf_kernel(u: f32) -> f32 {
+    let u2 = u * u;
+    (u2 * u).mul_add(polyvalf(&SIN_MINIMAX_F32, u2), u)
+}
+
+fn cosf_kernel(u: f32) -> f32 {
+    let u2 = u * u;
+    (u2 * u2).mul_add(polyvalf(&COS_MINIMAX_F32, u2), u2.mul_add(-0.5, 1.0))
+}
+
+fn sinf_quadrant(u: f32, n: i32) -> f32 {
+    let tl = if n & 1 != 0 {
+        cosf_kernel(u)
+    } else {
+        sinf_kernel(u)
+    };
+
+    if n & 2 != 0 {
+        -tl
+    } else {
+        tl
+    }
+}
+
+fn sincosf_quadrant(u: f32, n: i32) -> (f32, f32) {
+    let s = sinf_kernel(u);
+    let c = cosf_kernel(u);
+
+    let (s, c) = if n & 1 != 0 { (c, -s) } else { (s, c) };
+
+    if n & 2 != 0 {
+        (-s, -c)
+    } else {
+        (s, c)
+    }
+}
+
+pub fn sinf(x: f32) -> f32 {
+    if x.abs() < TRIG_TINY_F32 {
+        return x;
+    }
+
+    let (u, n) = reduce_pio2f(x);
+    sinf_quadrant(u, n)
+}
+
+pub fn cosf(x: f32) -> f32 {
+    let (u, n) = reduce_pio2f(x);
+    sinf_quadrant(u, n.wrapping_add(1))
+}
+
+/// The `f32` version of [`sincos`].
+pub fn sincosf(x: f32) -> (f32, f32) {
+    if x.abs() < TRIG_TINY_F32 {
+        return (x, 1.0);
+    }
+
+    let (u, n) = reduce_pio2f(x);
+    sincosf_quadrant(u, n)
+}
+
+fn tanf_quadrant(u: f32, n: i32) -> f32 {
+    let u2 = u * u;
+    let t = (u2 * u).mul_add(polyvalf(&TAN_MINIMAX_F32, u2), u);
+
+    if n & 1 != 0 {
+        -t.recip()
+    } else {
+        t
+    }
+}
+
+pub fn tanf(x: f32) -> f32 {
+    if x.abs() < TRIG_TINY_F32 {
+        return x;
+    }
+
+    let (u, n) = reduce_pio2f(x);
+    tanf_quadrant(u, n)
+}
+
+#[inline(always)]
+fn reduce_pio2f_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<i32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let k = (x * Simd::splat(std::f32::consts::FRAC_2_PI)).round();
+
+    let u = k.mul_add(Simd::splat(-PIO2_1_F32), x);
+    let u = k.mul_add(Simd::splat(-PIO2_2_F32), u);
+    let u = k.mul_add(Simd::splat(-PIO2_3_F32), u);
+    let u = k.mul_add(Simd::splat(-PIO2_4_F32), u);
+
+    let big = x.abs().simd_ge(Simd::splat(REDUCE_BIG_F32)) & x.is_finite();
+
+    if big.any() {
+        let (r, _, n) = reduce_pio2_simd(x.cast());
+        // Only n mod 4 matters, and that survives the trip through f32
+        let n = big.select((n & Simd::splat(3)).cast(), k);
+        (big.select(r.cast(), u), n.cast())
+    } else {
+        (u, k.cast())
+    }
+}
+
+#[inline(always)]
+fn sinf_quadrant_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u2 = u * u;
+
+    let s = (u2 * u).mul_add(polyvalf_simd(&SIN_MINIMAX_F32, u2), u);
+    let c = (u2 * u2).mul_add(
+        polyvalf_simd(&COS_MINIMAX_F32, u2),
+        u2.mul_add(Simd::splat(-0.5), Simd::splat(1.0)),
+    );
+
+    let tl = (n & Simd::splat(1)).simd_eq(Simd::splat(0)).select(s, c);
+
+    (n & Simd::splat(2)).simd_eq(Simd::splat(0)).select(tl, -tl)
+}
+
+#[inline(always)]
+fn sincosf_quadrant_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u2 = u * u;
+
+    let s = (u2 * u).mul_add(polyvalf_simd(&SIN_MINIMAX_F32, u2), u);
+    let c = (u2 * u2).mul_add(
+        polyvalf_simd(&COS_MINIMAX_F32, u2),
+        u2.mul_add(Simd::splat(-0.5), Simd::splat(1.0)),
+    );
+
+    let even = (n & Simd::splat(1)).simd_eq(Simd::splat(0));
+    let (s, c) = (even.select(s, c), even.select(c, -s));
+
+    let pos = (n & Simd::splat(2)).simd_eq(Simd::splat(0));
+    (pos.select(s, -s), pos.select(c, -c))
+}
+
+#[inline(always)]
+pub fn sinf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_pio2f_simd(x);
+    let y = sinf_quadrant_simd(u, n);
+
+    x.abs().simd_lt(Simd::splat(TRIG_TINY_F32)).select(x, y)
+}
+
+#[inline(always)]
+pub fn cosf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_pio2f_simd(x);
+    sinf_quadrant_simd(u, n + Simd::splat(1))
+}
+
+/// The SIMD version of [`sincosf`].
+#[inline(always)]
+pub fn sincosf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_pio2f_simd(x);
+    let (s, c) = sincosf_quadrant_simd(u, n);
+
+    (x.abs().simd_lt(Simd::splat(TRIG_TINY_F32)).select(x, s), c)
+}
+
+#[inline(always)]
+fn tanf_quadrant_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let u2 = u * u;
+    let t = (u2 * u).mul_add(polyvalf_simd(&TAN_MINIMAX_F32, u2), u);
+
+    (n & Simd::splat(1))
+        .simd_eq(Simd::splat(0))
+        .select(t, -t.recip())
+}
+
+#[inline(always)]
+pub fn tanf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_pio2f_simd(x);
+    let y = tanf_quadrant_simd(u, n);
+
+    x.abs().simd_lt(Simd::splat(TRIG_TINY_F32)).select(x, y)
+}
+
+fn reduce_half_turnsf(x: f32) -> (f32, i32) {
+    let x = if x.abs() < HALF_TURNS_BIG_F32 {
+        x
+    } else {
+        x * 0.0
+    };
+
+    let n = (2.0 * x).round();
+    let r = n.mul_add(-0.5, x);
+
+    let u = r.mul_add(std::f32::consts::PI, r * PI_LO_F32);
+
+    (u, (n - 4.0 * (0.25 * n).floor()) as i32)
+}
 
-    if n & 1 != 0 {
-        u = -u;
+fn sinf_exact(x: f32, u: f32, n: i32) -> f32 {
+    let y = sinf_quadrant(u, n);
+
+    if y == 0.0 {
+        0.0f32.copysign(x)
+    } else {
+        y
     }
+}
 
-    let mut tl = polyval(&TAYLOR_COEFFS, u);
+fn cosf_exact(u: f32, n: i32) -> f32 {
+    sinf_quadrant(u, n + 1) + 0.0
+}
 
-    if n & 2 != 0 {
-        tl = -tl;
+fn tanf_exact(x: f32, u: f32, n: i32) -> f32 {
+    if u == 0.0 {
+        let y = if n & 1 != 0 {
+            f32::INFINITY
+        } else {
+            0.0f32.copysign(x)
+        };
+
+        return if n & 2 != 0 { -y } else { y };
     }
 
-    tl
+    tanf_quadrant(u, n)
 }
 
-pub fn sin(x: f64) -> f64 {
-    sin_shift(x - PI / 4.0)
+fn sincosf_exact(x: f32, u: f32, n: i32) -> (f32, f32) {
+    let (s, c) = sincosf_quadrant(u, n);
+
+    let s = if s == 0.0 { 0.0f32.copysign(x) } else { s };
+
+    (s, c + 0.0)
 }
 
-pub fn cos(x: f64) -> f64 {
-    sin_shift(x + PI / 4.0)
+/// The `f32` version of [`sinpi`].
+pub fn sinpif(x: f32) -> f32 {
+    let (u, n) = reduce_half_turnsf(x);
+    sinf_exact(x, u, n)
 }
 
-pub fn tan(x: f64) -> f64 {
-    sin(x) / cos(x)
+/// The `f32` version of [`cospi`].
+pub fn cospif(x: f32) -> f32 {
+    let (u, n) = reduce_half_turnsf(x);
+    cosf_exact(u, n)
+}
+
+/// The `f32` version of [`tanpi`].
+pub fn tanpif(x: f32) -> f32 {
+    let (u, n) = reduce_half_turnsf(x);
+    tanf_exact(x, u, n)
+}
+
+/// The `f32` version of [`sincospi`].
+pub fn sincospif(x: f32) -> (f32, f32) {
+    let (u, n) = reduce_half_turnsf(x);
+    sincosf_exact(x, u, n)
 }
 
 #[inline(always)]
-fn sin_shift_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+fn reduce_half_turnsf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<i32, LANES>)
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    let (u, n) = periodic_clamp_simd(x, PI / 2.0);
+    let x = x
+        .abs()
+        .simd_lt(Simd::splat(HALF_TURNS_BIG_F32))
+        .select(x, x * Simd::splat(0.0));
 
-    let n: Simd<i64, LANES> = n.cast();
+    let n = (Simd::splat(2.0) * x).round();
+    let r = n.mul_add(Simd::splat(-0.5), x);
 
-    let u = (n & Simd::splat(1)).simd_eq(Simd::splat(0)).select(u, -u);
+    let u = r.mul_add(
+        Simd::splat(std::f32::consts::PI),
+        r * Simd::splat(PI_LO_F32),
+    );
 
-    let tl = polyval_simd(&TAYLOR_COEFFS, u);
+    let n = n - Simd::splat(4.0) * (Simd::splat(0.25) * n).floor();
 
-    (n & Simd::splat(2)).simd_eq(Simd::splat(0)).select(tl, -tl)
+    (u, n.cast())
 }
 
-pub fn sin_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+#[inline(always)]
+fn sinf_exact_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    sin_shift_simd(x - Simd::splat(PI / 4.0))
+    let y = sinf_quadrant_simd(u, n);
+
+    y.simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0).copysign(x), y)
 }
 
-pub fn cos_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+#[inline(always)]
+fn cosf_exact_simd<const LANES: usize>(
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    sin_shift_simd(x + Simd::splat(PI / 4.0))
+    sinf_quadrant_simd(u, n + Simd::splat(1)) + Simd::splat(0.0)
 }
 
-pub fn tan_simd<const LANES: usize>(x: Simd<f64, LANES>) -> Simd<f64, LANES>
+#[inline(always)]
+fn tanf_exact_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let y = tanf_quadrant_simd(u, n);
+
+    let exact = (n & Simd::splat(1))
+        .simd_eq(Simd::splat(0))
+        .select(Simd::splat(0.0).copysign(x), Simd::splat(f32::INFINITY));
+    let exact = (n & Simd::splat(2))
+        .simd_eq(Simd::splat(0))
+        .select(exact, -exact);
+
+    u.simd_eq(Simd::splat(0.0)).select(exact, y)
+}
+
+#[inline(always)]
+fn sincosf_exact_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    u: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (s, c) = sincosf_quadrant_simd(u, n);
+
+    let s = s
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0).copysign(x), s);
+
+    (s, c + Simd::splat(0.0))
+}
+
+/// The SIMD version of [`sinpif`].
+#[inline(always)]
+pub fn sinpif_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_half_turnsf_simd(x);
+    sinf_exact_simd(x, u, n)
+}
+
+/// The SIMD version of [`cospif`].
+#[inline(always)]
+pub fn cospif_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_half_turnsf_simd(x);
+    cosf_exact_simd(u, n)
+}
+
+/// The SIMD version of [`tanpif`].
+#[inline(always)]
+pub fn tanpif_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_half_turnsf_simd(x);
+    tanf_exact_simd(x, u, n)
+}
+
+/// The SIMD version of [`sincospif`].
+#[inline(always)]
+pub fn sincospif_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_half_turnsf_simd(x);
+    sincosf_exact_simd(x, u, n)
+}
+
+fn reduce_degreesf(x: f32) -> (f32, i32) {
+    let x = if x.abs() < DEGREES_BIG_F32 {
+        x
+    } else {
+        x % 360.0
+    };
+
+    let n = (x / 90.0).round();
+    let r = n.mul_add(-90.0, x);
+
+    let u = r.mul_add(DEG_F32, r * DEG_LO_F32);
+
+    (u, (n - 4.0 * (0.25 * n).floor()) as i32)
+}
+
+/// The `f32` version of [`sind`].
+pub fn sindf(x: f32) -> f32 {
+    let (u, n) = reduce_degreesf(x);
+    sinf_exact(x, u, n)
+}
+
+/// The `f32` version of [`cosd`].
+pub fn cosdf(x: f32) -> f32 {
+    let (u, n) = reduce_degreesf(x);
+    cosf_exact(u, n)
+}
+
+/// The `f32` version of [`tand`].
+pub fn tandf(x: f32) -> f32 {
+    let (u, n) = reduce_degreesf(x);
+    tanf_exact(x, u, n)
+}
+
+/// The `f32` version of [`sincosd`].
+pub fn sincosdf(x: f32) -> (f32, f32) {
+    let (u, n) = reduce_degreesf(x);
+    sincosf_exact(x, u, n)
+}
+
+#[inline(always)]
+fn reduce_degreesf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<i32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let big = x.abs().simd_ge(Simd::splat(DEGREES_BIG_F32));
+    let x = if big.any() {
+        big.select(x % Simd::splat(360.0), x)
+    } else {
+        x
+    };
+
+    let n = (x / Simd::splat(90.0)).round();
+    let r = n.mul_add(Simd::splat(-90.0), x);
+
+    let u = r.mul_add(Simd::splat(DEG_F32), r * Simd::splat(DEG_LO_F32));
+
+    let n = n - Simd::splat(4.0) * (Simd::splat(0.25) * n).floor();
+
+    (u, n.cast())
+}
+
+/// The SIMD version of [`sindf`].
+#[inline(always)]
+pub fn sindf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_degreesf_simd(x);
+    sinf_exact_simd(x, u, n)
+}
+
+/// The SIMD version of [`cosdf`].
+#[inline(always)]
+pub fn cosdf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_degreesf_simd(x);
+    cosf_exact_simd(u, n)
+}
+
+/// The SIMD version of [`tandf`].
+#[inline(always)]
+pub fn tandf_simd<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let (u, n) = reduce_degreesf_simd(x);
+    tanf_exact_simd(x, u, n)
+}
+
+/// The SIMD version of [`sincosdf`].
+#[inline(always)]
+pub fn sincosdf_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+) -> (Simd<f32, LANES>, Simd<f32, LANES>)
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    sin_simd(x) / cos_simd(x)
+    let (u, n) = reduce_degreesf_simd(x);
+    sincosf_exact_simd(x, u, n)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::PI;
+    use std::{f64::consts::PI, simd::Simd, time::Instant};
 
     use crate::{
-        tests::{accuracy_test, accuracy_test_simd, speed_test_simd_iterated},
-        trig::sin_shift,
+        tests::{
+            accuracy_test, accuracy_test_simd, accuracy_test_simdf,
+            accuracy_testf, print_array, speed_test_simd_iterated, ulp_diff,
+        },
         *,
     };
 
@@ -121,6 +1481,22 @@ mod tests {
         2267.811391833918,
     ];
 
+    const X_SMALL_F32: [f32; 8] = [
+        -4.7255907,
+        -3.0294428,
+        0.34498176,
+        -0.75379944,
+        0.7327486,
+        3.1200185,
+        -4.941557,
+        -4.9334373,
+    ];
+
+    const X_LARGE_F32: [f32; 8] = [
+        -673.1445, -4194.13, 2812.6233, -4882.862, 1815.659, -4318.335,
+        2955.7358, 2267.8115,
+    ];
+
     #[test]
     fn test_sin_shift() {
         let x = [
@@ -135,6 +1511,63 @@ mod tests {
         ];
 
         accuracy_test(&x, |x| (x + PI / 4.0).sin(), sin_shift);
+        accuracy_test_simd(x, |x| (x + PI / 4.0).sin(), sin_shift_simd);
+    }
+
+    #[test]
+    fn test_sin_shift_ulp() {
+        use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_4};
+
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let x = -100.0 + 200.0 * (i as f64 + 0.5) / N as f64;
+            let (s, c) = sincos(x);
+
+            // Away from its zeros, sin(x + pi/4) = (sin(x) + cos(x)) / sqrt(2)
+            // is good to a couple of ulps
+            let y = (s + c) * FRAC_1_SQRT_2;
+
+            if y.abs() > 0.5 {
+                assert!(ulp_diff(sin_shift(x), y) <= 3, "{x}");
+                assert!(
+                    ulp_diff(sin_shift_simd(Simd::<f64, 4>::splat(x))[0], y)
+                        <= 3,
+                    "{x}"
+                );
+            }
+        }
+
+        // Huge arguments go through Payne-Hanek first
+        for x in [1e22, -3.7e15, 1.6e9, f64::MAX] {
+            let y = (x.sin() + x.cos()) * FRAC_1_SQRT_2;
+            let y_simd = sin_shift_simd(Simd::from([x, 1.0, x, -x]));
+
+            assert!(ulp_diff(sin_shift(x), y) <= 3, "{x}");
+            assert!(ulp_diff(y_simd[0], y) <= 3, "{x}");
+            assert_eq!(y_simd[0], y_simd[2], "{x}");
+        }
+
+        // Close to the zeros only the rounding error of the multiples of
+        // FRAC_PI_4 is left
+        let x = Simd::from([
+            -FRAC_PI_4,
+            FRAC_PI_4,
+            3.0 * FRAC_PI_4,
+            -5.0 * FRAC_PI_4,
+        ]);
+        let y = [
+            3.061616997868383e-17,
+            1.0,
+            9.184850993605148e-17,
+            -1.5308084989341916e-16,
+        ];
+        let y_simd = sin_shift_simd(x);
+
+        for i in 0..4 {
+            assert_eq!(ulp_diff(sin_shift(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y_simd[i], y[i]), 0, "{}", x[i]);
+        }
     }
 
     #[test]
@@ -173,6 +1606,545 @@ mod tests {
         accuracy_test_simd(X_LARGE, |x| x.tan(), |x| x.tan());
     }
 
+    #[test]
+    fn test_sinf_accuracy() {
+        accuracy_testf(&X_SMALL_F32, |x| x.sin(), sinf);
+        accuracy_testf(&X_LARGE_F32, |x| x.sin(), sinf);
+    }
+
+    #[test]
+    fn test_cosf_accuracy() {
+        accuracy_testf(&X_SMALL_F32, |x| x.cos(), cosf);
+        accuracy_testf(&X_LARGE_F32, |x| x.cos(), cosf);
+    }
+
+    #[test]
+    fn test_tanf_accuracy() {
+        accuracy_testf(&X_SMALL_F32, |x| x.tan(), tanf);
+        accuracy_testf(&X_LARGE_F32, |x| x.tan(), tanf);
+    }
+
+    #[test]
+    fn test_sinf_simd() {
+        accuracy_test_simdf(X_SMALL_F32, |x| x.sin(), |x| x.sin());
+        accuracy_test_simdf(X_LARGE_F32, |x| x.sin(), |x| x.sin());
+    }
+
+    #[test]
+    fn test_cosf_simd() {
+        accuracy_test_simdf(X_SMALL_F32, |x| x.cos(), |x| x.cos());
+        accuracy_test_simdf(X_LARGE_F32, |x| x.cos(), |x| x.cos());
+    }
+
+    #[test]
+    fn test_tanf_simd() {
+        accuracy_test_simdf(X_SMALL_F32, |x| x.tan(), |x| x.tan());
+        accuracy_test_simdf(X_LARGE_F32, |x| x.tan(), |x| x.tan());
+    }
+
+    #[test]
+    fn test_sin_cos_ulp() {
+        const N: usize = 20000;
+
+        for i in 0..N {
+            // Exponents from -10 up to f64::MAX, through both reductions
+            let x = 2f64.powf(-10.0 + 1034.0 * (i as f64 + 0.5) / N as f64);
+
+            for x in [x, -x] {
+                let x_simd = Simd::<f64, 4>::splat(x);
+
+                assert!(ulp_diff(sin(x), x.sin()) <= 1, "{x}");
+                assert!(ulp_diff(cos(x), x.cos()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.sin()[0], x.sin()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.cos()[0], x.cos()) <= 1, "{x}");
+                assert!(ulp_diff(tan(x), x.tan()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.tan()[0], x.tan()) <= 1, "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_trig_tiny() {
+        for e in 8..=1074 {
+            let x = 2f64.powi(-e) * 1.3;
+
+            for x in [x, -x] {
+                let x_simd = Simd::<f64, 4>::splat(x);
+
+                assert!(ulp_diff(sin(x), x.sin()) <= 1, "{x}");
+                assert!(ulp_diff(tan(x), x.tan()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.sin()[0], x.sin()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.tan()[0], x.tan()) <= 1, "{x}");
+                assert!(ulp_diff(x_simd.sin_cos().0[0], x.sin()) <= 1, "{x}");
+                assert!(ulp_diff(cos(x), x.cos()) <= 1, "{x}");
+            }
+        }
+
+        assert_eq!(sin(1e-10), 1e-10);
+        assert_eq!(tan(-1e-9), -1e-9);
+        assert_eq!(sinf(1e-5), 1e-5);
+        assert_eq!(tanf(-1e-5), -1e-5);
+
+        let x = Simd::from([-0.0f64, 0.0, -1e-300, -5e-324]);
+        let xf = Simd::from([-0.0f32, 0.0, -1e-30, -1e-45]);
+
+        for y in [x.sin(), x.tan(), x.sin_cos().0] {
+            for i in 0..4 {
+                assert_eq!(y[i].to_bits(), x[i].to_bits(), "{}", x[i]);
+                assert_eq!(sin(x[i]).to_bits(), x[i].to_bits());
+                assert_eq!(tan(x[i]).to_bits(), x[i].to_bits());
+                assert_eq!(sincos(x[i]).0.to_bits(), x[i].to_bits());
+            }
+        }
+
+        for y in [xf.sin(), xf.tan(), xf.sin_cos().0] {
+            for i in 0..4 {
+                assert_eq!(y[i].to_bits(), xf[i].to_bits(), "{}", xf[i]);
+                assert_eq!(sinf(xf[i]).to_bits(), xf[i].to_bits());
+                assert_eq!(tanf(xf[i]).to_bits(), xf[i].to_bits());
+                assert_eq!(sincosf(xf[i]).0.to_bits(), xf[i].to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_trig_near_zeros() {
+        const N: i64 = 100000;
+
+        for k in 1..N {
+            // The doubles closest to the zeros of sin, cos and tan and to the
+            // poles of tan, where only the reduction error is visible
+            for x in [k as f64 * PI, (k as f64 + 0.5) * PI, k as f64 * 1e4 * PI]
+            {
+                for x in [x, f64::from_bits(x.to_bits() + 1), -x] {
+                    let x_simd = Simd::<f64, 4>::splat(x);
+
+                    assert!(ulp_diff(sin(x), x.sin()) <= 1, "{x}");
+                    assert!(ulp_diff(cos(x), x.cos()) <= 1, "{x}");
+                    assert!(ulp_diff(tan(x), x.tan()) <= 1, "{x}");
+                    assert!(ulp_diff(x_simd.sin()[0], x.sin()) <= 1, "{x}");
+                    assert!(ulp_diff(x_simd.cos()[0], x.cos()) <= 1, "{x}");
+                    assert!(ulp_diff(x_simd.tan()[0], x.tan()) <= 1, "{x}");
+                }
+            }
+
+            if k < 10000 {
+                let x = (k as f64 * PI) as f32;
+                let xc = ((k as f64 + 0.5) * PI) as f32;
+
+                let s = (x as f64).sin();
+                let c = (xc as f64).cos();
+
+                assert!(((sinf(x) as f64 - s) / s).abs() < 2e-7, "{x}");
+                assert!(
+                    ((tanf(x) as f64 - s / (x as f64).cos()) / s).abs()
+                        < 2.5e-7
+                );
+                assert!(((cosf(xc) as f64 - c) / c).abs() < 2e-7, "{xc}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_trig_huge() {
+        // Small and huge arguments mixed in one vector
+        let x = Simd::from([
+            1e22,
+            -2.5,
+            1e200,
+            1_073_741_824.0,
+            -1e300,
+            f64::MAX,
+            -f64::MAX,
+            1e-5,
+        ]);
+
+        let (s, c) = x.sin_cos();
+        let (sin_x, cos_x, tan_x) = (x.sin(), x.cos(), x.tan());
+
+        for i in 0..8 {
+            let (y_sin, y_cos) = (x[i].sin(), x[i].cos());
+
+            assert!(ulp_diff(sin(x[i]), y_sin) <= 1, "{}", x[i]);
+            assert!(ulp_diff(cos(x[i]), y_cos) <= 1, "{}", x[i]);
+            assert!(ulp_diff(sin_x[i], y_sin) <= 1, "{}", x[i]);
+            assert!(ulp_diff(cos_x[i], y_cos) <= 1, "{}", x[i]);
+            assert!(ulp_diff(tan(x[i]), x[i].tan()) <= 1, "{}", x[i]);
+            assert!(ulp_diff(tan_x[i], x[i].tan()) <= 1, "{}", x[i]);
+            assert!(ulp_diff(s[i], y_sin) <= 1, "{}", x[i]);
+            assert!(ulp_diff(c[i], y_cos) <= 1, "{}", x[i]);
+
+            let xf = x[i] as f32;
+            if xf.is_finite() {
+                let y = sinf(xf);
+                assert!((y - (xf as f64).sin() as f32).abs() < 1e-7, "{xf}");
+            }
+        }
+
+        assert_eq!(sin(1e22), -0.8522008497671888);
+
+        // The double closest to a multiple of pi/2
+        let x = 6381956970095103.0 * 2f64.powi(797);
+        assert_eq!(cos(x), -4.687165924254628e-19);
+        assert_eq!(Simd::<f64, 4>::splat(x).cos()[0], -4.687165924254628e-19);
+        assert_eq!(tan(x), -2.133485385753704e18);
+
+        let x = Simd::from([0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN]);
+        let (s, c) = x.sin_cos();
+
+        for i in 1..4 {
+            assert!(sin(x[i]).is_nan() && cos(x[i]).is_nan(), "{}", x[i]);
+            assert!(s[i].is_nan() && c[i].is_nan(), "{}", x[i]);
+        }
+        assert_eq!((s[0], c[0]), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_tan_special_values() {
+        let x = [
+            std::f64::consts::FRAC_PI_2,
+            -std::f64::consts::FRAC_PI_2,
+            1e-300,
+            std::f64::consts::PI,
+            4.71238898038469,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        let y = [
+            1.633123935319537e16,
+            -1.633123935319537e16,
+            1e-300,
+            -1.2246467991473532e-16,
+            5443746451065123.0,
+            f64::NAN,
+            f64::NAN,
+            f64::NAN,
+        ];
+
+        let y_simd = Simd::from(x).tan();
+
+        for i in 0..x.len() {
+            assert_eq!(ulp_diff(tan(x[i]), y[i]), 0, "{}", x[i]);
+            assert_eq!(ulp_diff(y_simd[i], y[i]), 0, "{}", x[i]);
+        }
+    }
+
+    #[test]
+    fn test_sinf_huge() {
+        let x =
+            Simd::from([1e30f32, -3e8, 8192.5, -4096.0, 1e10, 0.5, 7e37, 2.0]);
+        let y = x.sin();
+
+        for i in 0..8 {
+            let y_std = (x[i] as f64).sin() as f32;
+
+            assert!((sinf(x[i]) - y_std).abs() < 1e-7, "{}", x[i]);
+            assert!((y[i] - y_std).abs() < 1e-7, "{}", x[i]);
+            assert!((cosf(x[i]) - (x[i] as f64).cos() as f32).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_sincos() {
+        const N: usize = 100000;
+
+        for i in 0..N {
+            let x = -100.0 + 200.0 * (i as f64 + 0.5) / N as f64;
+            let xf = x as f32;
+
+            let (s, c) = sincos(x);
+            let (s_simd, c_simd) = Simd::<f64, 4>::splat(x).sin_cos();
+            let (sf, cf) = sincosf(xf);
+            let (sf_simd, cf_simd) = Simd::<f32, 4>::splat(xf).sin_cos();
+
+            assert_eq!(s, sin(x), "{x}");
+            assert_eq!(c, cos(x), "{x}");
+            assert!(ulp_diff(s, x.sin()) <= 1, "{x}");
+            assert!(ulp_diff(c, x.cos()) <= 1, "{x}");
+            assert_eq!(s, s_simd[0], "{x}");
+            assert_eq!(c, c_simd[0], "{x}");
+
+            assert_eq!(sf, sinf(xf), "{xf}");
+            assert_eq!(cf, cosf(xf), "{xf}");
+            assert_eq!(sf, sf_simd[0], "{xf}");
+            assert_eq!(cf, cf_simd[0], "{xf}");
+        }
+    }
+
+    #[test]
+    fn test_sincos_simd() {
+        accuracy_test_simd(X_SMALL, |x| x.sin(), |x| x.sin_cos().0);
+        accuracy_test_simd(X_LARGE, |x| x.cos(), |x| x.sin_cos().1);
+        accuracy_test_simdf(X_SMALL_F32, |x| x.sin(), |x| x.sin_cos().0);
+        accuracy_test_simdf(X_LARGE_F32, |x| x.cos(), |x| x.sin_cos().1);
+    }
+
+    // sin, cos and tan of x c, where c = c_hi + c_lo is pi / p, from an
+    // exact reduction modulo p
+    fn exact_ref(x: f64, p: f64, c_hi: f64, c_lo: f64) -> (f64, f64, f64) {
+        let m = (x / p).round();
+        let r = m.mul_add(-p, x);
+
+        let h = r * c_hi;
+        let l = r.mul_add(c_hi, -h) + r * c_lo;
+
+        let s = h.cos().mul_add(l, h.sin());
+        let c = (-h.sin()).mul_add(l, h.cos());
+        let t = h.tan();
+        let t = (t * t + 1.0).mul_add(l, t);
+
+        if m % 2.0 == 0.0 {
+            (s, c, t)
+        } else {
+            (-s, -c, t)
+        }
+    }
+
+    fn sinpi_ref(x: f64) -> (f64, f64, f64) {
+        exact_ref(x, 1.0, PI, 1.2246467991473532e-16)
+    }
+
+    fn sind_ref(x: f64) -> (f64, f64, f64) {
+        exact_ref(x, 180.0, 0.017453292519943295, 2.9486522708701687e-19)
+    }
+
+    #[test]
+    fn test_sinpi_exact() {
+        let x = Simd::from([0.0f64, -0.0, 1.0, -1.0, 2.5, -2.5, 7.0, -6.0]);
+        let s = [0.0f64, -0.0, 0.0, -0.0, 1.0, -1.0, 0.0, -0.0];
+        let c = [1.0f64, 1.0, -1.0, -1.0, 0.0, 0.0, -1.0, 1.0];
+        let inf = f64::INFINITY;
+        let t = [0.0f64, -0.0, -0.0, 0.0, inf, -inf, -0.0, -0.0];
+
+        let (s_simd, c_simd) = x.sin_cos_pi();
+
+        for i in 0..8 {
+            let xf = x[i] as f32;
+
+            for (y, y_ref) in [
+                (sinpi(x[i]), s[i]),
+                (x.sin_pi()[i], s[i]),
+                (s_simd[i], s[i]),
+                (sincospi(x[i]).0, s[i]),
+                (cospi(x[i]), c[i]),
+                (x.cos_pi()[i], c[i]),
+                (c_simd[i], c[i]),
+                (sincospi(x[i]).1, c[i]),
+                (tanpi(x[i]), t[i]),
+                (x.tan_pi()[i], t[i]),
+                (sinpif(xf) as f64, s[i]),
+                (cospif(xf) as f64, c[i]),
+                (tanpif(xf) as f64, t[i]),
+                (Simd::<f32, 4>::splat(xf).sin_pi()[0] as f64, s[i]),
+                (Simd::<f32, 4>::splat(xf).cos_pi()[0] as f64, c[i]),
+                (Simd::<f32, 4>::splat(xf).tan_pi()[0] as f64, t[i]),
+            ] {
+                assert_eq!(y.to_bits(), y_ref.to_bits(), "{}", x[i]);
+            }
+        }
+
+        assert_eq!(sinpi(1.0 / 6.0), 0.5);
+        assert_eq!(cospi(1.0 / 3.0), 0.5);
+        assert_eq!(tanpi(0.25), 1.0);
+        assert_eq!(tanpi(-0.75), 1.0);
+        assert_eq!(sinpi(1e300), 0.0);
+        assert_eq!(cospi(-1e300), 1.0);
+        assert_eq!(cospi(9007199254740991.0), -1.0);
+        assert_eq!(sinpif(0.5), 1.0);
+        assert_eq!(cospif(-16777215.0), -1.0);
+
+        let x = Simd::from([f64::NAN, f64::INFINITY, -f64::INFINITY, 0.5]);
+        let y = x.sin_pi() + x.cos_pi() + x.tan_pi();
+
+        assert!(y[0].is_nan() && y[1].is_nan() && y[2].is_nan());
+        assert!(sinpi(f64::INFINITY).is_nan() && tanpi(f64::NAN).is_nan());
+        assert!(cospif(f32::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_sinpi_ulp() {
+        let mut max_ulp = [0; 3];
+
+        for i in 0..200000 {
+            let x = (i as f64 * 1.8e-4 - 15.0).exp2() * (i as f64).sin();
+            let (s_ref, c_ref, t_ref) = sinpi_ref(x);
+
+            let x_simd = Simd::<f64, 4>::splat(x);
+            let (s, c) = sincospi(x);
+
+            assert_eq!(s, sinpi(x), "{x}");
+            assert_eq!(c, cospi(x), "{x}");
+            assert_eq!(s, x_simd.sin_pi()[0], "{x}");
+            assert_eq!(c, x_simd.cos_pi()[0], "{x}");
+            assert_eq!(tanpi(x), x_simd.tan_pi()[0], "{x}");
+
+            for (k, (y, y_ref)) in [(s, s_ref), (c, c_ref), (tanpi(x), t_ref)]
+                .into_iter()
+                .enumerate()
+            {
+                max_ulp[k] = max_ulp[k].max(ulp_diff(y, y_ref));
+            }
+
+            let xf = x as f32;
+            let (s_ref, c_ref, _) = sinpi_ref(xf as f64);
+
+            assert!((sinpif(xf) as f64 - s_ref).abs() < 1.5e-7, "{xf}");
+            assert!((cospif(xf) as f64 - c_ref).abs() < 1.5e-7, "{xf}");
+            assert_eq!(sinpif(xf), Simd::<f32, 4>::splat(xf).sin_pi()[0]);
+            assert_eq!(tanpif(xf), Simd::<f32, 4>::splat(xf).tan_pi()[0]);
+        }
+
+        assert!(max_ulp[0] <= 1 && max_ulp[1] <= 1 && max_ulp[2] <= 2);
+    }
+
+    #[test]
+    fn test_sind_exact() {
+        let x = [30.0f64, -150.0, 180.0, -180.0, 90.0, -90.0, 360.0, -720.0];
+        let s = [0.5f64, -0.5, 0.0, -0.0, 1.0, -1.0, 0.0, -0.0];
+        let c = [
+            0.8660254037844386f64,
+            -0.8660254037844386,
+            -1.0,
+            -1.0,
+            0.0,
+            0.0,
+            1.0,
+            1.0,
+        ];
+        let t = [
+            0.5773502691896257f64,
+            0.5773502691896257,
+            -0.0,
+            0.0,
+            f64::INFINITY,
+            -f64::INFINITY,
+            0.0,
+            -0.0,
+        ];
+
+        let x = Simd::from(x);
+        let (s_simd, c_simd) = x.sin_cos_deg();
+
+        for i in 0..8 {
+            let xf = x[i] as f32;
+            let xf_simd = Simd::<f32, 4>::splat(xf);
+
+            for (y, y_ref) in [
+                (sind(x[i]), s[i]),
+                (x.sin_deg()[i], s[i]),
+                (s_simd[i], s[i]),
+                (sincosd(x[i]).0, s[i]),
+                (cosd(x[i]), c[i]),
+                (x.cos_deg()[i], c[i]),
+                (c_simd[i], c[i]),
+                (sincosd(x[i]).1, c[i]),
+                (tand(x[i]), t[i]),
+                (x.tan_deg()[i], t[i]),
+            ] {
+                assert_eq!(y.to_bits(), y_ref.to_bits(), "{}", x[i]);
+            }
+
+            for (y, y_ref) in [
+                (sindf(xf), s[i]),
+                (cosdf(xf), c[i]),
+                (tandf(xf), t[i]),
+                (xf_simd.sin_deg()[0], s[i]),
+                (xf_simd.cos_deg()[0], c[i]),
+                (xf_simd.tan_deg()[0], t[i]),
+            ] {
+                // Only the values exact in f32 are expected to be exact
+                if y_ref as f32 as f64 == y_ref {
+                    assert_eq!(y.to_bits(), (y_ref as f32).to_bits(), "{xf}");
+                } else {
+                    assert!((y as f64 - y_ref).abs() < 1.2e-7, "{xf}");
+                }
+            }
+        }
+
+        assert_eq!(cosd(60.0), 0.5);
+        assert_eq!(tand(45.0), 1.0);
+        assert_eq!(tand(-225.0), -1.0);
+        assert_eq!(sind(1e300), sind(1e300 % 360.0));
+        assert_eq!(cosd(-1e22), cosd(-1e22 % 360.0));
+        assert_eq!(cosdf(1e30), cosdf(1e30 % 360.0));
+        assert_eq!(cosdf(-60.0), 0.5);
+
+        let x = Simd::from([f64::NAN, f64::INFINITY, -f64::INFINITY, 1e300]);
+        let y = x.sin_deg() + x.cos_deg() + x.tan_deg();
+
+        assert!(y[0].is_nan() && y[1].is_nan() && y[2].is_nan());
+        assert_eq!(x.sin_deg()[3], sind(1e300));
+        assert!(sind(f64::INFINITY).is_nan() && tand(f64::NAN).is_nan());
+        assert!(cosdf(f32::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_sind_ulp() {
+        let mut max_ulp = [0; 3];
+
+        for i in 0..200000 {
+            let x = (i as f64 * 1.6e-4 - 12.0).exp2() * (i as f64).sin();
+            let (s_ref, c_ref, t_ref) = sind_ref(x);
+
+            let x_simd = Simd::<f64, 4>::splat(x);
+            let (s, c) = sincosd(x);
+
+            assert_eq!(s, sind(x), "{x}");
+            assert_eq!(c, cosd(x), "{x}");
+            assert_eq!(s, x_simd.sin_deg()[0], "{x}");
+            assert_eq!(c, x_simd.cos_deg()[0], "{x}");
+            assert_eq!(tand(x), x_simd.tan_deg()[0], "{x}");
+
+            for (k, (y, y_ref)) in [(s, s_ref), (c, c_ref), (tand(x), t_ref)]
+                .into_iter()
+                .enumerate()
+            {
+                max_ulp[k] = max_ulp[k].max(ulp_diff(y, y_ref));
+            }
+
+            let xf = x as f32;
+            let (s_ref, c_ref, _) = sind_ref(xf as f64);
+
+            assert!((sindf(xf) as f64 - s_ref).abs() < 1.5e-7, "{xf}");
+            assert!((cosdf(xf) as f64 - c_ref).abs() < 1.5e-7, "{xf}");
+            assert_eq!(sindf(xf), Simd::<f32, 4>::splat(xf).sin_deg()[0]);
+            assert_eq!(tandf(xf), Simd::<f32, 4>::splat(xf).tan_deg()[0]);
+        }
+
+        assert!(max_ulp[0] <= 1 && max_ulp[1] <= 1 && max_ulp[2] <= 2);
+    }
+
+    #[test]
+    fn test_sincos_simd_speed() {
+        const ITERS: usize = 1000000;
+
+        let x = Simd::from(X_SMALL);
+
+        let t = Instant::now();
+        let mut y_sep = x;
+        for _ in 0..ITERS {
+            y_sep = y_sep.sin() + y_sep.cos();
+        }
+        let t_sep = t.elapsed();
+
+        let t = Instant::now();
+        let mut y_fused = x;
+        for _ in 0..ITERS {
+            let (s, c) = y_fused.sin_cos();
+            y_fused = s + c;
+        }
+        let t_fused = t.elapsed();
+
+        // Measured in a release build on x86-64: 35 ms fused against 62 ms
+        // separate, so sin_cos is about 1.75 times as fast. Timings are too
+        // noisy to assert on.
+        print!("separate (took {t_sep:?}):\n       ");
+        print_array(&y_sep.to_array());
+        print!("sin_cos (took {t_fused:?}):\n       ");
+        print_array(&y_fused.to_array());
+    }
+
     #[test]
     fn test_sin_simd_speed() {
         const ITERS: usize = 1000000;
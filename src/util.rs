@@ -1,9 +1,12 @@
 use std::simd::{prelude::*, LaneCount, StdFloat, SupportedLaneCount};
 
+/// Reduces `x` to `x - n * a` with `n` the nearest integer to `x / a`. The
+/// count saturates outside the range of `i32`, but the remainder is always
+/// taken with the unsaturated `n`.
 #[inline(always)]
 pub fn periodic_clamp(x: f64, a: f64) -> (f64, i32) {
-    let n = unsafe { (x / a + 0.5 * x.signum()).to_int_unchecked() };
-    (x - (n as f64) * a, n)
+    let n = (x / a).round();
+    (x - n * a, n as i32)
 }
 
 #[inline(always)]
@@ -14,16 +17,17 @@ pub fn periodic_clamp_simd<const LANES: usize>(
 where
     LaneCount<LANES>: SupportedLaneCount,
 {
-    let n = unsafe {
-        (x / Simd::splat(a) + Simd::splat(0.5).copysign(x)).to_int_unchecked()
-    };
-    (x - (SimdInt::cast::<f64>(n)) * Simd::splat(a), n)
+    let n = (x / Simd::splat(a)).round();
+    (x - n * Simd::splat(a), n.cast())
 }
 
 pub fn powi(x: f64, n: i32) -> f64 {
-    let mut x = if n < 0 { x.recip() } else { x };
-    let mut n = n.abs();
+    let x = if n < 0 { x.recip() } else { x };
+    powi_pos(x, n.unsigned_abs())
+}
 
+#[inline(always)]
+pub fn powi_pos(mut x: f64, mut n: u32) -> f64 {
     let mut acc = 1.0;
 
     while n != 0 {
@@ -84,6 +88,47 @@ where
     acc
 }
 
+pub fn powif(x: f32, n: i32) -> f32 {
+    let mut x = if n < 0 { x.recip() } else { x };
+    let mut n = n.unsigned_abs();
+
+    let mut acc = 1.0;
+
+    while n != 0 {
+        acc = if n & 1 != 0 { acc * x } else { acc };
+
+        x *= x;
+        n >>= 1;
+    }
+
+    acc
+}
+
+#[inline(always)]
+pub fn powif_simd<const LANES: usize>(
+    x: Simd<f32, LANES>,
+    n: Simd<i32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut x = n.is_negative().select(x.recip(), x);
+    let mut n: Simd<u32, LANES> = n.abs().cast();
+
+    let mut acc = Simd::splat(1.0);
+
+    while !n.simd_eq(Simd::splat(0)).all() {
+        acc = (n & Simd::splat(1))
+            .simd_eq(Simd::splat(0))
+            .select(acc, acc * x);
+
+        x *= x;
+        n >>= Simd::splat(1);
+    }
+
+    acc
+}
+
 #[inline(always)]
 pub fn polyval<const N: usize>(cs: &[f64; N], x: f64) -> f64 {
     let mut acc = cs[0];
@@ -112,6 +157,34 @@ where
     acc
 }
 
+#[inline(always)]
+pub fn polyvalf<const N: usize>(cs: &[f32; N], x: f32) -> f32 {
+    let mut acc = cs[0];
+
+    for &c in &cs[1..] {
+        acc = x.mul_add(acc, c);
+    }
+
+    acc
+}
+
+#[inline(always)]
+pub fn polyvalf_simd<const N: usize, const LANES: usize>(
+    cs: &[f32; N],
+    x: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut acc = Simd::splat(cs[0]);
+
+    for &c in &cs[1..] {
+        acc = x.mul_add(acc, Simd::splat(c));
+    }
+
+    acc
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::{
@@ -132,6 +205,27 @@ pub mod tests {
         println!("]");
     }
 
+    /// Distance between `a` and `b` in units in the last place. Two NaNs are
+    /// considered equal, and so are `0.0` and `-0.0`.
+    pub fn ulp_diff(a: f64, b: f64) -> u64 {
+        fn key(x: f64) -> i64 {
+            let i = x.to_bits() as i64;
+            if i < 0 {
+                i64::MIN - i
+            } else {
+                i
+            }
+        }
+
+        if a.is_nan() && b.is_nan() {
+            0
+        } else if a.is_nan() || b.is_nan() {
+            u64::MAX
+        } else {
+            key(a).abs_diff(key(b))
+        }
+    }
+
     pub fn accuracy_test<F1: Fn(f64) -> f64, F2: Fn(f64) -> f64>(
         x: &[f64],
         f_std: F1,
@@ -198,6 +292,55 @@ pub mod tests {
         print_array(&rdiff2);
     }
 
+    pub fn accuracy_testf<F1: Fn(f32) -> f32, F2: Fn(f32) -> f32>(
+        x: &[f32],
+        f_std: F1,
+        f_lib: F2,
+    ) {
+        let x: Vec<_> = x.iter().map(|&x| x as f64).collect();
+
+        accuracy_test(
+            &x,
+            |x| f_std(x as f32) as f64,
+            |x| f_lib(x as f32) as f64,
+        );
+    }
+
+    pub fn accuracy_test_simdf<
+        const LANES: usize,
+        F1: Fn(f32) -> f32 + Copy,
+        F2: Fn(Simd<f32, LANES>) -> Simd<f32, LANES>,
+    >(
+        x: [f32; LANES],
+        f_std: F1,
+        f_lib: F2,
+    ) where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        let y_lib = f_lib(Simd::from(x)).to_array();
+
+        let x: Vec<_> = x.iter().map(|&x| x as f64).collect();
+        let y_std: Vec<_> = x.iter().map(|&x| f_std(x as f32) as f64).collect();
+        let y_lib: Vec<_> = y_lib.iter().map(|&y| y as f64).collect();
+
+        let diff: Vec<_> =
+            y_std.iter().zip(&y_lib).map(|(a, b)| a - b).collect();
+
+        let rdiff: Vec<_> =
+            diff.iter().zip(&y_std).map(|(a, b)| a / b).collect();
+
+        print!("x:     ");
+        print_array(&x);
+        print!("y_std: ");
+        print_array(&y_std);
+        print!("y_lib: ");
+        print_array(&y_lib);
+        print!("adiff: ");
+        print_array(&diff);
+        print!("rodiff:");
+        print_array(&rdiff);
+    }
+
     pub fn speed_test_simd_iterated<
         const LANES: usize,
         F1: Fn(f64) -> f64 + Copy,